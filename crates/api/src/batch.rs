@@ -0,0 +1,54 @@
+use advent_of_code::solve;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of problems in a single batch.
+const MAX_BATCH_SIZE: usize = 50;
+
+#[derive(Deserialize)]
+struct BatchEntry {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BatchResult {
+    Answer(String),
+    Error(String),
+}
+
+/// Solve a JSON array of problems, returning a JSON array of results in the same order.
+pub fn solve_batch(body: &str) -> Result<String, String> {
+    let entries: Vec<BatchEntry> =
+        serde_json::from_str(body).map_err(|error| format!("Invalid batch: {error}"))?;
+    if entries.len() > MAX_BATCH_SIZE {
+        return Err(format!(
+            "Too many problems in batch - max {MAX_BATCH_SIZE} supported"
+        ));
+    }
+
+    let results = entries
+        .iter()
+        .map(
+            |entry| match solve(entry.year, entry.day, entry.part, &entry.input) {
+                Ok(answer) => BatchResult::Answer(answer),
+                Err(error) => BatchResult::Error(error),
+            },
+        )
+        .collect::<Vec<_>>();
+    serde_json::to_string(&results).map_err(|error| format!("Unable to serialize: {error}"))
+}
+
+#[test]
+fn test_batch() {
+    assert_eq!(
+        solve_batch(
+            r#"[{"year":2019,"day":1,"part":1,"input":"14"},{"year":2019,"day":1,"part":3,"input":"14"}]"#
+        ),
+        Ok(r#"[{"answer":"2"},{"error":"Invalid part 3 - must be 1-2"}]"#.to_string())
+    );
+    assert!(solve_batch("[{}]").is_err());
+    assert_eq!(solve_batch("[]"), Ok("[]".to_string()));
+}
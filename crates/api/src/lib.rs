@@ -0,0 +1,170 @@
+#![forbid(unsafe_code)]
+/*!
+A framework-agnostic request/response layer for solving [Advent of Code](https://adventofcode.com/) problems.
+
+Servers convert their native requests into a [Request](struct.Request.html), route them
+using [handle](fn.handle.html) and convert the returned [Response](struct.Response.html) back.
+
+# Example
+```rust
+use advent_of_code_api::{handle, Method, Request};
+let response = handle(&Request {
+    method: Method::Post,
+    path: "/solve/2019/1/1",
+    body: "14",
+});
+assert_eq!(response.status, 200);
+assert_eq!(response.body, "2");
+```
+*/
+
+mod batch;
+
+use advent_of_code::solve_raw;
+
+/// The version of the solutions, reported by the version route.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The HTTP methods relevant for routing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Options,
+    Other,
+}
+
+impl Method {
+    /// Parse a HTTP method name, case insensitively.
+    pub fn parse(method: &str) -> Self {
+        if method.eq_ignore_ascii_case("GET") {
+            Self::Get
+        } else if method.eq_ignore_ascii_case("POST") {
+            Self::Post
+        } else if method.eq_ignore_ascii_case("OPTIONS") {
+            Self::Options
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// An incoming request.
+pub struct Request<'a> {
+    pub method: Method,
+    /// The path of the request, without any query string.
+    pub path: &'a str,
+    pub body: &'a str,
+}
+
+/// A response to be sent back, together with the [headers](#method.headers).
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "text/plain",
+            body,
+        }
+    }
+
+    fn error<S: Into<String>>(status: u16, message: S) -> Self {
+        Self::text(status, message.into())
+    }
+
+    /// The headers to send together with the response.
+    pub fn headers(&self) -> [(&'static str, &'static str); 4] {
+        [
+            ("Content-Type", self.content_type),
+            ("Access-Control-Allow-Origin", "*"),
+            ("Access-Control-Allow-Methods", "GET, POST, OPTIONS"),
+            ("Access-Control-Allow-Headers", "Content-Type"),
+        ]
+    }
+}
+
+/// Route a request and return the response to send back.
+pub fn handle(request: &Request) -> Response {
+    let path = request.path.trim_end_matches('/');
+    let segments = path.split('/').skip(1).collect::<Vec<_>>();
+
+    let allowed_method = match segments.as_slice() {
+        [] | ["version" | "worker-version" | "health"] => Method::Get,
+        ["solve", _, _, _] | ["solve-batch"] => Method::Post,
+        _ => {
+            return Response::error(404, format!("Not found: {}", request.path));
+        }
+    };
+
+    if request.method == Method::Options {
+        return Response::text(204, String::new());
+    } else if request.method != allowed_method {
+        return Response::error(405, "Method not allowed");
+    }
+
+    match segments.as_slice() {
+        [] => Response {
+            status: 200,
+            content_type: "text/html",
+            body: "<h1>Advent of Code API</h1>\n\
+                   <p>Check the <a href='https://aoc.fornwall.net/api/openapi.json'>OpenAPI document</a>.</p>"
+                .to_string(),
+        },
+        ["version" | "worker-version"] => Response::text(200, VERSION.to_string()),
+        ["health"] => Response::text(200, "OK".to_string()),
+        ["solve", year, day, part] => match solve_raw(year, day, part, request.body) {
+            Ok(answer) => Response::text(200, answer),
+            Err(error) => Response::error(400, error),
+        },
+        ["solve-batch"] => match batch::solve_batch(request.body) {
+            Ok(body) => Response {
+                status: 200,
+                content_type: "application/json",
+                body,
+            },
+            Err(error) => Response::error(400, error),
+        },
+        _ => Response::error(404, format!("Not found: {}", request.path)),
+    }
+}
+
+#[test]
+fn test_routes() {
+    let get = |path| {
+        handle(&Request {
+            method: Method::Get,
+            path,
+            body: "",
+        })
+    };
+
+    assert_eq!(get("/health").body, "OK");
+    assert_eq!(get("/version").body, VERSION);
+    assert_eq!(get("/worker-version").body, VERSION);
+    assert_eq!(get("/").content_type, "text/html");
+    assert_eq!(get("/missing").status, 404);
+    assert_eq!(get("/solve/2019/1/1").status, 405);
+
+    let preflight = handle(&Request {
+        method: Method::Options,
+        path: "/solve/2019/1/1",
+        body: "",
+    });
+    assert_eq!(preflight.status, 204);
+
+    let error = handle(&Request {
+        method: Method::Post,
+        path: "/solve/2019/1/3",
+        body: "14",
+    });
+    assert_eq!(
+        error,
+        Response::error(400, "Invalid part 3 - must be 1-2".to_string())
+    );
+}
@@ -0,0 +1,150 @@
+//! Runs the API against a minimal local HTTP/1.1 stand-in server, mounting the
+//! shared router the same way as the real servers do.
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+use advent_of_code_api::{handle, Method, Request, VERSION};
+
+fn start_stand_in() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            serve(stream.unwrap());
+        }
+    });
+    address
+}
+
+fn serve(stream: TcpStream) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let mut parts = request_line.split_whitespace();
+    let method = Method::parse(parts.next().unwrap());
+    let path = parts.next().unwrap().to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    let body = String::from_utf8(body).unwrap();
+
+    let response = handle(&Request {
+        method,
+        path: &path,
+        body: &body,
+    });
+
+    let mut writer = stream;
+    write!(writer, "HTTP/1.1 {} Status\r\n", response.status).unwrap();
+    for (name, value) in response.headers() {
+        write!(writer, "{name}: {value}\r\n").unwrap();
+    }
+    write!(
+        writer,
+        "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.body.len(),
+        response.body
+    )
+    .unwrap();
+}
+
+struct ClientResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+fn request(address: &str, method: &str, path: &str, body: &str) -> ClientResponse {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .nth(1)
+        .unwrap()
+        .parse()
+        .unwrap();
+    let headers = lines
+        .filter_map(|line| line.split_once(": "))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    ClientResponse {
+        status,
+        headers,
+        body: body.to_string(),
+    }
+}
+
+#[test]
+fn solve_and_metadata_routes() {
+    let address = start_stand_in();
+
+    let response = request(&address, "POST", "/solve/2019/1/1", "14");
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, "2");
+    assert!(response
+        .headers
+        .iter()
+        .any(|(name, value)| name == "Access-Control-Allow-Origin" && value == "*"));
+
+    let response = request(&address, "POST", "/solve/2019/1/1", "");
+    assert_eq!(response.status, 400);
+    assert_eq!(response.body, "Empty input");
+
+    let response = request(&address, "GET", "/version", "");
+    assert_eq!((response.status, response.body.as_str()), (200, VERSION));
+
+    let response = request(&address, "GET", "/health", "");
+    assert_eq!((response.status, response.body.as_str()), (200, "OK"));
+
+    let response = request(&address, "DELETE", "/health", "");
+    assert_eq!(response.status, 405);
+
+    let response = request(&address, "GET", "/unknown", "");
+    assert_eq!(response.status, 404);
+}
+
+#[test]
+fn batch_route() {
+    let address = start_stand_in();
+
+    let response = request(
+        &address,
+        "POST",
+        "/solve-batch",
+        r#"[{"year":2019,"day":1,"part":1,"input":"14"},{"year":2019,"day":1,"part":2,"input":"1969"},{"year":2019,"day":26,"part":1,"input":"14"}]"#,
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body,
+        r#"[{"answer":"2"},{"answer":"966"},{"error":"Invalid day 26 - must be 1-25"}]"#
+    );
+
+    let response = request(&address, "POST", "/solve-batch", "not json");
+    assert_eq!(response.status, 400);
+    assert!(response.body.starts_with("Invalid batch"));
+}
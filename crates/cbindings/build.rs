@@ -3,7 +3,7 @@ extern crate cbindgen;
 use std::env;
 use std::path::PathBuf;
 
-use cbindgen::Config;
+use cbindgen::{Config, EnumConfig, RenameRule};
 
 fn main() {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
@@ -13,6 +13,11 @@ fn main() {
 
     let config = Config {
         cpp_compat: true,
+        enumeration: EnumConfig {
+            rename_variants: RenameRule::ScreamingSnakeCase,
+            prefix_with_name: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -20,6 +25,10 @@ fn main() {
         .with_crate(crate_dir)
         .with_config(config)
         .with_include_guard("ADVENT_OF_CODE_H")
+        .with_header(format!(
+            "/* Generated header for advent-of-code {} - do not edit. */",
+            env::var("CARGO_PKG_VERSION").unwrap()
+        ))
         .with_language(cbindgen::Language::C)
         .generate()
         .expect("Unable to generate bindings")
@@ -1,5 +1,10 @@
 #include <advent-of-code.h>
 #include <stdio.h>
+#include <string.h>
+
+#if ADVENT_OF_CODE_ABI_VERSION != 1
+#error "Unexpected ABI version"
+#endif
 
 void test(uint16_t year, uint8_t day, uint8_t part, const char* input) {
     bool ok;
@@ -8,10 +13,23 @@ void test(uint16_t year, uint8_t day, uint8_t part, const char* input) {
 
     printf("%d-%d-%d: Input='%s' -> ok=%s, output='%s'\n", year, day, part, input, ok ? "true" : "false", result);
 
-    free(result);
+    advent_of_code_free_string(result);
+}
+
+void test_result(uint16_t year, uint8_t day, uint8_t part, const char* input) {
+    AdventOfCodeResult result = advent_of_code_solve_result(year, day, part, input);
+
+    printf("%d-%d-%d: Input='%s' -> error_code=%d, output='%s'\n", year, day, part, input, result.error_code, result.output);
+
+    advent_of_code_free_result(result);
 }
 
 int main() {
+    if (advent_of_code_abi_version() != ADVENT_OF_CODE_ABI_VERSION) {
+        printf("Header and library ABI versions differ\n");
+        return 1;
+    }
+
     test(2019, 1, 1, "14");
     test(2019, 1, 1, "hej");
     test(2019, 1, 1, "");
@@ -19,5 +37,29 @@ int main() {
     test(2019, 1, 1, NULL);
     test(2019, 1, 1, "\xc3\x28");
     test(2021, 7, 2, "16,1,2,0,4,2,7,1,2,14");
+
+    // The ok pointer is optional:
+    advent_of_code_free_string(advent_of_code_solve(2019, 1, 1, "14", NULL));
+    // Freeing NULL does nothing:
+    advent_of_code_free_string(NULL);
+
+    test_result(2019, 1, 1, "14");
+    test_result(2019, 1, 1, NULL);
+    test_result(2019, 1, 1, "\xc3\x28");
+    test_result(2019, 26, 1, "14");
+    test_result(2019, 1, 3, "14");
+    test_result(2019, 1, 1, "hej");
+
+    size_t count = advent_of_code_list_puzzles(NULL, 0);
+    AdventOfCodePuzzle* puzzles = malloc(count * sizeof(AdventOfCodePuzzle));
+    if (advent_of_code_list_puzzles(puzzles, count) != count) {
+        printf("Puzzle count differs\n");
+        return 1;
+    }
+    printf("Supported puzzles: %zu, first=%d-%d, last=%d-%d\n", count, puzzles[0].year, puzzles[0].day, puzzles[count - 1].year, puzzles[count - 1].day);
+    free(puzzles);
+
+    const char* version = advent_of_code_version();
+    printf("Version is non-empty: %s\n", strlen(version) > 0 ? "true" : "false");
     return 0;
 }
@@ -1,10 +1,91 @@
 extern crate libc;
 
-use libc::c_char;
+use libc::{c_char, size_t};
 
 use std::ffi::CStr;
 use std::ffi::CString;
 
+use advent_of_code::{check_puzzle, supported_puzzles};
+
+/// The version of the ABI exposed in the generated header.
+///
+/// Incremented on every incompatible change to the exported functions or types.
+/// Compare with advent_of_code_abi_version() to check that a header matches the linked library.
+pub const ADVENT_OF_CODE_ABI_VERSION: u32 = 1;
+
+static VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// The outcome of solving a problem.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AdventOfCodeErrorCode {
+    /// The problem was solved.
+    Ok = 0,
+    /// A required pointer argument was NULL.
+    NullArgument = 1,
+    /// The input was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The year, day or part does not specify a supported problem.
+    UnsupportedPuzzle = 3,
+    /// The input to the problem could not be solved.
+    InvalidInput = 4,
+}
+
+/// The result of solving a problem.
+///
+/// Free with advent_of_code_free_result().
+#[repr(C)]
+pub struct AdventOfCodeResult {
+    /// Whether solving succeeded, and if not, why.
+    pub error_code: AdventOfCodeErrorCode,
+    /// The answer if `error_code` is `ADVENT_OF_CODE_ERROR_CODE_OK`, otherwise an error message.
+    pub output: *mut c_char,
+}
+
+/// A supported problem, as returned from advent_of_code_list_puzzles().
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AdventOfCodePuzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+fn to_c_string(value: String) -> *mut c_char {
+    // Answers and error messages never contain interior NUL bytes.
+    CString::new(value).unwrap_or_default().into_raw()
+}
+
+fn solve_to_result(year: u16, day: u8, part: u8, input: *const c_char) -> AdventOfCodeResult {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+    let (error_code, output) = if input.is_null() {
+        (
+            AdventOfCodeErrorCode::NullArgument,
+            "Input is NULL".to_string(),
+        )
+    } else {
+        let c_str = unsafe { CStr::from_ptr(input) };
+        match c_str.to_str() {
+            Err(error) => (
+                AdventOfCodeErrorCode::InvalidUtf8,
+                format!("Invalid UTF-8 input: {error}"),
+            ),
+            Ok(input_string) => match check_puzzle(year, day, part) {
+                Err(error) => (AdventOfCodeErrorCode::UnsupportedPuzzle, error),
+                Ok(()) => match advent_of_code::solve(year, day, part, input_string) {
+                    Ok(answer) => (AdventOfCodeErrorCode::Ok, answer),
+                    Err(error) => (AdventOfCodeErrorCode::InvalidInput, error),
+                },
+            },
+        }
+    };
+
+    AdventOfCodeResult {
+        error_code,
+        output: to_c_string(output),
+    }
+}
+
 /// Returns the answer for the specified problem and input.
 ///
 /// Arguments:
@@ -12,12 +93,11 @@ use std::ffi::CString;
 /// day: The day of the problem, from 1 to 25.
 /// part: The part of the problem, either 1 or 2.
 /// input: The input to the problem.
+/// ok: Set to whether the problem was solved. May be NULL.
 ///
 /// Returns:
-/// The computed answer as text.
-///
-/// Raises:
-/// ValueError: If the input was invalid.
+/// The computed answer as text if solved, otherwise an error message.
+/// Free with advent_of_code_free_string().
 #[no_mangle]
 pub extern "C" fn advent_of_code_solve(
     year: u16,
@@ -26,37 +106,83 @@ pub extern "C" fn advent_of_code_solve(
     input: *const c_char,
     ok: *mut bool,
 ) -> *mut c_char {
-    #![allow(clippy::unwrap_used, clippy::not_unsafe_ptr_arg_deref)]
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-    use advent_of_code::solve;
+    let result = solve_to_result(year, day, part, input);
+    if !ok.is_null() {
+        unsafe { *ok = result.error_code == AdventOfCodeErrorCode::Ok };
+    }
+    result.output
+}
+
+/// Returns the result of solving the specified problem and input.
+///
+/// Arguments:
+/// year: The year of the problem, as in 2018 or 2019.
+/// day: The day of the problem, from 1 to 25.
+/// part: The part of the problem, either 1 or 2.
+/// input: The input to the problem.
+///
+/// Returns:
+/// The error code together with the answer or error message.
+/// Free with advent_of_code_free_result().
+#[no_mangle]
+pub extern "C" fn advent_of_code_solve_result(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const c_char,
+) -> AdventOfCodeResult {
+    solve_to_result(year, day, part, input)
+}
 
-    if input.is_null() {
-        unsafe { *ok = false };
-        let c_str_result = CString::new("Input is NULL").unwrap();
-        return c_str_result.into_raw();
+/// Frees a string returned from advent_of_code_solve(). Does nothing if NULL.
+#[no_mangle]
+pub extern "C" fn advent_of_code_free_string(string: *mut c_char) {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
     }
+}
 
-    let c_str = unsafe { CStr::from_ptr(input) };
-    let input_string = match c_str.to_str() {
-        Ok(value) => value,
-        Err(error) => {
-            unsafe { *ok = false };
-            let c_str_result = CString::new(format!("Invalid UTF-8 input: {error}")).unwrap();
-            return c_str_result.into_raw();
-        }
-    };
+/// Frees the output of a result returned from advent_of_code_solve_result().
+#[no_mangle]
+pub extern "C" fn advent_of_code_free_result(result: AdventOfCodeResult) {
+    advent_of_code_free_string(result.output);
+}
 
-    let result = solve(year, day, part, input_string);
-    match result {
-        Ok(value) => {
-            unsafe { *ok = true };
-            let c_str_result = CString::new(value).unwrap();
-            c_str_result.into_raw()
-        }
-        Err(value) => {
-            unsafe { *ok = false };
-            let c_str_result = CString::new(value).unwrap();
-            c_str_result.into_raw()
+/// Returns the ABI version of the library, to compare with ADVENT_OF_CODE_ABI_VERSION.
+#[no_mangle]
+pub extern "C" fn advent_of_code_abi_version() -> u32 {
+    ADVENT_OF_CODE_ABI_VERSION
+}
+
+/// Returns the version of the library as a static string which should not be freed.
+#[no_mangle]
+pub extern "C" fn advent_of_code_version() -> *const c_char {
+    VERSION.as_ptr().cast()
+}
+
+/// Lists the supported problems.
+///
+/// Arguments:
+/// puzzles: Buffer to write supported problems into. May be NULL if capacity is 0.
+/// capacity: The number of problems the buffer has room for.
+///
+/// Returns:
+/// The total number of supported problems, which may be larger than capacity.
+#[no_mangle]
+pub extern "C" fn advent_of_code_list_puzzles(
+    puzzles: *mut AdventOfCodePuzzle,
+    capacity: size_t,
+) -> size_t {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+    let mut count = 0;
+    for (year, day) in supported_puzzles() {
+        if count < capacity && !puzzles.is_null() {
+            unsafe { *puzzles.add(count) = AdventOfCodePuzzle { year, day } };
         }
+        count += 1;
     }
+    count
 }
@@ -16,5 +16,5 @@ $CC -I ../target/ -L ../../../target/debug/ main.c $LIBS -o ../target/example
 diff -u expected-output.txt generated-output.txt
 
 if [ `uname` = Linux ]; then
-    valgrind  ../target/example
+    valgrind --error-exitcode=1 --leak-check=full ../target/example
 fi
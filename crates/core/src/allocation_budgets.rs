@@ -0,0 +1,204 @@
+//! Checks the number of allocations and the peak heap size of every solution on its bundled
+//! input against the budgets in `allocation_budgets.tsv`, failing if a change increases them.
+//!
+//! Run with `cargo test --release --features count-allocations allocation_budgets`, setting
+//! `UPDATE_ALLOCATION_BUDGETS=1` to rewrite the budget file after an intended change.
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+const BUDGETS_FILE: &str = "allocation_budgets.tsv";
+
+/// Solutions iterating over randomly seeded hash maps vary between runs, so each solution is
+/// measured this many times. Budgets are written from the largest measurements, while checks
+/// use the smallest ones.
+const RUNS: usize = 3;
+
+/// How much the smallest measurement may exceed its budget before failing.
+const TOLERANCE_PERCENT: u64 = 2;
+
+/// Small peaks depend on the order of allocations, so the peak may also exceed its budget by
+/// this many bytes (2015 day 19 part 2 peaks anywhere between 5.7 and 8 KiB).
+const PEAK_TOLERANCE_BYTES: u64 = 4096;
+
+#[derive(Clone, Copy)]
+struct Budget {
+    allocations: u64,
+    peak_bytes: u64,
+}
+
+impl Budget {
+    const fn exceeds(self, budget: Self) -> bool {
+        const fn with_tolerance(value: u64) -> u64 {
+            value + value * TOLERANCE_PERCENT / 100
+        }
+        let max_peak_bytes =
+            if with_tolerance(budget.peak_bytes) > budget.peak_bytes + PEAK_TOLERANCE_BYTES {
+                with_tolerance(budget.peak_bytes)
+            } else {
+                budget.peak_bytes + PEAK_TOLERANCE_BYTES
+            };
+        self.allocations > with_tolerance(budget.allocations) || self.peak_bytes > max_peak_bytes
+    }
+
+    fn combine(self, other: Self, pick: fn(u64, u64) -> u64) -> Self {
+        Self {
+            allocations: pick(self.allocations, other.allocations),
+            peak_bytes: pick(self.peak_bytes, other.peak_bytes),
+        }
+    }
+}
+
+type Problem = (u16, u8, u8);
+
+fn parse_budgets(text: &str) -> Result<BTreeMap<Problem, Budget>, String> {
+    let mut budgets = BTreeMap::new();
+    for (line_index, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let on_line = || format!("{BUDGETS_FILE} line {}: Invalid '{line}'", line_index + 1);
+        let fields = line.split('\t').collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(on_line());
+        }
+        let problem = (
+            fields[0].parse().map_err(|_| on_line())?,
+            fields[1].parse().map_err(|_| on_line())?,
+            fields[2].parse().map_err(|_| on_line())?,
+        );
+        let budget = Budget {
+            allocations: fields[3].parse().map_err(|_| on_line())?,
+            peak_bytes: fields[4].parse().map_err(|_| on_line())?,
+        };
+        budgets.insert(problem, budget);
+    }
+    Ok(budgets)
+}
+
+fn format_budgets(budgets: &BTreeMap<Problem, Budget>) -> String {
+    let mut result = "# Generated by the allocation_budgets test - see src/allocation_budgets.rs\n\
+         # year\tday\tpart\tallocations\tpeak_bytes\n"
+        .to_string();
+    for ((year, day, part), budget) in budgets {
+        let _ = writeln!(
+            result,
+            "{year}\t{day}\t{part}\t{}\t{}",
+            budget.allocations, budget.peak_bytes
+        );
+    }
+    result
+}
+
+fn measure((year, day, part): Problem, input: &str) -> Budget {
+    let info = allocation_counter::measure(|| {
+        assert!(
+            crate::solve(year, day, part, input).is_ok(),
+            "Failed solving {year} day {day} part {part}"
+        );
+    });
+    Budget {
+        allocations: info.count_total,
+        peak_bytes: info.bytes_max,
+    }
+}
+
+#[test]
+fn allocation_budgets() {
+    #![allow(clippy::unwrap_used)]
+    let source_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let budgets_path = source_directory.join(BUDGETS_FILE);
+
+    let updating = std::env::var_os("UPDATE_ALLOCATION_BUDGETS").is_some();
+    let mut measured = BTreeMap::new();
+    for year in 2015..=2022 {
+        for day in 1..=25 {
+            let input_path = source_directory.join(format!("year{year}/day{day:02}_input.txt"));
+            if let Ok(input) = std::fs::read_to_string(&input_path) {
+                for part in 1..=if day == 25 { 1 } else { 2 } {
+                    let problem = (year, day, part);
+                    let pick = if updating { u64::max } else { u64::min };
+                    let budget = (1..RUNS).fold(measure(problem, &input), |budget, _| {
+                        budget.combine(measure(problem, &input), pick)
+                    });
+                    measured.insert(problem, budget);
+                }
+            }
+        }
+    }
+
+    if updating {
+        std::fs::write(&budgets_path, format_budgets(&measured)).unwrap();
+        return;
+    }
+
+    let budgets = parse_budgets(&std::fs::read_to_string(&budgets_path).unwrap()).unwrap();
+    let mut failures = Vec::new();
+    for (&(year, day, part), measured) in &measured {
+        match budgets.get(&(year, day, part)) {
+            None => failures.push(format!("{year} day {day} part {part}: No budget")),
+            Some(budget) if measured.exceeds(*budget) => {
+                failures.push(format!(
+                    "{year} day {day} part {part}: {} allocations and {} peak bytes, \
+                     budget is {} allocations and {} peak bytes",
+                    measured.allocations,
+                    measured.peak_bytes,
+                    budget.allocations,
+                    budget.peak_bytes
+                ));
+            }
+            _ => {}
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Allocation budgets exceeded:\n{}\n\
+         Run with UPDATE_ALLOCATION_BUDGETS=1 to update {BUDGETS_FILE} if this is intended.",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn budget_file_format() {
+    #![allow(clippy::unwrap_used)]
+    let text = "# comment\n2019\t5\t2\t10\t2048\n";
+    let budgets = parse_budgets(text).unwrap();
+    assert_eq!(budgets.len(), 1);
+    let budget = budgets[&(2019, 5, 2)];
+    assert_eq!((budget.allocations, budget.peak_bytes), (10, 2048));
+    assert!(format_budgets(&budgets).ends_with("2019\t5\t2\t10\t2048\n"));
+
+    let within_tolerance = Budget {
+        allocations: 10,
+        peak_bytes: 2048 + 4096,
+    };
+    assert!(!within_tolerance.exceeds(budget));
+    let more_allocations = Budget {
+        allocations: 11,
+        ..within_tolerance
+    };
+    assert!(more_allocations.exceeds(budget));
+    let larger_peak = Budget {
+        peak_bytes: 2048 + 4097,
+        ..within_tolerance
+    };
+    assert!(larger_peak.exceeds(budget));
+    let combined = more_allocations.combine(larger_peak, u64::max);
+    assert_eq!(
+        (combined.allocations, combined.peak_bytes),
+        (11, 2048 + 4097)
+    );
+    let large_budget = Budget {
+        allocations: 10,
+        peak_bytes: 1_000_000,
+    };
+    let peak_of = |peak_bytes| Budget {
+        allocations: 10,
+        peak_bytes,
+    };
+    assert!(!peak_of(1_020_000).exceeds(large_budget));
+    assert!(peak_of(1_020_001).exceeds(large_budget));
+
+    assert!(parse_budgets("2019\t5\t2\t10").is_err());
+    assert!(parse_budgets("2019\t5\t2\tmany\t2048").is_err());
+}
@@ -0,0 +1,142 @@
+//! Subcommands of the command line tool operating on input files and directories.
+pub mod add_fuzz_case;
+pub mod bench;
+pub mod json;
+pub mod render;
+pub mod run;
+pub mod solve;
+pub mod verify;
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// A problem input found in a directory laid out like `year2019/day05_input.txt`.
+pub struct InputFile {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+impl InputFile {
+    /// The input of a problem in a directory, whether it exists or not.
+    pub fn in_directory(directory: &Path, year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            path: directory
+                .join(format!("year{year}"))
+                .join(format!("day{day:02}_input.txt")),
+        }
+    }
+
+    /// The parts of the problem - the last day of a year only has one part.
+    pub const fn parts(&self) -> std::ops::RangeInclusive<u8> {
+        1..=if self.day == 25 { 1 } else { 2 }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        std::fs::read_to_string(&self.path)
+            .map_err(|error| format!("Unable to read {}: {error}", self.path.display()))
+    }
+}
+
+fn parse_prefixed<T: std::str::FromStr>(name: &str, prefix: &str, suffix: &str) -> Option<T> {
+    let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Infer the year and day of an input from a path laid out like `year2019/day05_input.txt`,
+/// where either of them may be missing.
+pub fn infer_puzzle(path: &Path) -> (Option<u16>, Option<u8>) {
+    let year = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+        .and_then(|name| parse_prefixed(name, "year", ""));
+    let day = path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| parse_prefixed(name, "day", "_input.txt"));
+    (year, day)
+}
+
+fn sorted_entries(directory: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let entries = std::fs::read_dir(directory)
+        .map_err(|error| format!("Unable to read {}: {error}", directory.display()))?;
+    let mut result = Vec::new();
+    for entry in entries {
+        let entry =
+            entry.map_err(|error| format!("Unable to read {}: {error}", directory.display()))?;
+        if let Some(name) = entry.file_name().to_str() {
+            result.push((name.to_string(), entry.path()));
+        }
+    }
+    result.sort();
+    Ok(result)
+}
+
+/// Find the inputs in a directory, ordered by year and day.
+pub fn find_inputs(directory: &Path) -> Result<Vec<InputFile>, String> {
+    let mut inputs = Vec::new();
+    for (name, year_path) in sorted_entries(directory)? {
+        let year = match parse_prefixed::<u16>(&name, "year", "") {
+            Some(year) if year_path.is_dir() => year,
+            _ => continue,
+        };
+        for (name, path) in sorted_entries(&year_path)? {
+            if let Some(day @ 1..=25) = parse_prefixed::<u8>(&name, "day", "_input.txt") {
+                inputs.push(InputFile { year, day, path });
+            }
+        }
+    }
+    inputs.sort_by_key(|input| (input.year, input.day));
+    Ok(inputs)
+}
+
+#[test]
+fn test_find_inputs() {
+    #![allow(clippy::unwrap_used)]
+    let inputs = find_inputs(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src")).unwrap();
+    assert_eq!(
+        inputs
+            .iter()
+            .map(|input| (input.year, input.day))
+            .collect::<Vec<_>>(),
+        advent_of_code::supported_puzzles().collect::<Vec<_>>()
+    );
+    assert!(inputs[0].path.ends_with("year2015/day01_input.txt"));
+    let last = inputs.len() - 1;
+    assert_eq!(inputs[last].day, 25);
+    assert_eq!(inputs[last].parts(), 1..=1);
+    assert_eq!(inputs[last - 1].parts(), 1..=2);
+
+    assert_eq!(
+        parse_prefixed::<u8>("day05_input.txt", "day", "_input.txt"),
+        Some(5)
+    );
+    assert_eq!(
+        parse_prefixed::<u8>("day05_other_input.txt", "day", "_input.txt"),
+        None
+    );
+    assert_eq!(parse_prefixed::<u16>("year+2019", "year", ""), None);
+}
+
+#[test]
+fn test_infer_puzzle() {
+    assert_eq!(
+        infer_puzzle(Path::new("src/year2019/day07_input.txt")),
+        (Some(2019), Some(7))
+    );
+    assert_eq!(
+        infer_puzzle(Path::new("year2022/day25_input.txt")),
+        (Some(2022), Some(25))
+    );
+    assert_eq!(infer_puzzle(Path::new("day07_input.txt")), (None, Some(7)));
+    assert_eq!(
+        infer_puzzle(Path::new("year2019/input.txt")),
+        (Some(2019), None)
+    );
+}
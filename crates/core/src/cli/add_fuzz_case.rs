@@ -0,0 +1,187 @@
+//! The `add-fuzz-case` subcommand, minimizing inputs found by fuzzing and adding them to the
+//! corpus of regression cases replayed by the tests.
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+pub const USAGE: &str =
+    "advent-of-code add-fuzz-case [--corpus directory] [--time-limit seconds] fuzzer-file...";
+
+/// The corpus directory in the source tree, replayed by the `fuzz_corpus` test.
+const DEFAULT_CORPUS_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fuzz-corpus");
+
+#[cfg(feature = "fuzzing")]
+fn decode(data: &[u8]) -> Result<(u16, u8, u8, String), String> {
+    advent_of_code::fuzzing::decode(data).ok_or_else(|| "Not a valid fuzzer input".to_string())
+}
+
+#[cfg(not(feature = "fuzzing"))]
+fn decode(_data: &[u8]) -> Result<(u16, u8, u8, String), String> {
+    Err("Built without the fuzzing feature needed to decode fuzzer inputs".to_string())
+}
+
+/// Whether solving an input crashes or exceeds the time limit, solved in a child process to
+/// survive both.
+fn fails(year: u16, day: u8, part: u8, input: &str, time_limit: Duration) -> Result<bool, String> {
+    let executable = std::env::current_exe()
+        .map_err(|error| format!("Unable to find the current executable: {error}"))?;
+    let mut child = Command::new(executable)
+        .args([year.to_string(), day.to_string(), part.to_string()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|error| format!("Unable to start solving: {error}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The child may crash before reading all of its input:
+        let _ = stdin.write_all(input.as_bytes());
+    }
+
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Ok(!status.success()),
+            Ok(None) if start.elapsed() > time_limit => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Ok(true);
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+            Err(error) => return Err(format!("Unable to wait for solving: {error}")),
+        }
+    }
+}
+
+/// Remove chunks of units, halving the chunk size down to single units, for as long as the
+/// joined result keeps failing.
+fn remove_chunks(
+    mut units: Vec<String>,
+    separator: &str,
+    still_fails: &mut impl FnMut(&str) -> Result<bool, String>,
+) -> Result<Vec<String>, String> {
+    let mut chunk_size = (units.len() / 2).max(1);
+    loop {
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + chunk_size).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && still_fails(&candidate.join(separator))? {
+                units = candidate;
+            } else {
+                start = end;
+            }
+        }
+        if chunk_size == 1 {
+            return Ok(units);
+        }
+        chunk_size /= 2;
+    }
+}
+
+/// Minimize a failing input, first removing lines and then characters.
+fn minimize(
+    input: &str,
+    still_fails: &mut impl FnMut(&str) -> Result<bool, String>,
+) -> Result<String, String> {
+    let lines = remove_chunks(
+        input.lines().map(str::to_string).collect(),
+        "\n",
+        still_fails,
+    )?;
+    let characters = remove_chunks(
+        lines.join("\n").chars().map(String::from).collect(),
+        "",
+        still_fails,
+    )?;
+    Ok(characters.concat())
+}
+
+/// The file name of a corpus case, including a hash of the input to tell cases apart.
+fn case_file_name(year: u16, day: u8, part: u8, input: &str) -> String {
+    // The 64-bit FNV-1a hash:
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("year{year}_day{day:02}_part{part}_{hash:016x}.txt")
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut corpus_directory = Path::new(DEFAULT_CORPUS_DIRECTORY);
+    let mut time_limit = Duration::from_secs(10);
+    let mut paths = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut option_value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg} - usage: {USAGE}"))
+        };
+        match arg.as_str() {
+            "--corpus" => corpus_directory = Path::new(option_value()?),
+            "--time-limit" => {
+                time_limit = option_value()?
+                    .parse()
+                    .ok()
+                    .filter(|&seconds: &f64| seconds > 0.)
+                    .map(Duration::from_secs_f64)
+                    .ok_or_else(|| format!("Invalid value for {arg} - usage: {USAGE}"))?;
+            }
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            path => paths.push(Path::new(path)),
+        }
+    }
+    if paths.is_empty() {
+        return Err(format!("Missing fuzzer file - usage: {USAGE}"));
+    }
+
+    std::fs::create_dir_all(corpus_directory)
+        .map_err(|error| format!("Unable to create {}: {error}", corpus_directory.display()))?;
+    for path in paths {
+        let data = std::fs::read(path)
+            .map_err(|error| format!("Unable to read {}: {error}", path.display()))?;
+        let (year, day, part, input) =
+            decode(&data).map_err(|error| format!("{}: {error}", path.display()))?;
+        let input = input.trim_end();
+
+        let mut still_fails = |candidate: &str| fails(year, day, part, candidate, time_limit);
+        let case = if still_fails(input)? {
+            minimize(input, &mut still_fails)?
+        } else {
+            println!(
+                "{}: {year} day {day} part {part} no longer fails, adding it unminimized",
+                path.display()
+            );
+            input.to_string()
+        };
+
+        let case_path = corpus_directory.join(case_file_name(year, day, part, &case));
+        std::fs::write(&case_path, format!("{case}\n"))
+            .map_err(|error| format!("Unable to write {}: {error}", case_path.display()))?;
+        println!(
+            "{}: Added {} ({} bytes, from {})",
+            path.display(),
+            case_path.display(),
+            case.len(),
+            input.len()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_minimize() {
+    let mut still_fails =
+        |candidate: &str| Ok(candidate.contains("bad") && candidate.contains('!'));
+    assert_eq!(
+        minimize("good\nvery bad\nfine!\nok", &mut still_fails),
+        Ok("bad!".to_string())
+    );
+
+    let file_name = case_file_name(2019, 5, 2, "1,2,3");
+    assert!(file_name.starts_with("year2019_day05_part2_"));
+    assert!(file_name.ends_with(".txt"));
+    assert_ne!(file_name, case_file_name(2019, 5, 2, "1,2,4"));
+}
@@ -0,0 +1,341 @@
+//! The `bench` subcommand, timing solutions and comparing them against a saved baseline.
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::find_inputs;
+use super::json::{parse_json, JsonValue};
+
+pub const USAGE: &str = "advent-of-code bench [--iterations N] [--warmup N] [--baseline file] \
+    [--save-baseline file] [--threshold percent] [directory] [year[/day[/part]]...]";
+
+/// A selection of problems to benchmark, such as `2019`, `2019/5` or `2019/5/2`.
+struct Selector {
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+}
+
+impl Selector {
+    fn parse(value: &str) -> Option<Self> {
+        let mut components = value.split('/');
+        let year = components.next()?.parse().ok()?;
+        let day = components.next().map(str::parse).transpose().ok()?;
+        let part = components.next().map(str::parse).transpose().ok()?;
+        if components.next().is_some() {
+            return None;
+        }
+        Some(Self { year, day, part })
+    }
+
+    fn matches(&self, year: u16, day: u8, part: u8) -> bool {
+        self.year == year
+            && self.day.map_or(true, |d| d == day)
+            && self.part.map_or(true, |p| p == part)
+    }
+}
+
+/// Timing statistics of a benchmarked part.
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p99_ms: f64,
+    /// The number of allocations made when solving, if built with the
+    /// `count-allocations` feature.
+    pub allocations: Option<u64>,
+}
+
+impl Measurement {
+    fn from_durations(year: u16, day: u8, part: u8, mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let milliseconds = |index: usize| durations[index].as_secs_f64() * 1000.;
+        let count = durations.len();
+        Self {
+            year,
+            day,
+            part,
+            min_ms: milliseconds(0),
+            median_ms: milliseconds((count - 1) / 2),
+            // The nearest-rank 99th percentile:
+            p99_ms: milliseconds((count * 99 + 99) / 100 - 1),
+            allocations: None,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"year\": {}, \"day\": {}, \"part\": {}, \"min_ms\": {:.6}, \"median_ms\": {:.6}, \"p99_ms\": {:.6}, \"allocations\": {}}}",
+            self.year,
+            self.day,
+            self.part,
+            self.min_ms,
+            self.median_ms,
+            self.p99_ms,
+            self.allocations
+                .map_or_else(|| "null".to_string(), |count| count.to_string())
+        )
+    }
+
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        let number = |key: &str| value.get(key).and_then(JsonValue::as_f64);
+        Some(Self {
+            year: number("year")? as u16,
+            day: number("day")? as u8,
+            part: number("part")? as u8,
+            min_ms: number("min_ms")?,
+            median_ms: number("median_ms")?,
+            p99_ms: number("p99_ms")?,
+            allocations: number("allocations").map(|count| count as u64),
+        })
+    }
+
+    /// Compare against a baseline, returning a description and whether it is a regression.
+    fn compare(&self, baseline: &Self, threshold_percent: f64) -> (String, bool) {
+        let change_percent = (self.median_ms / baseline.median_ms - 1.) * 100.;
+        let slower = change_percent > threshold_percent;
+        let mut description = format!("{change_percent:+.1}%");
+        let more_allocations = match (self.allocations, baseline.allocations) {
+            (Some(allocations), Some(baseline_allocations))
+                if allocations > baseline_allocations =>
+            {
+                description.push_str(&format!(
+                    ", {} allocations (was {baseline_allocations})",
+                    allocations
+                ));
+                true
+            }
+            _ => false,
+        };
+        if slower || more_allocations {
+            description.push_str(" REGRESSION");
+        }
+        (description, slower || more_allocations)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+fn count_allocations(function: impl FnOnce()) -> Option<u64> {
+    Some(allocation_counter::measure(function).count_total)
+}
+
+#[cfg(not(feature = "count-allocations"))]
+fn count_allocations(_function: impl FnOnce()) -> Option<u64> {
+    None
+}
+
+/// Benchmark a part, returning an error if solving it fails.
+fn benchmark(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Measurement, String> {
+    for _ in 0..warmup {
+        advent_of_code::solve(year, day, part, input)?;
+    }
+    let mut durations = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = advent_of_code::solve(year, day, part, input);
+        durations.push(start.elapsed());
+        std::hint::black_box(result)?;
+    }
+    let mut measurement = Measurement::from_durations(year, day, part, durations);
+    measurement.allocations = count_allocations(|| {
+        std::hint::black_box(advent_of_code::solve(year, day, part, input)).ok();
+    });
+    Ok(measurement)
+}
+
+fn read_baseline(path: &Path) -> Result<Vec<Measurement>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {error}", path.display()))?;
+    let invalid = |error: &str| format!("Invalid baseline {}: {error}", path.display());
+    match parse_json(&text).map_err(|error| invalid(&error))? {
+        JsonValue::Array(elements) => elements
+            .iter()
+            .map(|element| Measurement::from_json(element).ok_or_else(|| invalid("Invalid entry")))
+            .collect(),
+        _ => Err(invalid("Expected an array")),
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut iterations = 10;
+    let mut warmup = 2;
+    let mut threshold_percent = 10.;
+    let mut baseline_path = None;
+    let mut save_baseline_path = None;
+    let mut directory = None;
+    let mut selectors = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut option_value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg} - usage: {USAGE}"))
+        };
+        let invalid_value = || format!("Invalid value for {arg} - usage: {USAGE}");
+        match arg.as_str() {
+            "--iterations" => {
+                iterations = option_value()?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(invalid_value)?;
+            }
+            "--warmup" => warmup = option_value()?.parse().map_err(|_| invalid_value())?,
+            "--threshold" => {
+                threshold_percent = option_value()?.parse().map_err(|_| invalid_value())?;
+            }
+            "--baseline" => baseline_path = Some(Path::new(option_value()?)),
+            "--save-baseline" => save_baseline_path = Some(Path::new(option_value()?)),
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            value => match Selector::parse(value) {
+                Some(selector) => selectors.push(selector),
+                None if directory.is_none() => directory = Some(Path::new(value)),
+                None => return Err(format!("Too many arguments - usage: {USAGE}")),
+            },
+        }
+    }
+    let directory = directory.unwrap_or_else(|| Path::new("."));
+    let baseline = baseline_path.map(read_baseline).transpose()?;
+
+    println!("Year Day Part   Min (ms) Median (ms)   P99 (ms)  Allocations  Baseline");
+    let mut measurements = Vec::new();
+    let (mut failures, mut regressions) = (0, 0);
+    for input_file in find_inputs(directory)? {
+        let parts = input_file.parts().filter(|&part| {
+            selectors.is_empty()
+                || selectors
+                    .iter()
+                    .any(|selector| selector.matches(input_file.year, input_file.day, part))
+        });
+        for part in parts {
+            let (year, day) = (input_file.year, input_file.day);
+            let prefix = format!("{year:>4} {day:>3} {part:>4}");
+            let measurement = match input_file
+                .read()
+                .and_then(|input| benchmark(year, day, part, &input, warmup, iterations))
+            {
+                Ok(measurement) => measurement,
+                Err(error) => {
+                    println!("{prefix}  Error: {error}");
+                    failures += 1;
+                    continue;
+                }
+            };
+
+            let comparison = baseline
+                .iter()
+                .flatten()
+                .find(|b| (b.year, b.day, b.part) == (year, day, part))
+                .map_or_else(
+                    || "-".to_string(),
+                    |baseline| {
+                        let (description, regressed) =
+                            measurement.compare(baseline, threshold_percent);
+                        if regressed {
+                            regressions += 1;
+                        }
+                        description
+                    },
+                );
+            println!(
+                "{prefix} {:>10.3} {:>11.3} {:>10.3} {:>12}  {comparison}",
+                measurement.min_ms,
+                measurement.median_ms,
+                measurement.p99_ms,
+                measurement
+                    .allocations
+                    .map_or_else(|| "-".to_string(), |count| count.to_string()),
+            );
+            measurements.push(measurement);
+        }
+    }
+
+    if measurements.is_empty() && failures == 0 {
+        return Err(format!(
+            "No inputs matched in {} - expected files like year2019/day05_input.txt",
+            directory.display()
+        ));
+    }
+
+    if let Some(path) = save_baseline_path {
+        let objects = measurements
+            .iter()
+            .map(Measurement::to_json)
+            .collect::<Vec<_>>();
+        std::fs::write(path, format!("[\n  {}\n]\n", objects.join(",\n  ")))
+            .map_err(|error| format!("Unable to write {}: {error}", path.display()))?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    if failures > 0 || regressions > 0 {
+        return Err(format!(
+            "{failures} failures and {regressions} regressions in {} benchmarks",
+            measurements.len() + failures
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_selector() {
+    #![allow(clippy::unwrap_used)]
+    let selector = Selector::parse("2019/5").unwrap();
+    assert!(selector.matches(2019, 5, 1));
+    assert!(selector.matches(2019, 5, 2));
+    assert!(!selector.matches(2019, 6, 1));
+    assert!(Selector::parse("2019").unwrap().matches(2019, 25, 1));
+    assert!(!Selector::parse("2019/5/2").unwrap().matches(2019, 5, 1));
+    assert!(Selector::parse("inputs").is_none());
+    assert!(Selector::parse("2019/5/2/1").is_none());
+    assert!(Selector::parse("2019/x").is_none());
+}
+
+#[test]
+fn test_measurement() {
+    #![allow(clippy::unwrap_used)]
+    let durations = (1..=200).rev().map(Duration::from_millis).collect();
+    let mut measurement = Measurement::from_durations(2019, 5, 2, durations);
+    assert_eq!(measurement.min_ms, 1.);
+    assert_eq!(measurement.median_ms, 100.);
+    assert_eq!(measurement.p99_ms, 198.);
+    measurement.allocations = Some(3);
+
+    let json = parse_json(&measurement.to_json()).unwrap();
+    let parsed = Measurement::from_json(&json).unwrap();
+    assert_eq!((parsed.year, parsed.day, parsed.part), (2019, 5, 2));
+    assert_eq!(parsed.median_ms, 100.);
+    assert_eq!(parsed.allocations, Some(3));
+
+    let baseline = Measurement {
+        median_ms: 80.,
+        allocations: Some(3),
+        ..parsed
+    };
+    assert_eq!(
+        measurement.compare(&baseline, 10.),
+        ("+25.0% REGRESSION".to_string(), true)
+    );
+    assert_eq!(
+        measurement.compare(&baseline, 30.),
+        ("+25.0%".to_string(), false)
+    );
+    let baseline = Measurement {
+        median_ms: 200.,
+        allocations: Some(2),
+        ..baseline
+    };
+    assert_eq!(
+        measurement.compare(&baseline, 10.),
+        ("-50.0%, 3 allocations (was 2) REGRESSION".to_string(), true)
+    );
+}
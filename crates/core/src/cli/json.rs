@@ -0,0 +1,237 @@
+//! Minimal JSON support for the files read and written by the command line tool.
+
+pub enum JsonValue {
+    Number(f64),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+    /// A string, boolean or null, whose value is not inspected by the files read.
+    Other,
+}
+
+impl JsonValue {
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(entries) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+}
+
+/// Escape a string for inclusion in a JSON string literal.
+pub fn escape_json(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("{message} at byte offset {}", self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.position)
+            .map_or(false, u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    fn consume(&mut self, expected: &str) -> bool {
+        if self.input[self.position..].starts_with(expected.as_bytes()) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        let value = match self.input.get(self.position) {
+            Some(b'{') => {
+                self.position += 1;
+                let mut entries = Vec::new();
+                self.skip_whitespace();
+                if !self.consume("}") {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string()?;
+                        self.skip_whitespace();
+                        if !self.consume(":") {
+                            return Err(self.error("Expected ':'"));
+                        }
+                        entries.push((key, self.value()?));
+                        self.skip_whitespace();
+                        if self.consume("}") {
+                            break;
+                        } else if !self.consume(",") {
+                            return Err(self.error("Expected ',' or '}'"));
+                        }
+                    }
+                }
+                JsonValue::Object(entries)
+            }
+            Some(b'[') => {
+                self.position += 1;
+                let mut elements = Vec::new();
+                self.skip_whitespace();
+                if !self.consume("]") {
+                    loop {
+                        elements.push(self.value()?);
+                        self.skip_whitespace();
+                        if self.consume("]") {
+                            break;
+                        } else if !self.consume(",") {
+                            return Err(self.error("Expected ',' or ']'"));
+                        }
+                    }
+                }
+                JsonValue::Array(elements)
+            }
+            Some(b'"') => {
+                self.string()?;
+                JsonValue::Other
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.position;
+                while self.input.get(self.position).map_or(false, |&b| {
+                    b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E')
+                }) {
+                    self.position += 1;
+                }
+                let number = std::str::from_utf8(&self.input[start..self.position])
+                    .ok()
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| self.error("Invalid number"))?;
+                JsonValue::Number(number)
+            }
+            _ if self.consume("null") || self.consume("true") || self.consume("false") => {
+                JsonValue::Other
+            }
+            _ => return Err(self.error("Expected a value")),
+        };
+        Ok(value)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if !self.consume("\"") {
+            return Err(self.error("Expected '\"'"));
+        }
+        let mut bytes = Vec::new();
+        loop {
+            match self.input.get(self.position) {
+                None => return Err(self.error("Unterminated string")),
+                Some(b'"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    let escaped = match self.input.get(self.position + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let code = self
+                                .input
+                                .get(self.position + 2..self.position + 6)
+                                .and_then(|hex| std::str::from_utf8(hex).ok())
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("Invalid unicode escape"))?;
+                            self.position += 4;
+                            code
+                        }
+                        _ => return Err(self.error("Invalid escape")),
+                    };
+                    self.position += 2;
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buffer).as_bytes());
+                }
+                Some(&b) => {
+                    bytes.push(b);
+                    self.position += 1;
+                }
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("Invalid UTF-8 in string"))
+    }
+}
+
+/// Parse a JSON document.
+pub fn parse_json(input: &str) -> Result<JsonValue, String> {
+    let mut parser = Parser {
+        input: input.as_bytes(),
+        position: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.position != input.len() {
+        return Err(parser.error("Unexpected content after the value"));
+    }
+    Ok(value)
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    assert_eq!(escape_json("a\"b\\c\nd\u{1}é"), "a\\\"b\\\\c\\nd\\u0001é");
+
+    let value = parse_json(
+        " [{\"a\": 1.5e1, \"b\": \"x\\\"\\u00e9\\n\", \"c\": null}, [true, false], -2, {}, []] ",
+    )
+    .unwrap();
+    let elements = match &value {
+        JsonValue::Array(elements) => elements,
+        _ => panic!("Expected an array"),
+    };
+    assert_eq!(elements.len(), 5);
+    assert_eq!(elements[0].get("a").and_then(JsonValue::as_f64), Some(15.));
+    assert!(matches!(elements[0].get("b"), Some(JsonValue::Other)));
+    assert!(matches!(elements[0].get("c"), Some(JsonValue::Other)));
+    assert!(elements[0].get("d").is_none());
+    assert!(
+        matches!(&elements[1], JsonValue::Array(a) if matches!(a[..], [JsonValue::Other, JsonValue::Other]))
+    );
+    assert_eq!(elements[2].as_f64(), Some(-2.));
+    let object = parse_json("{\"x\\\"\\u00e9\\n\": 3}").unwrap();
+    assert_eq!(object.get("x\"é\n").and_then(JsonValue::as_f64), Some(3.));
+
+    assert!(parse_json("[1,]").is_err());
+    assert!(parse_json("{\"a\" 1}").is_err());
+    assert!(parse_json("\"abc").is_err());
+    assert!(parse_json("\"\\x\"").is_err());
+    assert!(parse_json("1 2").is_err());
+}
@@ -0,0 +1,90 @@
+//! The `render` subcommand, rendering the visualization of a solution to a PNG, APNG or GIF image.
+use std::io::Read;
+
+pub const USAGE: &str = "advent-of-code render [--height pixels] [--max-frames count] year day part output.png|output.apng|output.gif < input-file";
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut height = 600;
+    let mut max_frames = 500;
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut option_value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg} - usage: {USAGE}"))
+        };
+        let invalid_value = || format!("Invalid value for {arg} - usage: {USAGE}");
+        match arg.as_str() {
+            "--height" => height = option_value()?.parse().map_err(|_| invalid_value())?,
+            "--max-frames" => max_frames = option_value()?.parse().map_err(|_| invalid_value())?,
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            value => positional.push(value),
+        }
+    }
+
+    let [year, day, part, output_path] = positional[..] else {
+        return Err(format!(
+            "Expected year, day, part and output file - usage: {USAGE}"
+        ));
+    };
+    let year = year.parse::<u16>().map_err(|_| "Invalid year")?;
+    let day = day.parse::<u8>().map_err(|_| "Invalid day")?;
+    let part = part.parse::<u8>().map_err(|_| "Invalid part")?;
+
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|error| format!("Error reading input: {error}"))?;
+
+    let (encoded, frame_count) = render(year, day, part, &input, height, max_frames, output_path)?;
+    std::fs::write(output_path, encoded)
+        .map_err(|error| format!("Error writing {output_path}: {error}"))?;
+    eprintln!("Wrote {frame_count} frame(s) to {output_path}");
+    Ok(())
+}
+
+/// Render the visualization of a solution as an image in the format of the output file,
+/// returning the encoded image and the number of frames in it.
+#[cfg(feature = "raster")]
+fn render(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    height: u32,
+    max_frames: usize,
+    output_path: &str,
+) -> Result<(Vec<u8>, usize), String> {
+    use advent_of_code::painter::raster::RasterPainter;
+    use advent_of_code_raster::{encode_apng, encode_gif, encode_png};
+
+    let mut painter = RasterPainter::new(height).with_max_frames(max_frames);
+    let answer = advent_of_code::solve_with_painter(year, day, part, input, &mut painter)?;
+    println!("{answer}");
+
+    let frames = painter.into_frames();
+    let encoded = match frames.last() {
+        None => return Err(format!("No visualization for year={year}, day={day}")),
+        Some(_) if output_path.ends_with(".gif") => encode_gif(&frames)?,
+        Some(_) if output_path.ends_with(".apng") => encode_apng(&frames)?,
+        Some(last_frame) if output_path.ends_with(".png") => encode_png(last_frame)?,
+        Some(_) => return Err(format!("Unsupported image format - usage: {USAGE}")),
+    };
+    Ok((encoded, frames.len()))
+}
+
+#[cfg(not(feature = "raster"))]
+fn render(
+    _year: u16,
+    _day: u8,
+    _part: u8,
+    _input: &str,
+    _height: u32,
+    _max_frames: usize,
+    _output_path: &str,
+) -> Result<(Vec<u8>, usize), String> {
+    Err("Built without the raster feature needed to render images".to_string())
+}
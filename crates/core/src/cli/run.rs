@@ -0,0 +1,164 @@
+//! The `run` subcommand, solving every input found in a directory.
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::find_inputs;
+use super::json::escape_json;
+
+pub const USAGE: &str = "advent-of-code run [--json] [directory]";
+
+/// The outcome of solving one part of a problem.
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<String, String>,
+    pub duration: Duration,
+}
+
+/// Solve a part of a problem, timing how long it takes.
+pub fn solve_part(year: u16, day: u8, part: u8, input: &str) -> PartResult {
+    let start = Instant::now();
+    let result = advent_of_code::solve(year, day, part, input);
+    PartResult {
+        year,
+        day,
+        part,
+        result,
+        duration: start.elapsed(),
+    }
+}
+
+/// Solve all parts of the inputs in a directory, reporting each result as it is done.
+pub fn solve_directory(
+    directory: &Path,
+    mut on_result: impl FnMut(&PartResult),
+) -> Result<Vec<PartResult>, String> {
+    let inputs = find_inputs(directory)?;
+    if inputs.is_empty() {
+        return Err(format!(
+            "No inputs found in {} - expected files like year2019/day05_input.txt",
+            directory.display()
+        ));
+    }
+
+    let mut results = Vec::new();
+    for input_file in inputs {
+        let input = input_file.read()?;
+        for part in input_file.parts() {
+            let part_result = solve_part(input_file.year, input_file.day, part, &input);
+            on_result(&part_result);
+            results.push(part_result);
+        }
+    }
+    Ok(results)
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.
+}
+
+fn table_row(result: &PartResult) -> String {
+    let outcome = match &result.result {
+        Ok(answer) => answer.replace('\n', "\\n"),
+        Err(error) => format!("Error: {error}"),
+    };
+    format!(
+        "{:>4} {:>3} {:>4} {:>12.3}  {}",
+        result.year,
+        result.day,
+        result.part,
+        milliseconds(result.duration),
+        outcome
+    )
+}
+
+fn json_object(result: &PartResult) -> String {
+    let (answer, error) = match &result.result {
+        Ok(answer) => (format!("\"{}\"", escape_json(answer)), "null".to_string()),
+        Err(error) => ("null".to_string(), format!("\"{}\"", escape_json(error))),
+    };
+    format!(
+        "{{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {answer}, \"error\": {error}, \"time_ms\": {:.3}}}",
+        result.year,
+        result.day,
+        result.part,
+        milliseconds(result.duration)
+    )
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut json = false;
+    let mut directory = None;
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            path if directory.is_none() => directory = Some(path),
+            _ => return Err(format!("Too many arguments - usage: {USAGE}")),
+        }
+    }
+    let directory = Path::new(directory.unwrap_or("."));
+
+    let mut printed_header = false;
+    let results = solve_directory(directory, |result| {
+        if !json {
+            if !printed_header {
+                println!("Year Day Part    Time (ms)  Answer");
+                printed_header = true;
+            }
+            println!("{}", table_row(result));
+        }
+    })?;
+
+    if json {
+        let objects = results.iter().map(json_object).collect::<Vec<_>>();
+        println!("[\n  {}\n]", objects.join(",\n  "));
+    } else {
+        let total = results
+            .iter()
+            .map(|result| result.duration)
+            .sum::<Duration>();
+        println!("Total time: {:.3} ms", milliseconds(total));
+    }
+
+    let failures = results
+        .iter()
+        .filter(|result| result.result.is_err())
+        .count();
+    if failures > 0 {
+        return Err(format!("{failures} of {} parts failed", results.len()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_output() {
+    let result = PartResult {
+        year: 2019,
+        day: 5,
+        part: 2,
+        result: Ok("a\nb".to_string()),
+        duration: Duration::from_micros(1500),
+    };
+    assert_eq!(table_row(&result), "2019   5    2        1.500  a\\nb");
+    assert_eq!(
+        json_object(&result),
+        "{\"year\": 2019, \"day\": 5, \"part\": 2, \"answer\": \"a\\nb\", \"error\": null, \"time_ms\": 1.500}"
+    );
+
+    let result = PartResult {
+        result: Err("Invalid \"input\"".to_string()),
+        ..result
+    };
+    assert_eq!(
+        table_row(&result),
+        "2019   5    2        1.500  Error: Invalid \"input\""
+    );
+    assert_eq!(
+        json_object(&result),
+        "{\"year\": 2019, \"day\": 5, \"part\": 2, \"answer\": null, \"error\": \"Invalid \\\"input\\\"\", \"time_ms\": 1.500}"
+    );
+}
@@ -0,0 +1,72 @@
+//! The `solve` subcommand, solving an input file named after its puzzle.
+use std::path::Path;
+
+use super::{infer_puzzle, InputFile};
+
+pub const USAGE: &str =
+    "advent-of-code solve [--year year] [--day day] [--part part] path/to/year2019/day05_input.txt";
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (mut year, mut day, mut part) = (None, None, None);
+    let mut path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut option_value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg} - usage: {USAGE}"))
+        };
+        let invalid_value = || format!("Invalid value for {arg} - usage: {USAGE}");
+        match arg.as_str() {
+            "--year" => year = Some(option_value()?.parse().map_err(|_| invalid_value())?),
+            "--day" => day = Some(option_value()?.parse().map_err(|_| invalid_value())?),
+            "--part" => part = Some(option_value()?.parse().map_err(|_| invalid_value())?),
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            value if path.is_none() => path = Some(Path::new(value)),
+            _ => return Err(format!("Too many arguments - usage: {USAGE}")),
+        }
+    }
+    let path = path.ok_or_else(|| format!("Missing input file - usage: {USAGE}"))?;
+
+    let (inferred_year, inferred_day) = infer_puzzle(path);
+    let input_file = InputFile {
+        year: year.or(inferred_year).ok_or_else(|| {
+            format!(
+                "Unable to infer the year from {} - pass it with --year",
+                path.display()
+            )
+        })?,
+        day: day.or(inferred_day).ok_or_else(|| {
+            format!(
+                "Unable to infer the day from {} - pass it with --day",
+                path.display()
+            )
+        })?,
+        path: path.to_path_buf(),
+    };
+    let input = input_file.read()?;
+
+    // Print only the answer when solving a single part, as when reading from stdin:
+    let parts = part.map_or_else(|| input_file.parts().collect(), |part| vec![part]);
+    let mut failed = false;
+    for &part in &parts {
+        let prefix = if parts.len() == 1 {
+            String::new()
+        } else {
+            format!("Part {part}: ")
+        };
+        match advent_of_code::solve(input_file.year, input_file.day, part, &input) {
+            Ok(answer) => println!("{prefix}{answer}"),
+            Err(error) => {
+                println!("{prefix}Error: {error}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        return Err("Solving failed".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,257 @@
+//! The `verify` subcommand, checking solutions against a file of expected answers.
+use std::path::Path;
+
+use super::run::{solve_part, PartResult};
+use super::InputFile;
+
+pub const USAGE: &str = "advent-of-code verify [--answers answers-file] [--no-color] [directory]";
+
+/// The name of the answers file looked for in the input directory by default.
+const DEFAULT_ANSWERS_FILE: &str = "answers.tsv";
+
+/// An expected answer, from a line `year<TAB>day<TAB>part<TAB>answer` of an answers file.
+pub struct ExpectedAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Parse an answers file, where empty lines and lines starting with `#` are ignored.
+pub fn parse_answers(text: &str) -> Result<Vec<ExpectedAnswer>, String> {
+    let mut answers = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let on_line = |message: &str| format!("Line {}: {message}", line_index + 1);
+
+        let mut fields = line.splitn(4, '\t');
+        let mut next_field = |name: &str| {
+            fields.next().map(str::trim).ok_or_else(|| {
+                on_line(&format!(
+                    "Missing {name} - expected year<TAB>day<TAB>part<TAB>answer"
+                ))
+            })
+        };
+        let year = next_field("year")?;
+        let day = next_field("day")?;
+        let part = next_field("part")?;
+        let answer = next_field("answer")?;
+
+        let answer = ExpectedAnswer {
+            year: year
+                .parse()
+                .map_err(|_| on_line(&format!("Invalid year '{year}'")))?,
+            day: day
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| on_line(&format!("Invalid day '{day}'")))?,
+            part: part
+                .parse()
+                .ok()
+                .filter(|part| matches!(part, 1 | 2))
+                .ok_or_else(|| on_line(&format!("Invalid part '{part}'")))?,
+            answer: answer.to_string(),
+        };
+        answers.push(answer);
+    }
+    Ok(answers)
+}
+
+struct Colors {
+    enabled: bool,
+}
+
+impl Colors {
+    /// Colors are used when standard output is a terminal and `NO_COLOR` is not set.
+    fn for_stdout() -> Self {
+        Self {
+            enabled: std::env::var_os("NO_COLOR").is_none() && stdout_is_terminal(),
+        }
+    }
+
+    fn paint(&self, ansi_color: u8, text: &str) -> String {
+        if self.enabled {
+            format!("\x1b[{ansi_color}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+    fn red(&self, text: &str) -> String {
+        self.paint(31, text)
+    }
+    fn green(&self, text: &str) -> String {
+        self.paint(32, text)
+    }
+}
+
+/// Whether standard output is a terminal, approximated by it being a character device
+/// to avoid `std::io::IsTerminal`, which requires a newer Rust version.
+#[cfg(unix)]
+fn stdout_is_terminal() -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    std::fs::metadata("/dev/stdout").map_or(false, |metadata| metadata.file_type().is_char_device())
+}
+
+#[cfg(not(unix))]
+const fn stdout_is_terminal() -> bool {
+    false
+}
+
+/// Line by line difference between an expected and actual answer.
+fn diff_lines(expected: &str, actual: &str, colors: &Colors) -> Vec<String> {
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let mut result = Vec::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(expected_line), Some(actual_line)) if expected_line == actual_line => {
+                result.push(format!("    {expected_line}"));
+            }
+            (expected_line, actual_line) => {
+                if let Some(expected_line) = expected_line {
+                    result.push(colors.red(&format!("  - {expected_line}")));
+                }
+                if let Some(actual_line) = actual_line {
+                    result.push(colors.green(&format!("  + {actual_line}")));
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Report the outcome of checking an answer, returning whether it matched.
+fn report(expected: &ExpectedAnswer, result: &PartResult, colors: &Colors) -> bool {
+    let prefix = format!(
+        "{:>4} {:>3} {:>4}  ",
+        expected.year, expected.day, expected.part
+    );
+    match &result.result {
+        Ok(answer) if *answer == expected.answer => {
+            println!("{prefix}{}", colors.green("ok"));
+            true
+        }
+        Ok(answer) => {
+            println!("{prefix}{}", colors.red("MISMATCH"));
+            for line in diff_lines(&expected.answer, answer, colors) {
+                println!("{line}");
+            }
+            false
+        }
+        Err(error) => {
+            println!("{prefix}{}", colors.red(&format!("ERROR: {error}")));
+            false
+        }
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut answers_path = None;
+    let mut colors = Colors::for_stdout();
+    let mut directory = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers_path = Some(
+                    args.next()
+                        .ok_or_else(|| format!("Missing answers file - usage: {USAGE}"))?,
+                );
+            }
+            "--no-color" => colors.enabled = false,
+            option if option.starts_with('-') => {
+                return Err(format!("Unknown option '{option}' - usage: {USAGE}"));
+            }
+            path if directory.is_none() => directory = Some(path),
+            _ => return Err(format!("Too many arguments - usage: {USAGE}")),
+        }
+    }
+    let directory = Path::new(directory.unwrap_or("."));
+    let answers_path = answers_path.map_or_else(
+        || directory.join(DEFAULT_ANSWERS_FILE),
+        |path| Path::new(path).to_path_buf(),
+    );
+
+    let answers_text = std::fs::read_to_string(&answers_path)
+        .map_err(|error| format!("Unable to read {}: {error}", answers_path.display()))?;
+    let answers = parse_answers(&answers_text)
+        .map_err(|error| format!("Invalid {}: {error}", answers_path.display()))?;
+    if answers.is_empty() {
+        return Err(format!("No answers in {}", answers_path.display()));
+    }
+
+    let mut failures = 0;
+    for expected in &answers {
+        let result = InputFile::in_directory(directory, expected.year, expected.day)
+            .read()
+            .map_or_else(
+                |error| PartResult {
+                    year: expected.year,
+                    day: expected.day,
+                    part: expected.part,
+                    result: Err(error),
+                    duration: std::time::Duration::ZERO,
+                },
+                |input| solve_part(expected.year, expected.day, expected.part, &input),
+            );
+        if !report(expected, &result, &colors) {
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!(
+            "{failures} of {} answers failed verification",
+            answers.len()
+        ));
+    }
+    println!("All {} answers verified", answers.len());
+    Ok(())
+}
+
+#[test]
+fn test_parse_answers() {
+    #![allow(clippy::unwrap_used)]
+    let answers =
+        parse_answers("# year day part answer\n2019\t1\t2\t4890696\n\n2022\t25\t1\t2=-1 0\r\n")
+            .unwrap();
+    assert_eq!(answers.len(), 2);
+    assert_eq!(
+        (answers[0].year, answers[0].day, answers[0].part),
+        (2019, 1, 2)
+    );
+    assert_eq!(answers[0].answer, "4890696");
+    assert_eq!(answers[1].answer, "2=-1 0");
+
+    assert_eq!(
+        parse_answers("2019\t1\t3\t1").err(),
+        Some("Line 1: Invalid part '3'".to_string())
+    );
+    assert_eq!(
+        parse_answers("\n2019\t26\t1\t1").err(),
+        Some("Line 2: Invalid day '26'".to_string())
+    );
+    assert_eq!(
+        parse_answers("2019 1 1 1").err(),
+        Some("Line 1: Missing day - expected year<TAB>day<TAB>part<TAB>answer".to_string())
+    );
+}
+
+#[test]
+fn test_diff_lines() {
+    let colors = Colors { enabled: false };
+    assert_eq!(diff_lines("1", "2", &colors), ["  - 1", "  + 2"]);
+    assert_eq!(
+        diff_lines("a\nb\nc", "a\nx", &colors),
+        ["    a", "  - b", "  + x", "  - c"]
+    );
+    let colors = Colors { enabled: true };
+    assert_eq!(
+        diff_lines("1", "2", &colors),
+        ["\x1b[31m  - 1\x1b[0m", "\x1b[32m  + 2\x1b[0m"]
+    );
+}
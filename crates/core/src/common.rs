@@ -1,7 +1,10 @@
 pub mod character_recognition;
 pub mod chunk_iterator;
+pub mod grid;
 pub mod int_to_ascii;
 pub mod md5;
+pub mod parallel;
 pub mod parser;
 pub mod permutation;
+pub mod search;
 pub mod tuple_window_iterator;
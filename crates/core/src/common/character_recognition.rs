@@ -1,207 +1,435 @@
+//! Recognition of letters drawn by solutions, supporting both fonts used in Advent of Code.
+
+/// Width of the small font glyphs, including the blank column separating letters.
 pub const CHAR_WIDTH: usize = 5;
+/// Height of the small font glyphs.
 pub const CHAR_HEIGHT: usize = 6;
 
-fn recognize_letter(
-    r1: &[bool],
-    r2: &[bool],
-    r3: &[bool],
-    r4: &[bool],
-    r5: &[bool],
-    r6: &[bool],
-) -> Result<char, String> {
-    Ok(match (r1, r2, r3, r4, r5, r6) {
-        (
-            [false, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, true, true, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-        ) => 'A',
+/// The share of matching pixels needed to recognize a glyph as a letter.
+const MIN_CONFIDENCE: f64 = 0.95;
 
-        (
-            [true, true, true, false, false],
-            [true, false, false, true, false],
-            [true, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, true, true, false, false],
-        ) => 'B',
+struct Font {
+    /// Width of a glyph.
+    width: usize,
+    /// Distance between the start of two adjacent glyphs.
+    pitch: usize,
+    /// The known letters, each drawn with a string per row of '#' (lit) and '.' (unlit).
+    letters: &'static [(char, &'static [&'static str])],
+}
 
-        (
-            [false, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, false, false, true, false],
-            [false, true, true, false, false],
-        ) => 'C',
+impl Font {
+    const fn height(&self) -> usize {
+        self.letters[0].1.len()
+    }
+}
 
-        (
-            [true, true, true, true, false],
-            [true, false, false, false, false],
-            [true, true, true, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, true, true, true, false],
-        ) => 'E',
+/// The 4x6 font (with a letter 'Y' spanning 5 columns) used in 2016 day 8, 2019 day 8 and 11,
+/// 2021 day 13 and 2022 day 10.
+const SMALL_FONT: Font = Font {
+    width: CHAR_WIDTH,
+    pitch: CHAR_WIDTH,
+    letters: &[
+        ('A', &[".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#."]),
+        ('B', &["###..", "#..#.", "###..", "#..#.", "#..#.", "###.."]),
+        ('C', &[".##..", "#..#.", "#....", "#....", "#..#.", ".##.."]),
+        ('E', &["####.", "#....", "###..", "#....", "#....", "####."]),
+        ('F', &["####.", "#....", "###..", "#....", "#....", "#...."]),
+        ('G', &[".##..", "#..#.", "#....", "#.##.", "#..#.", ".###."]),
+        ('H', &["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]),
+        ('I', &[".###.", "..#..", "..#..", "..#..", "..#..", ".###."]),
+        ('J', &["..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+        ('K', &["#..#.", "#.#..", "##...", "#.#..", "#.#..", "#..#."]),
+        ('L', &["#....", "#....", "#....", "#....", "#....", "####."]),
+        ('O', &[".##..", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+        ('P', &["###..", "#..#.", "#..#.", "###..", "#....", "#...."]),
+        ('R', &["###..", "#..#.", "#..#.", "###..", "#.#..", "#..#."]),
+        ('S', &[".###.", "#....", "#....", ".##..", "...#.", "###.."]),
+        ('U', &["#..#.", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####.", "...#.", "..#..", ".#...", "#....", "####."]),
+    ],
+};
 
+/// The 6x10 font used in 2018 day 10.
+const LARGE_FONT: Font = Font {
+    width: 6,
+    pitch: 8,
+    letters: &[
         (
-            [true, true, true, true, false],
-            [true, false, false, false, false],
-            [true, true, true, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-        ) => 'F',
-
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
         (
-            [false, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, false, false],
-            [true, false, true, true, false],
-            [true, false, false, true, false],
-            [false, true, true, true, false],
-        ) => 'G',
-
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
         (
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, true, true, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-        ) => 'H',
-
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
         (
-            [false, true, true, true, false],
-            [false, false, true, false, false],
-            [false, false, true, false, false],
-            [false, false, true, false, false],
-            [false, false, true, false, false],
-            [false, true, true, true, false],
-        ) => 'I',
-
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
         (
-            [false, false, true, true, false],
-            [false, false, false, true, false],
-            [false, false, false, true, false],
-            [false, false, false, true, false],
-            [true, false, false, true, false],
-            [false, true, true, false, false],
-        ) => 'J',
-
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
         (
-            [true, false, false, true, false],
-            [true, false, true, false, false],
-            [true, true, false, false, false],
-            [true, false, true, false, false],
-            [true, false, true, false, false],
-            [true, false, false, true, false],
-        ) => 'K',
-
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
         (
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [true, true, true, true, false],
-        ) => 'L',
-
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
         (
-            [false, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [false, true, true, false, false],
-        ) => 'O',
-
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
         (
-            [true, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, true, true, false, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-        ) => 'P',
-
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
         (
-            [true, true, true, false, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, true, true, false, false],
-            [true, false, true, false, false],
-            [true, false, false, true, false],
-        ) => 'R',
-
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
         (
-            [false, true, true, true, false],
-            [true, false, false, false, false],
-            [true, false, false, false, false],
-            [false, true, true, false, false],
-            [false, false, false, true, false],
-            [true, true, true, false, false],
-        ) => 'S',
-
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
         (
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [true, false, false, true, false],
-            [false, true, true, false, false],
-        ) => 'U',
-
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
         (
-            [true, false, false, false, true],
-            [true, false, false, false, true],
-            [false, true, false, true, false],
-            [false, false, true, false, false],
-            [false, false, true, false, false],
-            [false, false, true, false, false],
-        ) => 'Y',
-
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
         (
-            [true, true, true, true, false],
-            [false, false, false, true, false],
-            [false, false, true, false, false],
-            [false, true, false, false, false],
-            [true, false, false, false, false],
-            [true, true, true, true, false],
-        ) => 'Z',
-
-        _ => {
-            return Err("Unrecognized character".to_string());
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// A letter recognized from a glyph, with the share of pixels matching the font.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glyph {
+    pub letter: char,
+    pub confidence: f64,
+}
+
+/// The lit pixels of a screen, cropped to their bounding box.
+#[derive(Clone, Copy)]
+struct Screen<'a> {
+    pixels: &'a [bool],
+    width: usize,
+    left: usize,
+    top: usize,
+    cropped_width: usize,
+    cropped_height: usize,
+}
+
+impl<'a> Screen<'a> {
+    fn new(pixels: &'a [bool], width: usize) -> Result<Self, String> {
+        if width == 0 || pixels.len() % width != 0 {
+            return Err(format!(
+                "Screen size {} is not a multiple of its width {width}",
+                pixels.len()
+            ));
         }
-    })
+        let (mut left, mut right, mut top, mut bottom) = (usize::MAX, 0, usize::MAX, 0);
+        for (idx, _) in pixels.iter().enumerate().filter(|(_, &lit)| lit) {
+            let (x, y) = (idx % width, idx / width);
+            left = left.min(x);
+            right = right.max(x);
+            top = top.min(y);
+            bottom = bottom.max(y);
+        }
+        if left == usize::MAX {
+            return Err("No letters on screen".to_string());
+        }
+        Ok(Self {
+            pixels,
+            width,
+            left,
+            top,
+            cropped_width: right + 1 - left,
+            cropped_height: bottom + 1 - top,
+        })
+    }
+
+    /// Whether a pixel, relative to the top left of the cropped screen, is lit.
+    fn is_lit(&self, x: isize, y: usize) -> bool {
+        x >= 0 && (x as usize) < self.cropped_width && {
+            let idx = (self.top + y) * self.width + self.left + x as usize;
+            self.pixels[idx]
+        }
+    }
+
+    fn is_blank(&self, font: &Font, start: isize) -> bool {
+        (0..font.height()).all(|y| (0..font.width as isize).all(|dx| !self.is_lit(start + dx, y)))
+    }
+
+    fn render(&self, font: &Font, start: isize) -> String {
+        (0..font.height())
+            .map(|y| {
+                (0..font.width as isize)
+                    .map(|dx| if self.is_lit(start + dx, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The outcome of matching the glyph starting at a column against all letters in a font.
+struct Match {
+    start: isize,
+    glyph: Glyph,
+    matching_pixels: usize,
+    /// Whether another letter matches equally well.
+    ambiguous: bool,
+}
+
+fn match_glyph(screen: &Screen, font: &Font, start: isize) -> Match {
+    let mut result = Match {
+        start,
+        glyph: Glyph {
+            letter: '?',
+            confidence: 0.,
+        },
+        matching_pixels: 0,
+        ambiguous: true,
+    };
+    for &(letter, rows) in font.letters {
+        let matching_pixels = rows
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.bytes()
+                    .zip(start..)
+                    .filter(|&(c, x)| (c == b'#') == screen.is_lit(x, y))
+                    .count()
+            })
+            .sum();
+        if matching_pixels > result.matching_pixels {
+            result.glyph.letter = letter;
+            result.matching_pixels = matching_pixels;
+            result.ambiguous = false;
+        } else if matching_pixels == result.matching_pixels {
+            result.ambiguous = true;
+        }
+    }
+    result.glyph.confidence = result.matching_pixels as f64 / (font.width * font.height()) as f64;
+    result
+}
+
+/// The glyphs on a screen, in the detected font and alignment.
+#[derive(Clone, Copy)]
+struct Glyphs<'a> {
+    screen: Screen<'a>,
+    font: &'static Font,
+    /// The start column of the first glyph, which is negative if the first letter starts
+    /// with blank columns.
+    first_start: isize,
+}
+
+impl<'a> Glyphs<'a> {
+    fn new(pixels: &'a [bool], width: usize) -> Result<Self, String> {
+        let screen = Screen::new(pixels, width)?;
+        let font = [&SMALL_FONT, &LARGE_FONT]
+            .into_iter()
+            .find(|font| font.height() == screen.cropped_height)
+            .ok_or_else(|| format!("Unsupported letter height: {}", screen.cropped_height))?;
+        let mut glyphs = Self {
+            screen,
+            font,
+            first_start: 0,
+        };
+
+        // Try each alignment of glyphs to the first lit column and keep the one matching
+        // the most pixels:
+        glyphs.first_start = (1 - font.pitch as isize..=0)
+            .min_by_key(|&first_start| {
+                glyphs
+                    .matches_from(first_start)
+                    .map(|m| font.width * font.height() - m.matching_pixels)
+                    .sum::<usize>()
+            })
+            .unwrap_or_default();
+        Ok(glyphs)
+    }
+
+    /// Match each non-blank glyph, with the first glyph starting at a column.
+    fn matches_from(self, first_start: isize) -> impl Iterator<Item = Match> + 'a {
+        (0..)
+            .map(move |idx| first_start + idx * self.font.pitch as isize)
+            .take_while(move |&start| start < self.screen.cropped_width as isize)
+            .filter(move |&start| !self.screen.is_blank(self.font, start))
+            .map(move |start| match_glyph(&self.screen, self.font, start))
+    }
+
+    fn matches(self) -> impl Iterator<Item = Match> + 'a {
+        self.matches_from(self.first_start)
+    }
+
+    /// Check that all glyphs are recognized, failing with a rendering of those which are not.
+    fn check_recognized(&self) -> Result<(), String> {
+        let unrecognized = self
+            .matches()
+            .enumerate()
+            .filter(|(_, m)| m.ambiguous || m.glyph.confidence < MIN_CONFIDENCE)
+            .map(|(idx, m)| {
+                format!(
+                    "Unrecognized glyph {} (closest to '{}' with {:.0}% confidence):\n{}",
+                    idx + 1,
+                    m.glyph.letter,
+                    m.glyph.confidence * 100.,
+                    self.screen.render(self.font, m.start)
+                )
+            })
+            .collect::<Vec<_>>();
+        if unrecognized.is_empty() {
+            Ok(())
+        } else {
+            Err(unrecognized.join("\n"))
+        }
+    }
 }
 
-pub fn recognize(bytes: &[bool]) -> Result<String, String> {
-    if bytes.len() % (CHAR_WIDTH * CHAR_HEIGHT) != 0 {
-        return Err(format!(
-            "Input length is not a multiple of {}",
-            CHAR_WIDTH * CHAR_HEIGHT
-        ));
+/// Recognize the letters drawn on a screen, given as rows of pixels.
+///
+/// The font is detected from the height of the lit pixels, and blank columns and rows
+/// around the letters are ignored.
+pub fn recognize_glyphs(
+    pixels: &[bool],
+    width: usize,
+) -> Result<impl Iterator<Item = Glyph> + '_, String> {
+    let glyphs = Glyphs::new(pixels, width)?;
+    glyphs.check_recognized()?;
+    Ok(glyphs.matches().map(|m| m.glyph))
+}
+
+/// Recognize the text drawn on a screen, given as rows of pixels.
+pub fn recognize(pixels: &[bool], width: usize) -> Result<String, String> {
+    let glyphs = recognize_glyphs(pixels, width)?;
+    // At most one letter per the pitch of the narrowest font:
+    let mut text = String::with_capacity(width / SMALL_FONT.pitch + 1);
+    text.extend(glyphs.map(|glyph| glyph.letter));
+    Ok(text)
+}
+
+#[cfg(test)]
+fn draw(font: &Font, text: &str, blank_columns: usize) -> (Vec<bool>, usize) {
+    #![allow(clippy::unwrap_used)]
+    let width = blank_columns * 2 + text.len() * font.pitch;
+    let mut pixels = vec![false; width * font.height()];
+    for (idx, letter) in text.chars().enumerate() {
+        let (_, rows) = font.letters.iter().find(|(l, _)| *l == letter).unwrap();
+        for (y, row) in rows.iter().enumerate() {
+            for (dx, c) in row.bytes().enumerate() {
+                pixels[y * width + blank_columns + idx * font.pitch + dx] = c == b'#';
+            }
+        }
     }
-    let num_letters = bytes.len() / (CHAR_WIDTH * CHAR_HEIGHT);
-    let all_width = CHAR_WIDTH * num_letters;
-    let mut result = String::with_capacity(num_letters);
-    for letter_idx in 0..num_letters {
-        result.push(recognize_letter(
-            &bytes[(letter_idx * CHAR_WIDTH)..((letter_idx + 1) * CHAR_WIDTH)],
-            &bytes[(letter_idx * CHAR_WIDTH + all_width)
-                ..((letter_idx + 1) * CHAR_WIDTH + all_width)],
-            &bytes[(letter_idx * CHAR_WIDTH + 2 * all_width)
-                ..((letter_idx + 1) * CHAR_WIDTH + 2 * all_width)],
-            &bytes[(letter_idx * CHAR_WIDTH + 3 * all_width)
-                ..((letter_idx + 1) * CHAR_WIDTH + 3 * all_width)],
-            &bytes[(letter_idx * CHAR_WIDTH + 4 * all_width)
-                ..((letter_idx + 1) * CHAR_WIDTH + 4 * all_width)],
-            &bytes[(letter_idx * CHAR_WIDTH + 5 * all_width)
-                ..((letter_idx + 1) * CHAR_WIDTH + 5 * all_width)],
-        )?);
+    (pixels, width)
+}
+
+#[test]
+fn test_recognize() {
+    #![allow(clippy::unwrap_used)]
+    for font in [&SMALL_FONT, &LARGE_FONT] {
+        let alphabet = font.letters.iter().map(|(l, _)| *l).collect::<String>();
+        for blank_columns in [0, 1, 7] {
+            let (pixels, width) = draw(font, &alphabet, blank_columns);
+            assert_eq!(recognize(&pixels, width), Ok(alphabet.clone()));
+        }
+        for &(letter, rows) in font.letters {
+            assert_eq!(rows.len(), font.height());
+            assert!(rows.iter().all(|row| row.len() == font.width));
+            let (pixels, width) = draw(font, &format!("{letter}"), 3);
+            assert_eq!(recognize(&pixels, width), Ok(format!("{letter}")));
+        }
     }
-    Ok(result)
+
+    // Letters starting and ending with blank columns:
+    let (pixels, width) = draw(&SMALL_FONT, "JIYA", 0);
+    assert_eq!(recognize(&pixels, width), Ok("JIYA".to_string()));
+
+    // A glyph with a pixel flipped is still recognized, with lower confidence:
+    let (mut pixels, width) = draw(&LARGE_FONT, "HEL", 2);
+    pixels[2 * width + 2 + LARGE_FONT.pitch + 3] = true;
+    let glyphs = recognize_glyphs(&pixels, width)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(glyphs.iter().map(|g| g.letter).collect::<String>(), "HEL");
+    assert_eq!(glyphs[0].confidence, 1.);
+    assert!(glyphs[1].confidence < 1.);
+
+    // An unrecognized glyph is rendered in the error:
+    let (mut pixels, width) = draw(&SMALL_FONT, "AB", 0);
+    pixels[width * 2 + CHAR_WIDTH..width * 4].fill(true);
+    assert_eq!(
+        recognize(&pixels, width),
+        Err(
+            "Unrecognized glyph 2 (closest to 'B' with 83% confidence):\n###..\n#..#.\n#####\n#####\n#..#.\n###.."
+                .to_string()
+        )
+    );
+
+    assert!(recognize(&[false; 30], 5).is_err());
+    assert!(recognize(&[true; 30], 7).is_err());
+    assert!(recognize(&[true; 7], 7).is_err());
 }
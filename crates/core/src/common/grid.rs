@@ -0,0 +1,511 @@
+//! Grids of cells shared between solutions: a dense [`Grid`] for bounded maps and a
+//! [`SparseGrid`] for unbounded maps in any number of dimensions.
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{Add, Index, IndexMut};
+
+/// A position in a two-dimensional grid, as (x, y) with y growing downwards.
+pub type Position = (i32, i32);
+
+/// A point in an N-dimensional grid, where a small coordinate type keeps maps of points compact.
+pub type Point<const N: usize, C = i32> = [C; N];
+
+/// The type of coordinates of a [Point].
+pub trait Coordinate:
+    Copy + Ord + Hash + Add<Output = Self> + From<i8> + TryFrom<i32> + Into<i32>
+{
+}
+
+impl<C: Copy + Ord + Hash + Add<Output = C> + From<i8> + TryFrom<i32> + Into<i32>> Coordinate
+    for C
+{
+}
+
+/// Which cells are neighbors of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The cells sharing an edge (4 in two dimensions, 2N in N dimensions).
+    Orthogonal,
+    /// The cells sharing an edge or corner (8 in two dimensions, 3^N-1 in N dimensions).
+    All,
+    /// The 6 cells around a hexagon in axial coordinates, where (x, y) neighbors
+    /// (x+1, y-1) and (x-1, y+1) but not (x+1, y+1) and (x-1, y-1). Only for two dimensions.
+    Hex,
+}
+
+impl Neighborhood {
+    /// The offsets from a two-dimensional position to its neighbors.
+    pub const fn offsets(self) -> &'static [Position] {
+        match self {
+            Self::Orthogonal => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Self::All => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+            Self::Hex => &[(0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)],
+        }
+    }
+
+    /// The offsets from an N-dimensional point to its `M` neighbors, where `M` is 2N, 3^N-1
+    /// or 6 depending on the neighborhood. Computed once to be reused for every point.
+    pub fn point_offsets<C: Coordinate, const N: usize, const M: usize>(self) -> [Point<N, C>; M] {
+        let mut offsets = [[C::from(0); N]; M];
+        let mut offset_count = 0;
+        for combination in 0..3_u32.pow(N as u32) {
+            let mut offset = [0_i8; N];
+            let mut remaining = combination;
+            for component in offset.iter_mut() {
+                *component = (remaining % 3) as i8 - 1;
+                remaining /= 3;
+            }
+            let non_zero = offset.iter().filter(|&&component| component != 0).count();
+            let is_neighbor = match self {
+                Self::Orthogonal => non_zero == 1,
+                Self::All => non_zero > 0,
+                Self::Hex => {
+                    let sum = offset
+                        .iter()
+                        .map(|&component| i32::from(component))
+                        .sum::<i32>();
+                    N == 2 && non_zero > 0 && sum.abs() < 2
+                }
+            };
+            if is_neighbor {
+                assert!(offset_count < M, "More than {M} neighbors");
+                offsets[offset_count] = offset.map(C::from);
+                offset_count += 1;
+            }
+        }
+        assert_eq!(offset_count, M, "Wrong neighbor count");
+        offsets
+    }
+}
+
+/// Call `on_cell` for each character of a map with lines of equal length.
+fn parse_map(
+    text: &str,
+    mut on_cell: impl FnMut(Position, u8) -> Result<(), String>,
+) -> Result<(usize, usize), String> {
+    let width = text.lines().next().map(str::len).unwrap_or_default();
+    if width == 0 {
+        return Err("Empty input".to_string());
+    }
+    let mut height = 0;
+    for (y, line) in text.lines().enumerate() {
+        if line.len() != width {
+            return Err("Not all lines have equal length".to_string());
+        }
+        for (x, c) in line.bytes().enumerate() {
+            on_cell((x as i32, y as i32), c)?;
+        }
+        height += 1;
+    }
+    Ok((width, height))
+}
+
+/// A dense two-dimensional grid, storing a value for every position from (0, 0) up to
+/// its width and height.
+///
+/// The cells are stored row by row in a `Vec` by default, or in a fixed size array for
+/// grids whose size is known beforehand.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T, S = Vec<T>> {
+    width: usize,
+    height: usize,
+    cells: S,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse a map of characters, with one line per row.
+    pub fn parse(
+        text: &str,
+        mut parse_cell: impl FnMut(u8) -> Result<T, String>,
+    ) -> Result<Self, String> {
+        let mut cells = Vec::with_capacity(text.len());
+        let (width, height) = parse_map(text, |_, c| {
+            cells.push(parse_cell(c)?);
+            Ok(())
+        })?;
+        Ok(Self {
+            width,
+            height,
+            cells,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Copy + Default, const SIZE: usize> Grid<T, [T; SIZE]> {
+    /// Parse a map of characters, with one line per row, which must have `SIZE` cells.
+    pub fn parse_fixed_size(
+        text: &str,
+        mut parse_cell: impl FnMut(u8) -> Result<T, String>,
+    ) -> Result<Self, String> {
+        let mut cells = [T::default(); SIZE];
+        let mut cell_count = 0;
+        let (width, height) = parse_map(text, |_, c| {
+            *cells
+                .get_mut(cell_count)
+                .ok_or_else(|| format!("Not a grid of {SIZE} cells"))? = parse_cell(c)?;
+            cell_count += 1;
+            Ok(())
+        })?;
+        if cell_count != SIZE {
+            return Err(format!("Not a grid of {SIZE} cells"));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, S: AsRef<[T]>> Grid<T, S> {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell_index(&self, (x, y): Position) -> Option<usize> {
+        ((x as usize) < self.width && (y as usize) < self.height)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.cells.as_ref().get(self.cell_index(position)?)
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .as_ref()
+            .chunks(self.width)
+            .zip(0..)
+            .flat_map(|(row, y)| row.iter().zip(0..).map(move |(cell, x)| ((x, y), cell)))
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        self.cells.as_ref()
+    }
+
+    /// The neighbors of a position which are inside the grid.
+    pub fn neighbors(
+        &self,
+        (x, y): Position,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        neighborhood.offsets().iter().filter_map(move |&(dx, dy)| {
+            let neighbor = (x + dx, y + dy);
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    /// Display the grid with the character `cell_char` returns for each cell.
+    // Only used by tests until a solution prints a grid:
+    #[allow(dead_code)]
+    pub const fn display<F: Fn(&T) -> char>(&self, cell_char: F) -> GridDisplay<'_, Self, F> {
+        GridDisplay {
+            grid: self,
+            cell_char,
+        }
+    }
+}
+
+impl<T, S: AsRef<[T]>> Index<Position> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {position:?} outside of grid"))
+    }
+}
+
+impl<T, S: AsRef<[T]> + AsMut<[T]>> IndexMut<Position> for Grid<T, S> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let idx = self
+            .cell_index(position)
+            .unwrap_or_else(|| panic!("Position {position:?} outside of grid"));
+        &mut self.cells.as_mut()[idx]
+    }
+}
+
+/// A sparse grid in N dimensions, storing values only for some points without any bounds.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T, const N: usize = 2, C = i32> {
+    cells: HashMap<Point<N, C>, T>,
+}
+
+impl<T, const N: usize, C> Default for SparseGrid<T, N, C> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T, const N: usize, C: Coordinate> SparseGrid<T, N, C> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            cells: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Parse a two-dimensional map of characters into the plane where all but the
+    /// first two coordinates are zero, storing the cells which `parse_cell` maps to a value.
+    pub fn insert_parsed(
+        &mut self,
+        text: &str,
+        mut parse_cell: impl FnMut(u8) -> Result<Option<T>, String>,
+    ) -> Result<(), String> {
+        parse_map(text, |(x, y), c| {
+            if let Some(value) = parse_cell(c)? {
+                let coordinate =
+                    |value: i32| C::try_from(value).map_err(|_| "Too large map".to_string());
+                let mut point = [C::from(0); N];
+                point[0] = coordinate(x)?;
+                point[1] = coordinate(y)?;
+                self.cells.insert(point, value);
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn get(&self, point: Point<N, C>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn insert(&mut self, point: Point<N, C>, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    /// Only keep the cells for which a predicate returns true.
+    pub fn retain(&mut self, mut keep: impl FnMut(Point<N, C>, &mut T) -> bool) {
+        self.cells.retain(|&point, value| keep(point, value));
+    }
+
+    /// All stored cells with their points, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<N, C>, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The smallest and largest coordinates of the stored points in each dimension, which
+    /// grow as points are inserted further out. `None` if no points are stored.
+    pub fn bounds(&self) -> Option<(Point<N, C>, Point<N, C>)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(mut min, mut max), point| {
+            for i in 0..N {
+                min[i] = min[i].min(point[i]);
+                max[i] = max[i].max(point[i]);
+            }
+            (min, max)
+        }))
+    }
+}
+
+impl<T, C: Coordinate> SparseGrid<T, 2, C> {
+    /// Display the bounds of the stored cells with the character `cell_char` returns for
+    /// each position, which is given `None` for positions without a stored value.
+    // Only used by tests until a solution prints a grid:
+    #[allow(dead_code)]
+    pub const fn display<F: Fn(Option<&T>) -> char>(
+        &self,
+        cell_char: F,
+    ) -> GridDisplay<'_, Self, F> {
+        GridDisplay {
+            grid: self,
+            cell_char,
+        }
+    }
+}
+
+/// A grid displayed with a character per cell and a line per row, as returned by
+/// [`Grid::display`] and [`SparseGrid::display`].
+pub struct GridDisplay<'a, G, F> {
+    grid: &'a G,
+    cell_char: F,
+}
+
+impl<T, S: AsRef<[T]>, F: Fn(&T) -> char> Display for GridDisplay<'_, Grid<T, S>, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.cells().chunks(self.grid.width).enumerate() {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+            for cell in row {
+                f.write_char((self.cell_char)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, C: Coordinate, F: Fn(Option<&T>) -> char> Display
+    for GridDisplay<'_, SparseGrid<T, 2, C>, F>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(([left, top], [right, bottom])) = self.grid.bounds() else {
+            return Ok(());
+        };
+        for y in top.into()..=bottom.into() {
+            if y > top.into() {
+                f.write_char('\n')?;
+            }
+            for x in left.into()..=right.into() {
+                // Positions inside the bounds fit in the coordinate type:
+                let cell = match (C::try_from(x), C::try_from(y)) {
+                    (Ok(x), Ok(y)) => self.grid.get([x, y]),
+                    _ => None,
+                };
+                f.write_char((self.cell_char)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The point at an offset from another point.
+pub fn offset_point<C: Coordinate, const N: usize>(
+    mut point: Point<N, C>,
+    offset: Point<N, C>,
+) -> Point<N, C> {
+    for (component, delta) in point.iter_mut().zip(offset) {
+        *component = *component + delta;
+    }
+    point
+}
+
+#[test]
+fn test_neighborhood() {
+    let mut neighbors = Neighborhood::Orthogonal.offsets().to_vec();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, [(-1, 0), (0, -1), (0, 1), (1, 0)]);
+    assert_eq!(Neighborhood::All.offsets().len(), 8);
+    assert!(Neighborhood::All.offsets().iter().all(|&p| p != (0, 0)));
+    let mut neighbors = Neighborhood::Hex.offsets().to_vec();
+    neighbors.sort_unstable();
+    assert_eq!(
+        neighbors,
+        [(-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0)]
+    );
+    let offsets = Neighborhood::Hex.point_offsets::<i32, 2, 6>();
+    assert!(offsets.iter().all(|&[x, y]| neighbors.contains(&(x, y))));
+
+    let offsets = Neighborhood::Orthogonal.point_offsets::<i32, 3, 6>();
+    assert!(offsets.contains(&[0, 0, -1]));
+    assert!(!offsets.contains(&[0, 1, -1]));
+    let offsets = Neighborhood::All.point_offsets::<i8, 4, 80>();
+    assert!(offsets.contains(&[-1, 1, 0, 1]));
+    assert!(!offsets.contains(&[0, 0, 0, 0]));
+    assert_eq!(offset_point([1, 2, 3], [0, -1, 1]), [1, 1, 4]);
+}
+
+#[test]
+#[should_panic = "Wrong neighbor count"]
+fn test_neighborhood_count() {
+    Neighborhood::All.point_offsets::<i32, 2, 9>();
+}
+
+#[test]
+fn test_grid() {
+    #![allow(clippy::unwrap_used)]
+    let mut grid = Grid::parse("#..\n.#.", |c| Ok(c == b'#')).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get((1, 1)), Some(&true));
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, -1)), None);
+    assert_eq!(
+        grid.neighbors((0, 0), Neighborhood::All)
+            .filter(|(_, &lit)| lit)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>(),
+        [(1, 1)]
+    );
+    assert_eq!(grid.neighbors((0, 0), Neighborhood::Orthogonal).count(), 2);
+    grid[(2, 1)] = true;
+    assert_eq!(
+        grid.iter()
+            .filter(|(_, &lit)| lit)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>(),
+        [(0, 0), (1, 1), (2, 1)]
+    );
+    assert_eq!(grid.cells(), [true, false, false, false, true, true]);
+    assert_eq!(
+        grid.display(|&lit| if lit { '#' } else { '.' }).to_string(),
+        "#..\n.##"
+    );
+
+    assert_eq!(
+        Grid::parse("..\n...", Ok),
+        Err("Not all lines have equal length".to_string())
+    );
+    assert_eq!(Grid::parse("", Ok), Err("Empty input".to_string()));
+    assert_eq!(
+        Grid::<u8>::parse("ab", |_| Err("Invalid".to_string())),
+        Err("Invalid".to_string())
+    );
+
+    let grid = Grid::<_, [bool; 6]>::parse_fixed_size("#..\n.#.", |c| Ok(c == b'#')).unwrap();
+    assert_eq!(grid.cells(), [true, false, false, false, true, false]);
+    assert!(grid[(1, 1)]);
+    let not_six = Err("Not a grid of 6 cells".to_string());
+    assert_eq!(Grid::<_, [u8; 6]>::parse_fixed_size("ab\ncd", Ok), not_six);
+    assert_eq!(
+        Grid::<_, [u8; 6]>::parse_fixed_size("abcd\nefgh", Ok),
+        not_six
+    );
+}
+
+#[test]
+fn test_sparse_grid() {
+    #![allow(clippy::unwrap_used)]
+    let mut grid = SparseGrid::<(), 3, i8>::with_capacity(4);
+    grid.insert_parsed(".#\n#.", |c| Ok((c == b'#').then_some(())))
+        .unwrap();
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.insert([-2, 0, 5], ()), None);
+    assert_eq!(grid.bounds(), Some(([-2, 0, 0], [1, 1, 5])));
+    grid.retain(|point, _| point[2] == 0);
+    let mut points = grid.iter().map(|(point, _)| point).collect::<Vec<_>>();
+    points.sort_unstable();
+    assert_eq!(points, [[0, 1, 0], [1, 0, 0]]);
+    assert_eq!(grid.bounds(), Some(([0, 0, 0], [1, 1, 0])));
+    assert_eq!(grid.get([1, 0, 0]), Some(&()));
+    assert_eq!(grid.get([1, 1, 0]), None);
+
+    let mut grid = SparseGrid::<char>::default();
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.display(|_| '#').to_string(), "");
+    grid.insert([2, -1], 'a');
+    grid.insert([0, 0], 'b');
+    assert_eq!(grid.bounds(), Some(([0, -1], [2, 0])));
+    assert_eq!(
+        grid.display(|c| c.copied().unwrap_or('.')).to_string(),
+        "..a\nb.."
+    );
+
+    assert!(SparseGrid::<(), 2, i8>::with_capacity(0)
+        .insert_parsed(&".".repeat(200), |_| Ok(Some(())))
+        .is_err());
+}
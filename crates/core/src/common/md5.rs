@@ -22,14 +22,23 @@
 // https://people.csail.mit.edu/rivest/Md5.c
 // https://tools.ietf.org/html/rfc1321
 
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+#[cfg(feature = "simd")]
+use std::simd::Simd;
+
 /// A context.
+///
+/// With the `simd` feature messages are instead hashed by [compute_lanes], with
+/// the context only kept as a reference in tests.
 #[derive(Clone)]
+#[cfg(any(test, not(feature = "simd")))]
 pub struct Context {
     buffer: [u8; 64],
     count: [u32; 2],
     state: [u32; 4],
 }
 
+#[cfg(any(test, not(feature = "simd")))]
 const PADDING: [u8; 64] = [
     0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -37,6 +46,7 @@ const PADDING: [u8; 64] = [
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
 ];
 
+#[cfg(any(test, not(feature = "simd")))]
 impl Context {
     /// Create a context for computing a digest.
     #[inline]
@@ -45,7 +55,7 @@ impl Context {
         Self {
             buffer: [0; 64],
             count: [0, 0],
-            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            state: INITIAL_STATE,
         }
     }
 
@@ -119,6 +129,152 @@ pub fn compute<T: AsRef<[u8]>>(data: T) -> [u8; 16] {
     context.compute()
 }
 
+/// The number of messages hashed at once by [compute_all].
+///
+/// Batches of 4, 8 or 16 messages can be hashed by [compute_lanes], where
+/// wider batches make better use of wide simd registers.
+pub const LANES: usize = 16;
+
+const INITIAL_STATE: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+/// The number of 64 byte blocks of a message of the specified length after padding.
+#[cfg(feature = "simd")]
+const fn block_count(length: usize) -> usize {
+    (length + 8) / 64 + 1
+}
+
+/// The words of a block of the message formed by a prefix followed by a suffix, padded
+/// with a one bit, zeros and the message length in bits as specified by MD5.
+#[cfg(feature = "simd")]
+fn padded_block(prefix: &[u8], suffix: &[u8], block_index: usize) -> [u32; 16] {
+    let length = prefix.len() + suffix.len();
+    let mut block = [0_u8; 64];
+    let start = block_index * 64;
+    for (part_start, part) in [(0, prefix), (prefix.len(), suffix)] {
+        let from = start.max(part_start);
+        let to = (start + 64).min(part_start + part.len());
+        if from < to {
+            block[from - start..to - start]
+                .copy_from_slice(&part[from - part_start..to - part_start]);
+        }
+    }
+    if (start..start + 64).contains(&length) {
+        block[length - start] = 0x80;
+    }
+    if block_index + 1 == block_count(length) {
+        block[56..].copy_from_slice(&((length as u64) << 3).to_le_bytes());
+    }
+    std::array::from_fn(|i| {
+        u32::from_le_bytes([
+            block[i * 4],
+            block[i * 4 + 1],
+            block[i * 4 + 2],
+            block[i * 4 + 3],
+        ])
+    })
+}
+
+/// Compute the digests of a prefix followed by each of the suffixes, with each message
+/// hashed in its own lane of simd vectors.
+#[cfg(feature = "simd")]
+fn compute_prefixed_lanes<const N: usize>(prefix: &[u8], suffixes: [&[u8]; N]) -> [[u8; 16]; N] {
+    let block_counts = suffixes.map(|suffix| block_count(prefix.len() + suffix.len()));
+    let max_block_count = block_counts.iter().copied().max().unwrap_or_default();
+
+    let mut state = INITIAL_STATE.map(Simd::<u32, N>::splat);
+    for block_index in 0..max_block_count {
+        let blocks = suffixes.map(|suffix| padded_block(prefix, suffix, block_index));
+        let input = std::array::from_fn(|word| {
+            Simd::from_array(std::array::from_fn(|lane| blocks[lane][word]))
+        });
+        let mut new_state = state;
+        transform(&mut new_state, &input);
+        // Only update the state of lanes whose message is not yet fully consumed:
+        let active =
+            Simd::from_array(
+                block_counts.map(|count| if block_index < count { u32::MAX } else { 0 }),
+            );
+        for (word, new_word) in state.iter_mut().zip(new_state) {
+            *word = (new_word & active) | (*word & !active);
+        }
+    }
+
+    std::array::from_fn(|lane| {
+        let mut digest = [0_u8; 16];
+        for (i, word) in state.iter().enumerate() {
+            digest[i * 4..i * 4 + 4].copy_from_slice(&word[lane].to_le_bytes());
+        }
+        digest
+    })
+}
+
+/// Compute the digests of a batch of 4, 8 or 16 messages at once.
+///
+/// With the `simd` feature each message is hashed in its own lane of simd vectors,
+/// making it about as fast as hashing a single message as long as the messages are
+/// of similar length. Without it the messages are hashed one at a time.
+pub fn compute_lanes<const N: usize>(messages: [&[u8]; N]) -> [[u8; 16]; N] {
+    #[cfg(feature = "simd")]
+    return compute_prefixed_lanes(&[], messages);
+
+    #[cfg(not(feature = "simd"))]
+    messages.map(|message| {
+        let mut context = Context::new();
+        context.consume(message);
+        context.compute()
+    })
+}
+
+/// Compute the digests of messages, [LANES] messages at a time.
+pub fn compute_all<T: AsRef<[u8]>>(messages: &[T]) -> Vec<[u8; 16]> {
+    let mut digests = Vec::with_capacity(messages.len());
+    for chunk in messages.chunks(LANES) {
+        let lanes = compute_lanes::<LANES>(std::array::from_fn(|lane| {
+            chunk.get(lane).map_or(&[][..], AsRef::as_ref)
+        }));
+        digests.extend_from_slice(&lanes[..chunk.len()]);
+    }
+    digests
+}
+
+/// A prefix shared by messages, prepared for hashing it followed by different suffixes.
+pub struct Prefix {
+    #[cfg(feature = "simd")]
+    bytes: Vec<u8>,
+    /// The context having consumed the prefix, cloned for each message.
+    #[cfg(not(feature = "simd"))]
+    context: Context,
+}
+
+impl Prefix {
+    pub fn new(prefix: &[u8]) -> Self {
+        #[cfg(feature = "simd")]
+        return Self {
+            bytes: prefix.to_vec(),
+        };
+
+        #[cfg(not(feature = "simd"))]
+        {
+            let mut context = Context::new();
+            context.consume(prefix);
+            Self { context }
+        }
+    }
+
+    /// Compute the digests of the prefix followed by each of a batch of 4, 8 or 16 suffixes.
+    pub fn compute_lanes<const N: usize>(&self, suffixes: [&[u8]; N]) -> [[u8; 16]; N] {
+        #[cfg(feature = "simd")]
+        return compute_prefixed_lanes(&self.bytes, suffixes);
+
+        #[cfg(not(feature = "simd"))]
+        suffixes.map(|suffix| {
+            let mut context = self.context.clone();
+            context.consume(suffix);
+            context.compute()
+        })
+    }
+}
+
 #[cfg(test)]
 fn lower_hex(data: &[u8]) -> String {
     use std::fmt::Write;
@@ -130,14 +286,56 @@ fn lower_hex(data: &[u8]) -> String {
     buf
 }
 
-fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
+/// A 32 bit word, or with the `simd` feature a vector of such words hashing one message per lane.
+trait Word:
+    Copy + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self>
+{
+    fn splat(value: u32) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn rotate_left(self, n: u32) -> Self;
+}
+
+impl Word for u32 {
+    #[inline]
+    fn splat(value: u32) -> Self {
+        value
+    }
+    #[inline]
+    fn wrapping_add(self, other: Self) -> Self {
+        Self::wrapping_add(self, other)
+    }
+    #[inline]
+    fn rotate_left(self, n: u32) -> Self {
+        Self::rotate_left(self, n)
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<const N: usize> Word for Simd<u32, N> {
+    #[inline]
+    fn splat(value: u32) -> Self {
+        Self::splat(value)
+    }
+    #[inline]
+    fn wrapping_add(self, other: Self) -> Self {
+        // Addition of simd vectors wraps on overflow.
+        self + other
+    }
+    #[inline]
+    fn rotate_left(self, n: u32) -> Self {
+        (self << Self::splat(n)) | (self >> Self::splat(32 - n))
+    }
+}
+
+#[inline]
+fn transform<W: Word>(state: &mut [W; 4], input: &[W; 16]) {
     #![allow(clippy::unreadable_literal)]
     let (mut a, mut b, mut c, mut d) = (state[0], state[1], state[2], state[3]);
     macro_rules! add(
         ($a:expr, $b:expr) => ($a.wrapping_add($b));
     );
     macro_rules! rotate(
-        ($x:expr, $n:expr) => (($x << $n) | ($x >> (32 - $n)));
+        ($x:expr, $n:expr) => ($x.rotate_left($n));
     );
     {
         macro_rules! F(
@@ -145,7 +343,7 @@ fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -177,7 +375,7 @@ fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -209,7 +407,7 @@ fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -241,7 +439,7 @@ fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -302,6 +500,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compute_lanes() {
+        let messages: Vec<Vec<u8>> = (0..200_u8).map(|length| (0..length).collect()).collect();
+        let expected: Vec<[u8; 16]> = messages.iter().map(super::compute).collect();
+        assert_eq!(super::compute_all(&messages), expected);
+        assert_eq!(
+            super::compute_lanes::<4>(std::array::from_fn(|i| &messages[i * 50][..])),
+            std::array::from_fn(|i| expected[i * 50])
+        );
+        assert_eq!(
+            super::compute_lanes::<8>(std::array::from_fn(|i| &messages[i * 25][..])),
+            std::array::from_fn(|i| expected[i * 25])
+        );
+        assert_eq!(
+            super::compute_lanes::<16>(std::array::from_fn(|i| &messages[i * 12][..])),
+            std::array::from_fn(|i| expected[i * 12])
+        );
+        assert_eq!(
+            super::compute_all(&["abc", ""]),
+            [super::compute("abc"), expected[0]]
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        let messages: Vec<Vec<u8>> = (0..200_u8).map(|length| (0..length).collect()).collect();
+        for prefix_length in [0, 1, 60, 64, 70, 130] {
+            let prefix = super::Prefix::new(&messages[prefix_length]);
+            let digests = prefix.compute_lanes::<8>(std::array::from_fn(|i| {
+                &messages[prefix_length + i * 8][prefix_length..]
+            }));
+            assert_eq!(
+                digests,
+                std::array::from_fn(|i| super::compute(&messages[prefix_length + i * 8]))
+            );
+        }
+    }
+
     #[test]
     fn index() {
         let mut digest = super::compute(b"abc");
@@ -0,0 +1,200 @@
+//! Splitting of work across threads.
+//!
+//! With the `parallel` feature work is performed in scoped worker threads, one per available
+//! core, while results are always delivered in the same order as a single-threaded run
+//! would produce them. Without the feature everything runs on the calling thread.
+use std::ops::{ControlFlow, Range};
+
+#[cfg(feature = "parallel")]
+fn thread_count() -> usize {
+    std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+}
+
+/// Apply a function to each item, returning the results in the order of the items.
+#[cfg(feature = "parallel")]
+pub fn map<T, R, F>(items: &[T], function: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if items.len() < 2 {
+        return items.iter().map(function).collect();
+    }
+    let chunk_size = (items.len() + thread_count() - 1) / thread_count();
+    let function = &function;
+    std::thread::scope(|scope| {
+        let handles = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(function).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(results) => results,
+                Err(panic) => std::panic::resume_unwind(panic),
+            })
+            .collect()
+    })
+}
+
+/// Apply a function to each item, returning the results in the order of the items.
+#[cfg(not(feature = "parallel"))]
+pub fn map<T, R, F>(items: &[T], function: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    items.iter().map(function).collect()
+}
+
+/// Search a range in consecutive chunks of `chunk_size`, passing the result of each
+/// chunk to `on_result` in chunk order until it breaks with a value, which is returned.
+///
+/// With the `parallel` feature one worker thread per core is started, each repeatedly
+/// taking the next unsearched chunk. Chunks after the one whose result causes a break
+/// may have been searched, with their results discarded.
+#[cfg(feature = "parallel")]
+pub fn search_chunks<R, B, S, C>(
+    range: Range<u32>,
+    chunk_size: u32,
+    search_chunk: S,
+    mut on_result: C,
+) -> Option<B>
+where
+    R: Send,
+    S: Fn(Range<u32>) -> R + Sync,
+    C: FnMut(R) -> ControlFlow<B>,
+{
+    use std::collections::BTreeMap;
+    use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::mpsc;
+
+    let chunk_size = chunk_size.max(1);
+    let chunk_count = chunks(range.clone(), chunk_size).len();
+    let next_chunk = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+
+    std::thread::scope(|scope| {
+        // The receiver is dropped if this closure returns or unwinds, which stops the workers.
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..thread_count().min(chunk_count) {
+            let sender = sender.clone();
+            let (range, search_chunk, next_chunk, stop) =
+                (&range, &search_chunk, &next_chunk, &stop);
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let chunk_index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                    if chunk_index >= chunk_count {
+                        break;
+                    }
+                    let start = range.start + chunk_index as u32 * chunk_size;
+                    let chunk = start..start.saturating_add(chunk_size).min(range.end);
+                    let result = catch_unwind(AssertUnwindSafe(|| search_chunk(chunk)));
+                    if sender.send((chunk_index, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Results arrive in completion order, so buffer those ahead of the next chunk:
+        let mut pending = BTreeMap::new();
+        let mut next_to_deliver = 0;
+        while next_to_deliver < chunk_count {
+            let Ok((chunk_index, result)) = receiver.recv() else {
+                break;
+            };
+            pending.insert(chunk_index, result);
+            while let Some(result) = pending.remove(&next_to_deliver) {
+                next_to_deliver += 1;
+                let flow = match result {
+                    Ok(result) => on_result(result),
+                    Err(panic) => {
+                        stop.store(true, Ordering::Relaxed);
+                        resume_unwind(panic);
+                    }
+                };
+                if let ControlFlow::Break(value) = flow {
+                    stop.store(true, Ordering::Relaxed);
+                    return Some(value);
+                }
+            }
+        }
+        None
+    })
+}
+
+/// Search a range in consecutive chunks of `chunk_size`, passing the result of each
+/// chunk to `on_result` in chunk order until it breaks with a value, which is returned.
+#[cfg(not(feature = "parallel"))]
+pub fn search_chunks<R, B, S, C>(
+    range: Range<u32>,
+    chunk_size: u32,
+    search_chunk: S,
+    mut on_result: C,
+) -> Option<B>
+where
+    R: Send,
+    S: Fn(Range<u32>) -> R + Sync,
+    C: FnMut(R) -> ControlFlow<B>,
+{
+    for chunk in chunks(range, chunk_size.max(1)) {
+        if let ControlFlow::Break(value) = on_result(search_chunk(chunk)) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn chunks(range: Range<u32>, chunk_size: u32) -> impl ExactSizeIterator<Item = Range<u32>> {
+    let end = range.end;
+    range
+        .step_by(chunk_size as usize)
+        .map(move |start| start..start.saturating_add(chunk_size).min(end))
+}
+
+#[test]
+fn test_map() {
+    assert_eq!(map(&[1, 2, 3, 4, 5], |x| x * 2), [2, 4, 6, 8, 10]);
+    assert_eq!(map(&[] as &[u32], |x| x * 2), []);
+    let items = (0..1000).collect::<Vec<u32>>();
+    assert_eq!(map(&items, |&x| x + 1), (1..1001).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_search_chunks() {
+    let mut visited_chunks = Vec::new();
+    let found = search_chunks(
+        0..1000,
+        30,
+        |mut chunk| chunk.find(|&value| value % 7 == 6 && value > 100),
+        |result| {
+            visited_chunks.push(result);
+            result.map_or(ControlFlow::Continue(()), ControlFlow::Break)
+        },
+    );
+    assert_eq!(found, Some(104));
+    assert_eq!(visited_chunks, [None, None, None, Some(104)]);
+
+    let found = search_chunks(
+        0..95,
+        10,
+        |chunk| chunk.len(),
+        |length| {
+            if length < 10 {
+                ControlFlow::Break(length)
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+    assert_eq!(found, Some(5));
+    assert_eq!(
+        search_chunks(0..10, 3, |_| (), |_| ControlFlow::<()>::Continue(())),
+        None
+    );
+}
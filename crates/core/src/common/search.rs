@@ -0,0 +1,440 @@
+//! Shortest path searches over a [`Graph`] of states: breadth-first search, Dijkstra, A*
+//! and bidirectional breadth-first search, optionally reconstructing the path found.
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A graph to search, with states as nodes.
+pub trait Graph {
+    type State: Clone + Eq + Hash;
+
+    /// Call `on_neighbor` with each state reachable from a state in one step, and the cost
+    /// of that step.
+    fn neighbors(&self, state: &Self::State, on_neighbor: impl FnMut(Self::State, usize));
+
+    /// Whether a state is a goal, ending the search.
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound of the cost from a state to the nearest goal, guiding A* search.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// The result of a search which found a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub goal: S,
+    /// The cost of the path to the goal, which for breadth-first searches is its
+    /// number of steps.
+    pub cost: usize,
+    /// The states from the start to the goal, both included, if asked for.
+    // Only read by tests until a solution reports its route:
+    #[allow(dead_code)]
+    pub path: Option<Vec<S>>,
+    /// The number of states visited by the search.
+    #[allow(dead_code)]
+    pub visited: usize,
+}
+
+/// A shortest path search, created with [`Search::new`] and optionally asked to
+/// reconstruct the path found with [`Search::with_path`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Search {
+    record_path: bool,
+}
+
+/// The state with the lowest priority is popped first from the binary heap.
+struct QueueEntry<S> {
+    priority: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for QueueEntry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for QueueEntry<S> {}
+
+impl<S> PartialOrd for QueueEntry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for QueueEntry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prefer higher costs among equal priorities, as those are closer to a goal:
+        (other.priority, self.cost).cmp(&(self.priority, other.cost))
+    }
+}
+
+/// The state each reached state was reached from, if recording paths.
+struct Previous<S>(Option<HashMap<S, S>>);
+
+impl<S: Clone + Eq + Hash> Previous<S> {
+    fn new(record_path: bool) -> Self {
+        Self(record_path.then(HashMap::new))
+    }
+
+    fn record(&mut self, state: &S, from: &S) {
+        if let Some(previous) = &mut self.0 {
+            previous.insert(state.clone(), from.clone());
+        }
+    }
+
+    /// The path from the start to a state, ending with the state.
+    fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let previous = self.0.as_ref()?;
+        let mut path = vec![state.clone()];
+        while let Some(from) = previous.get(&path[path.len() - 1]) {
+            path.push(from.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The lowest cost found to each reached state.
+struct Reached<S> {
+    costs: HashMap<S, usize>,
+    previous: Previous<S>,
+}
+
+impl<S: Clone + Eq + Hash> Reached<S> {
+    fn new(record_path: bool, start: &S) -> Self {
+        let mut costs = HashMap::new();
+        costs.insert(start.clone(), 0);
+        Self {
+            costs,
+            previous: Previous::new(record_path),
+        }
+    }
+
+    fn cost(&self, state: &S) -> Option<usize> {
+        self.costs.get(state).copied()
+    }
+
+    /// Reach a state from another, returning whether it was not reached as cheaply before.
+    fn reach(&mut self, state: &S, cost: usize, from: &S) -> bool {
+        match self.costs.entry(state.clone()) {
+            Entry::Occupied(mut entry) => {
+                if cost >= *entry.get() {
+                    return false;
+                }
+                entry.insert(cost);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(cost);
+            }
+        }
+        self.previous.record(state, from);
+        true
+    }
+
+    fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.previous.path_to(state)
+    }
+}
+
+impl Search {
+    pub const fn new() -> Self {
+        Self { record_path: false }
+    }
+
+    /// Reconstruct the path to the goal, at the cost of remembering how each state was reached.
+    #[allow(dead_code)]
+    pub const fn with_path(self) -> Self {
+        Self { record_path: true }
+    }
+
+    /// Breadth-first search for the goal reached in the fewest steps, ignoring step costs.
+    pub fn bfs<G: Graph>(self, graph: &G, start: G::State) -> Option<SearchResult<G::State>> {
+        let mut reached = HashSet::from([start.clone()]);
+        let mut previous = Previous::new(self.record_path);
+        let mut to_visit = VecDeque::from([(0, start)]);
+        let mut visited = 0;
+
+        while let Some((steps, state)) = to_visit.pop_front() {
+            visited += 1;
+            if graph.is_goal(&state) {
+                return Some(SearchResult {
+                    path: previous.path_to(&state),
+                    goal: state,
+                    cost: steps,
+                    visited,
+                });
+            }
+            graph.neighbors(&state, |neighbor, _| {
+                if reached.insert(neighbor.clone()) {
+                    previous.record(&neighbor, &state);
+                    to_visit.push_back((steps + 1, neighbor));
+                }
+            });
+        }
+        None
+    }
+
+    /// Dijkstra's algorithm, searching for the goal with the lowest cost.
+    #[allow(dead_code)]
+    pub fn dijkstra<G: Graph>(self, graph: &G, start: G::State) -> Option<SearchResult<G::State>> {
+        self.best_first(graph, start, |_| 0)
+    }
+
+    /// A* search for the goal with the lowest cost, guided by [`Graph::heuristic`], which must
+    /// never overestimate the remaining cost.
+    pub fn a_star<G: Graph>(self, graph: &G, start: G::State) -> Option<SearchResult<G::State>> {
+        self.best_first(graph, start, |state| graph.heuristic(state))
+    }
+
+    fn best_first<G: Graph>(
+        self,
+        graph: &G,
+        start: G::State,
+        heuristic: impl Fn(&G::State) -> usize,
+    ) -> Option<SearchResult<G::State>> {
+        let mut reached = Reached::new(self.record_path, &start);
+        let mut to_visit = BinaryHeap::from([QueueEntry {
+            priority: heuristic(&start),
+            cost: 0,
+            state: start,
+        }]);
+        let mut visited = 0;
+
+        while let Some(QueueEntry { cost, state, .. }) = to_visit.pop() {
+            if reached.cost(&state).map_or(false, |best| cost > best) {
+                // Already visited with a lower cost:
+                continue;
+            }
+            visited += 1;
+            if graph.is_goal(&state) {
+                return Some(SearchResult {
+                    path: reached.path_to(&state),
+                    goal: state,
+                    cost,
+                    visited,
+                });
+            }
+            graph.neighbors(&state, |neighbor, step_cost| {
+                let new_cost = cost + step_cost;
+                if reached.reach(&neighbor, new_cost, &state) {
+                    to_visit.push(QueueEntry {
+                        priority: new_cost + heuristic(&neighbor),
+                        cost: new_cost,
+                        state: neighbor,
+                    });
+                }
+            });
+        }
+        None
+    }
+
+    /// Breadth-first search from both the start and a known goal, meeting in the middle,
+    /// which visits far fewer states when the number of neighbors is large.
+    ///
+    /// Steps must be reversible, as neighbors of the goal are searched in the same graph,
+    /// and [`Graph::is_goal`] is not used.
+    pub fn bidirectional_bfs<G: Graph>(
+        self,
+        graph: &G,
+        start: G::State,
+        goal: G::State,
+    ) -> Option<SearchResult<G::State>> {
+        if start == goal {
+            return Some(SearchResult {
+                path: self.record_path.then(|| vec![goal.clone()]),
+                goal,
+                cost: 0,
+                visited: 1,
+            });
+        }
+
+        let mut from_start = Reached::new(self.record_path, &start);
+        let mut from_goal = Reached::new(self.record_path, &goal);
+        let mut start_frontier = vec![start];
+        let mut goal_frontier = vec![goal.clone()];
+        let mut next_frontier = Vec::new();
+        let mut visited = 0;
+
+        while !start_frontier.is_empty() && !goal_frontier.is_empty() {
+            // Expand the smallest frontier one step, with all of its states:
+            let expand_start = start_frontier.len() <= goal_frontier.len();
+            let (frontier, reached, other_reached) = if expand_start {
+                (&mut start_frontier, &mut from_start, &from_goal)
+            } else {
+                (&mut goal_frontier, &mut from_goal, &from_start)
+            };
+
+            let mut meeting: Option<(usize, G::State)> = None;
+            for state in frontier.iter() {
+                visited += 1;
+                let steps = reached.cost(state).unwrap_or_default() + 1;
+                graph.neighbors(state, |neighbor, _| {
+                    if reached.cost(&neighbor).is_some() {
+                        return;
+                    }
+                    reached.reach(&neighbor, steps, state);
+                    if let Some(other_steps) = other_reached.cost(&neighbor) {
+                        if meeting.as_ref().map_or(true, |m| steps + other_steps < m.0) {
+                            meeting = Some((steps + other_steps, neighbor.clone()));
+                        }
+                    }
+                    next_frontier.push(neighbor);
+                });
+            }
+            std::mem::swap(frontier, &mut next_frontier);
+            next_frontier.clear();
+
+            if let Some((cost, meeting_state)) = meeting {
+                let path = from_start.path_to(&meeting_state).and_then(|mut path| {
+                    let mut rest = from_goal.path_to(&meeting_state)?;
+                    rest.pop();
+                    path.extend(rest.into_iter().rev());
+                    Some(path)
+                });
+                return Some(SearchResult {
+                    goal,
+                    cost,
+                    path,
+                    visited,
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+struct TestMaze {
+    walls: crate::common::grid::Grid<bool>,
+    goal: (i32, i32),
+    /// Cost of entering a position, by its x coordinate.
+    cost_per_column: fn(i32) -> usize,
+}
+
+#[cfg(test)]
+impl TestMaze {
+    fn parse(text: &str, cost_per_column: fn(i32) -> usize) -> (Self, (i32, i32)) {
+        #![allow(clippy::unwrap_used)]
+        let walls = crate::common::grid::Grid::parse(text, |c| Ok(c == b'#')).unwrap();
+        let find = |needle| {
+            text.lines()
+                .enumerate()
+                .find_map(|(y, line)| Some((line.find(needle)? as i32, y as i32)))
+                .unwrap()
+        };
+        let maze = Self {
+            walls,
+            goal: find('G'),
+            cost_per_column,
+        };
+        (maze, find('S'))
+    }
+}
+
+#[cfg(test)]
+impl Graph for TestMaze {
+    type State = (i32, i32);
+
+    fn neighbors(&self, &state: &(i32, i32), mut on_neighbor: impl FnMut((i32, i32), usize)) {
+        use crate::common::grid::Neighborhood;
+        for (neighbor, &wall) in self.walls.neighbors(state, Neighborhood::Orthogonal) {
+            if !wall {
+                on_neighbor(neighbor, (self.cost_per_column)(neighbor.0));
+            }
+        }
+    }
+
+    fn is_goal(&self, state: &(i32, i32)) -> bool {
+        *state == self.goal
+    }
+
+    fn heuristic(&self, &(x, y): &(i32, i32)) -> usize {
+        (x.abs_diff(self.goal.0) + y.abs_diff(self.goal.1)) as usize
+    }
+}
+
+#[cfg(test)]
+fn assert_path(found: &SearchResult<(i32, i32)>, start: (i32, i32), steps: usize) {
+    #![allow(clippy::unwrap_used)]
+    let path = found.path.as_ref().unwrap();
+    assert_eq!(path.len(), steps + 1);
+    assert_eq!((path[0], path[steps]), (start, found.goal));
+    assert!(path
+        .windows(2)
+        .all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+}
+
+#[test]
+fn test_search() {
+    #![allow(clippy::unwrap_used)]
+    let maze = "\
+S....
+.###.
+.#G..
+.#.#.
+...#.";
+    let (maze, start) = TestMaze::parse(maze, |_| 1);
+    let search = Search::new().with_path();
+
+    for found in [
+        search.bfs(&maze, start),
+        search.dijkstra(&maze, start),
+        search.a_star(&maze, start),
+        search.bidirectional_bfs(&maze, start, (2, 2)),
+    ] {
+        let found = found.unwrap();
+        assert_eq!((found.goal, found.cost), ((2, 2), 8));
+        assert_path(&found, start, 8);
+    }
+    let found = search.bidirectional_bfs(&maze, start, (4, 4)).unwrap();
+    assert_eq!(found.cost, 8);
+    assert_path(&found, start, 8);
+    assert_eq!(
+        search.bfs(&maze, (2, 2)).map(|f| f.path),
+        Some(Some(vec![(2, 2)]))
+    );
+
+    // Paths are only reconstructed when asked for:
+    assert_eq!(Search::new().a_star(&maze, start).unwrap().path, None);
+    // The heuristic avoids visiting states leading away from the goal:
+    assert!(
+        Search::new().a_star(&maze, start).unwrap().visited
+            < Search::new().dijkstra(&maze, start).unwrap().visited
+    );
+
+    let (walled_in, start) = TestMaze::parse("S#G", |_| 1);
+    assert_eq!(Search::new().bfs(&walled_in, start), None);
+    assert_eq!(Search::new().dijkstra(&walled_in, start), None);
+    assert_eq!(
+        Search::new().bidirectional_bfs(&walled_in, start, (2, 0)),
+        None
+    );
+}
+
+#[test]
+fn test_weighted_search() {
+    #![allow(clippy::unwrap_used)]
+    // Entering the second column is expensive, so the cheapest path crosses it only once:
+    let maze = "\
+S.#..
+....#
+..#.G";
+    let (maze, start) = TestMaze::parse(maze, |x| if x == 1 { 10 } else { 1 });
+    let search = Search::new().with_path();
+
+    // Breadth-first search finds the path with the fewest steps, ignoring costs:
+    assert_eq!(search.bfs(&maze, start).unwrap().cost, 6);
+    for found in [search.dijkstra(&maze, start), search.a_star(&maze, start)] {
+        let found = found.unwrap();
+        assert_eq!(found.cost, 15);
+        assert_eq!(
+            found.path.unwrap(),
+            [(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (3, 2), (4, 2)]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Replays the inputs in `fuzz-corpus/`, which once crashed or hung when found by fuzzing,
+//! checking that solving them now returns an error within a time limit.
+//!
+//! Add new cases with `cargo run --features fuzzing -- add-fuzz-case <fuzzer-files>`, which
+//! minimizes them first. Cases are named like `year2019_day05_part2_<hash>.txt`.
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Generous to allow for unoptimized test builds.
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+/// Set to a case name to make the `solve_case` test solve the input on stdin.
+const CASE_VARIABLE: &str = "AOC_FUZZ_CORPUS_CASE";
+
+/// Prefixes the outcome printed by the `solve_case` test.
+const OUTCOME_PREFIX: &str = "fuzz-corpus-outcome: ";
+
+fn parse_case_name(file_name: &str) -> Option<(u16, u8, u8)> {
+    let mut components = file_name.strip_suffix(".txt")?.split('_');
+    let year = components.next()?.strip_prefix("year")?.parse().ok()?;
+    let day = components.next()?.strip_prefix("day")?.parse().ok()?;
+    let part = components.next()?.strip_prefix("part")?.parse().ok()?;
+    Some((year, day, part))
+}
+
+/// Solve in a child process running the `solve_case` test, which can be killed if it hangs,
+/// returning a description of any problem with the outcome.
+fn replay(year: u16, day: u8, part: u8, input: &str) -> Option<String> {
+    let executable = match std::env::current_exe() {
+        Ok(executable) => executable,
+        Err(error) => return Some(format!("Unable to find the test executable: {error}")),
+    };
+    let mut child = match Command::new(executable)
+        .args(["--exact", "fuzz_corpus::solve_case", "--nocapture"])
+        .env(
+            CASE_VARIABLE,
+            format!("year{year}_day{day:02}_part{part}.txt"),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(error) => return Some(format!("Unable to start solving: {error}")),
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The child may crash before reading all of its input:
+        let _ = stdin.write_all(input.as_bytes());
+    }
+
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if start.elapsed() > TIME_LIMIT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Some(format!("Did not finish within {TIME_LIMIT:?}"));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+            Err(error) => return Some(format!("Unable to wait for solving: {error}")),
+        }
+    }
+
+    let mut output = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        let _ = stdout.read_to_string(&mut output);
+    }
+    match output
+        .lines()
+        .find_map(|line| Some(line.split_once(OUTCOME_PREFIX)?.1))
+    {
+        Some("error") => None,
+        Some(answer) => Some(format!("Returned an answer instead of an error: {answer}")),
+        None => Some("Panicked".to_string()),
+    }
+}
+
+/// Solves a single case for [replay] when run in a child process, doing nothing otherwise.
+#[test]
+fn solve_case() {
+    #![allow(clippy::unwrap_used)]
+    let Ok(case_name) = std::env::var(CASE_VARIABLE) else {
+        return;
+    };
+    let (year, day, part) = parse_case_name(&case_name).unwrap();
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input).unwrap();
+    let outcome = crate::solve(year, day, part, &input).unwrap_or_else(|_| "error".to_string());
+    println!("{OUTCOME_PREFIX}{outcome}");
+}
+
+#[test]
+fn fuzz_corpus() {
+    #![allow(clippy::unwrap_used)]
+    let corpus_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz-corpus");
+    let mut failures = Vec::new();
+    for entry in std::fs::read_dir(corpus_directory).unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        let (year, day, part) = parse_case_name(&file_name)
+            .unwrap_or_else(|| panic!("Invalid corpus file name: {file_name}"));
+        let input = std::fs::read_to_string(&path).unwrap();
+        if let Some(failure) = replay(year, day, part, &input) {
+            failures.push(format!("{file_name}: {failure}"));
+        }
+    }
+    failures.sort_unstable();
+    assert!(
+        failures.is_empty(),
+        "Fuzz corpus failures:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_replay() {
+    assert_eq!(
+        parse_case_name("year2019_day05_part2_0123abcd.txt"),
+        Some((2019, 5, 2))
+    );
+    assert_eq!(parse_case_name("README.md"), None);
+
+    assert_eq!(replay(2019, 1, 1, "x"), None);
+    assert_eq!(
+        replay(2019, 1, 1, "14"),
+        Some("Returned an answer instead of an error: 2".to_string())
+    );
+}
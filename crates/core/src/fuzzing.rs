@@ -0,0 +1,459 @@
+//! Structure-aware fuzzing, generating syntactically valid inputs with random values for
+//! problems with a grammar so that fuzzing reaches the solving logic instead of stopping at
+//! the first parse error.
+//!
+//! Problems with alternative implementations are also differentially tested, checking that the
+//! implementations give identical answers.
+//!
+//! Used by the afl, honggfuzz and libFuzzer targets, which all pass their data to [fuzz].
+use std::fmt::Write;
+
+use arbitrary::{Result, Unstructured};
+
+use crate::input::Input;
+
+/// Solve a problem selected by the first three bytes of fuzzing data, with an input generated
+/// from the remaining bytes.
+pub fn fuzz(data: &[u8]) {
+    if let Some((year, day, part, input)) = decode(data) {
+        let _ = crate::solve(year, day, part, &input);
+        check_alternatives(year, day, part, &input);
+    }
+}
+
+/// The year, day, part and input which fuzzing data is turned into.
+pub fn decode(data: &[u8]) -> Option<(u16, u8, u8, String)> {
+    if data.len() < 4 {
+        return None;
+    }
+    let year = 2015 + u16::from(data[0] % 8);
+    let day = 1 + data[1] % 25;
+    let part = 1 + data[2] % 2;
+    let input = arbitrary_input(year, day, &mut Unstructured::new(&data[3..])).ok()?;
+    Some((year, day, part, input))
+}
+
+/// A solution returning its answer as text.
+type Solver = fn(&Input) -> Result<String, String>;
+
+/// Alternative implementations solving a problem, which should give identical answers.
+struct Alternatives {
+    year: u16,
+    day: u8,
+    implementations: [(&'static str, Solver); 2],
+}
+
+impl Alternatives {
+    /// The answers of the implementations if they differ, ignoring errors as implementations
+    /// may differ in what inputs they accept.
+    fn divergence(&self, part: u8, input: &str) -> Option<[String; 2]> {
+        if input.trim().is_empty() {
+            return None;
+        }
+        let input = if part == 1 {
+            Input::part_one(input)
+        } else {
+            Input::part_two(input)
+        };
+        let [(_, first), (_, second)] = self.implementations;
+        match (first(&input), second(&input)) {
+            (Ok(first_answer), Ok(second_answer)) if first_answer != second_answer => {
+                Some([first_answer, second_answer])
+            }
+            _ => None,
+        }
+    }
+
+    /// Remove lines from a diverging input for as long as the answers still differ.
+    fn minimize(&self, part: u8, input: &str) -> String {
+        let mut lines = input.lines().collect::<Vec<_>>();
+        let mut line_idx = 0;
+        while line_idx < lines.len() {
+            let mut candidate = lines.clone();
+            candidate.remove(line_idx);
+            if self.divergence(part, &candidate.join("\n")).is_some() {
+                lines = candidate;
+            } else {
+                line_idx += 1;
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+fn alternatives() -> Vec<Alternatives> {
+    #[cfg_attr(not(feature = "simd"), allow(unused_mut))]
+    let mut result = vec![Alternatives {
+        year: 2020,
+        day: 14,
+        implementations: [
+            ("solve", |input| {
+                crate::year2020::day14::solve(input).map(|answer| answer.to_string())
+            }),
+            ("simulation", |input| {
+                crate::year2020::day14::solve_by_simulation(input).map(|answer| answer.to_string())
+            }),
+        ],
+    }];
+    #[cfg(feature = "simd")]
+    result.push(Alternatives {
+        year: 2022,
+        day: 23,
+        implementations: [
+            ("scalar", |input| {
+                crate::year2022::day23::solve_scalar(input).map(|answer| answer.to_string())
+            }),
+            ("simd", |input| {
+                crate::year2022::day23_simd::solve(input).map(|answer| answer.to_string())
+            }),
+        ],
+    });
+    result
+}
+
+/// Check that alternative implementations of a problem give identical answers, panicking with
+/// a minimized input if they do not.
+pub fn check_alternatives(year: u16, day: u8, part: u8, input: &str) {
+    let input = input.trim_end();
+    if input.is_empty() || !input.is_ascii() || !matches!(part, 1 | 2) {
+        return;
+    }
+    for alternatives in alternatives() {
+        if (alternatives.year, alternatives.day) != (year, day) {
+            continue;
+        }
+        if let Some(answers) = alternatives.divergence(part, input) {
+            let [(first_name, _), (second_name, _)] = alternatives.implementations;
+            panic!(
+                "{year} day {day} part {part}: {first_name} answered {}, {second_name} answered {} - minimized input:\n{}",
+                answers[0],
+                answers[1],
+                alternatives.minimize(part, input)
+            );
+        }
+    }
+}
+
+/// Generate an input for a problem from its grammar, or use the data as text for problems
+/// without a grammar.
+pub fn arbitrary_input(year: u16, day: u8, u: &mut Unstructured) -> Result<String> {
+    match (year, day) {
+        (2018, 19) => elfcode_program(u, None),
+        (2018, 21) => elfcode_program(u, Some(31)),
+        (2019, 2 | 5 | 7 | 9 | 11 | 13 | 15 | 17 | 19 | 21 | 23 | 25) => int_code_program(u),
+        (2020, 14) => bit_mask_program(u),
+        (2020, 19) => message_rules(u),
+        (2022, 16) => valve_graph(u),
+        (2022, 23) => elf_grid(u),
+        _ => {
+            let bytes = u.bytes(u.len())?;
+            std::str::from_utf8(bytes)
+                .map(str::to_string)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+}
+
+/// An Intcode program of valid instructions, as used in 2019.
+fn int_code_program(u: &mut Unstructured) -> Result<String> {
+    // The opcodes with their number of parameters, and whether the last one is written to:
+    const OPCODES: [(i64, u32, bool); 10] = [
+        (1, 3, true),
+        (2, 3, true),
+        (3, 1, true),
+        (4, 1, false),
+        (5, 2, false),
+        (6, 2, false),
+        (7, 3, true),
+        (8, 3, true),
+        (9, 1, false),
+        (99, 0, false),
+    ];
+
+    let mut words = Vec::new();
+    for _ in 0..u.int_in_range(1..=64)? {
+        let &(opcode, parameter_count, writes) = u.choose(&OPCODES)?;
+        let mut instruction = opcode;
+        let mut parameters = Vec::with_capacity(parameter_count as usize);
+        for position in 1..=parameter_count {
+            let mode = if writes && position == parameter_count {
+                // Writing is not possible in immediate mode:
+                *u.choose(&[0, 2])?
+            } else {
+                u.int_in_range(0..=2)?
+            };
+            instruction += mode * 10_i64.pow(position + 1);
+            parameters.push(if mode == 1 {
+                u.int_in_range(-1000..=1000)?
+            } else {
+                u.int_in_range(0..=255)?
+            });
+        }
+        words.push(instruction);
+        words.extend(parameters);
+    }
+
+    Ok(words
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// An elfcode program, as used in 2018, optionally with a fixed number of instructions.
+fn elfcode_program(u: &mut Unstructured, instruction_count: Option<usize>) -> Result<String> {
+    // The opcodes with whether their a and b operands are registers:
+    const OPCODES: [(&str, bool, bool); 16] = [
+        ("addr", true, true),
+        ("addi", true, false),
+        ("mulr", true, true),
+        ("muli", true, false),
+        ("banr", true, true),
+        ("bani", true, false),
+        ("borr", true, true),
+        ("bori", true, false),
+        ("setr", true, false),
+        ("seti", false, false),
+        ("gtir", false, true),
+        ("gtri", true, false),
+        ("gtrr", true, true),
+        ("eqir", false, true),
+        ("eqri", true, false),
+        ("eqrr", true, true),
+    ];
+    let operand = |u: &mut Unstructured, register: bool| -> Result<u16> {
+        u.int_in_range(0..=if register { 5 } else { 255 })
+    };
+
+    let mut text = format!("#ip {}", operand(u, true)?);
+    let instruction_count = match instruction_count {
+        Some(count) => count,
+        None => u.int_in_range(1..=40)?,
+    };
+    for _ in 0..instruction_count {
+        let &(name, a_is_register, b_is_register) = u.choose(&OPCODES)?;
+        let _ = write!(
+            text,
+            "\n{name} {} {} {}",
+            operand(u, a_is_register)?,
+            operand(u, b_is_register)?,
+            operand(u, true)?
+        );
+    }
+    Ok(text)
+}
+
+/// A program of masks and memory writes, as in 2020 day 14.
+fn bit_mask_program(u: &mut Unstructured) -> Result<String> {
+    let mut text = String::new();
+    for line_idx in 0..u.int_in_range(1..=64)? {
+        if line_idx == 0 || u.ratio(1, 4)? {
+            text.push_str("mask = ");
+            for _ in 0..36 {
+                // Floating bits are kept rare, as each doubles the number of addresses written:
+                text.push(if u.ratio(1, 8)? {
+                    'X'
+                } else {
+                    *u.choose(&['0', '1'])?
+                });
+            }
+            text.push('\n');
+        } else {
+            let address = u.int_in_range(0..=0xffff_u64)?;
+            let value = u.int_in_range(0..=(1_u64 << 36) - 1)?;
+            let _ = writeln!(text, "mem[{address}] = {value}");
+        }
+    }
+    Ok(text)
+}
+
+/// Message rules followed by messages, as in 2020 day 19.
+fn message_rules(u: &mut Unstructured) -> Result<String> {
+    let rule_count = u.int_in_range(1..=64_u8)?;
+    let mut text = String::new();
+    for rule_id in 0..rule_count {
+        let rule = if rule_id + 1 == rule_count || u.ratio(1, 3)? {
+            u.choose(&["\"a\"", "\"b\""])?.to_string()
+        } else {
+            let mut alternatives = Vec::new();
+            for _ in 0..u.int_in_range(1..=2)? {
+                // Only referring to later rules avoids cycles, which would never terminate:
+                let mut sequence = Vec::new();
+                for _ in 0..u.int_in_range(1..=3)? {
+                    sequence.push(u.int_in_range(rule_id + 1..=rule_count - 1)?.to_string());
+                }
+                alternatives.push(sequence.join(" "));
+            }
+            alternatives.join(" | ")
+        };
+        let _ = writeln!(text, "{rule_id}: {rule}");
+    }
+
+    text.push('\n');
+    for _ in 0..u.int_in_range(1..=16)? {
+        for _ in 0..u.int_in_range(1..=16)? {
+            text.push(*u.choose(&['a', 'b'])?);
+        }
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// A connected graph of valves including the starting valve `AA`, as in 2022 day 16.
+fn valve_graph(u: &mut Unstructured) -> Result<String> {
+    let valve_count = u.int_in_range(2..=16_usize)?;
+    let start_index = u.choose_index(valve_count)?;
+    let name = |index: usize| {
+        if index == start_index {
+            "AA".to_string()
+        } else {
+            let n = index as u8 + 1;
+            format!("{}{}", char::from(b'A' + n / 26), char::from(b'A' + n % 26))
+        }
+    };
+
+    let mut tunnels = vec![Vec::new(); valve_count];
+    let mut connect = |from: usize, to: usize| {
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    };
+    // A random spanning tree keeps all valves reachable, with extra tunnels adding cycles:
+    for valve in 1..valve_count {
+        connect(valve, u.choose_index(valve)?);
+    }
+    for _ in 0..u.int_in_range(0..=valve_count)? {
+        connect(u.choose_index(valve_count)?, u.choose_index(valve_count)?);
+    }
+
+    let mut text = String::new();
+    for (valve, tunnels) in tunnels.iter().enumerate() {
+        let flow_rate = if valve == start_index || u.ratio(1, 2)? {
+            0
+        } else {
+            u.int_in_range(1..=25)?
+        };
+        let names = tunnels.iter().map(|&to| name(to)).collect::<Vec<_>>();
+        let _ = writeln!(
+            text,
+            "Valve {} has flow rate={flow_rate}; {} {}",
+            name(valve),
+            if names.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            },
+            names.join(", ")
+        );
+    }
+    Ok(text)
+}
+
+/// A grid of elves, as in 2022 day 23.
+fn elf_grid(u: &mut Unstructured) -> Result<String> {
+    let (width, height) = (u.int_in_range(1..=24)?, u.int_in_range(1..=24)?);
+    let mut text = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            text.push(if u.ratio(1, 3)? { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+fn pseudo_random_data() -> Vec<u8> {
+    // From a xorshift generator:
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..4096)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_arbitrary_input() {
+    #![allow(clippy::unwrap_used)]
+    let data = pseudo_random_data();
+
+    for offset in (0..2048).step_by(64) {
+        let generate = |year, day| {
+            arbitrary_input(year, day, &mut Unstructured::new(&data[offset..])).unwrap()
+        };
+
+        assert!(crate::year2019::int_code::Program::parse(&generate(2019, 9)).is_ok());
+        let elfcode = crate::year2018::elfcode::Program::parse(&generate(2018, 21)).unwrap();
+        assert_eq!(elfcode.instructions.len(), 31);
+        assert!(crate::year2018::elfcode::Program::parse(&generate(2018, 19)).is_ok());
+
+        let message_rules = generate(2020, 19);
+        assert!(crate::solve(2020, 19, 1, &message_rules).is_ok());
+        assert!(crate::solve(2020, 19, 2, &message_rules).is_ok());
+        assert!(crate::solve(2022, 16, 1, &generate(2022, 16)).is_ok());
+    }
+
+    assert_eq!(
+        arbitrary_input(2019, 1, &mut Unstructured::new(b"12\n14")),
+        Ok("12\n14".to_string())
+    );
+    assert!(arbitrary_input(2019, 1, &mut Unstructured::new(&[0xff])).is_err());
+}
+
+#[test]
+fn test_alternatives() {
+    #![allow(clippy::unwrap_used)]
+    let data = pseudo_random_data();
+    for alternatives in alternatives() {
+        let (year, day) = (alternatives.year, alternatives.day);
+        let bundled_input = std::fs::read_to_string(format!(
+            "{}/src/year{year}/day{day:02}_input.txt",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        for part in [1, 2] {
+            // Both implementations should solve the bundled input, so that it is compared:
+            let input = if part == 1 {
+                Input::part_one(bundled_input.trim_end())
+            } else {
+                Input::part_two(bundled_input.trim_end())
+            };
+            for (name, solver) in alternatives.implementations {
+                assert!(solver(&input).is_ok(), "{year} day {day} {name} failed");
+            }
+            check_alternatives(year, day, part, &bundled_input);
+            for offset in (0..2048).step_by(64) {
+                let input =
+                    arbitrary_input(year, day, &mut Unstructured::new(&data[offset..])).unwrap();
+                check_alternatives(year, day, part, &input);
+            }
+        }
+    }
+
+    let diverging = Alternatives {
+        year: 2015,
+        day: 1,
+        implementations: [
+            ("lines", |input| Ok(input.text.lines().count().to_string())),
+            ("buggy", |input| {
+                Ok(input
+                    .text
+                    .lines()
+                    .filter(|&line| line != "b")
+                    .count()
+                    .to_string())
+            }),
+        ],
+    };
+    assert_eq!(diverging.divergence(1, "a\nc"), None);
+    assert_eq!(
+        diverging.divergence(1, "a\nb\nc"),
+        Some(["3".to_string(), "2".to_string()])
+    );
+    assert_eq!(diverging.minimize(1, "a\nb\nc\nb"), "b");
+}
@@ -2,6 +2,13 @@
 #![allow(unused)]
 
 use std::cell::RefCell;
+#[cfg(feature = "visualization")]
+use std::ops::ControlFlow;
+
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
+#[cfg(feature = "visualization")]
+use crate::FrameListener;
 
 #[derive(Copy, Clone)]
 pub enum Part {
@@ -14,6 +21,12 @@ pub struct Input<'a> {
     pub text: &'a str,
     #[cfg(feature = "visualization")]
     pub rendered_svg: RefCell<String>,
+    /// Receives intermediate frames while solving, if anyone is listening.
+    #[cfg(feature = "visualization")]
+    pub frame_listener: Option<FrameListener<'a>>,
+    /// Receives drawing commands while solving, if anyone is painting.
+    #[cfg(feature = "painter")]
+    pub painter: Option<&'a RefCell<&'a mut dyn Painter>>,
 }
 
 impl<'a> Input<'a> {
@@ -34,7 +47,42 @@ impl<'a> Input<'a> {
         }
     }
 
-    #[cfg(test)]
+    /// Emit an intermediate frame to the frame listener, if any.
+    ///
+    /// The frame is only rendered if the listener wants it as SVG. Fails if the listener
+    /// asks for solving to stop.
+    #[cfg(feature = "visualization")]
+    pub fn emit_frame<F: FnOnce() -> String>(&self, render: F) -> Result<(), String> {
+        let flow = match self.frame_listener {
+            Some(FrameListener::Svg(listener)) => listener(render()),
+            Some(FrameListener::Progress(listener)) => listener(),
+            None => ControlFlow::Continue(()),
+        };
+        match flow {
+            ControlFlow::Continue(()) => Ok(()),
+            ControlFlow::Break(()) => Err("Stopped by the frame listener".to_string()),
+        }
+    }
+
+    /// Whether intermediate frames are rendered, so that state only used to render them is needed.
+    #[cfg(feature = "visualization")]
+    pub const fn renders_frames(&self) -> bool {
+        matches!(self.frame_listener, Some(FrameListener::Svg(_)))
+    }
+
+    /// Draw a frame on the painter, if any.
+    ///
+    /// The frame is only drawn if a painter is present, and is ended after drawing.
+    #[cfg(feature = "painter")]
+    pub fn paint_frame<F: FnOnce(&mut dyn Painter)>(&self, draw: F) {
+        if let Some(painter) = self.painter {
+            let mut painter = painter.borrow_mut();
+            draw(&mut **painter);
+            painter.end_frame();
+        }
+    }
+
+    #[cfg(any(test, feature = "fuzzing"))]
     #[allow(clippy::missing_const_for_fn)]
     pub fn part_one(text: &'a str) -> Self {
         Self {
@@ -42,10 +90,14 @@ impl<'a> Input<'a> {
             text,
             #[cfg(feature = "visualization")]
             rendered_svg: RefCell::new("".to_string()),
+            #[cfg(feature = "visualization")]
+            frame_listener: None,
+            #[cfg(feature = "painter")]
+            painter: None,
         }
     }
 
-    #[cfg(test)]
+    #[cfg(any(test, feature = "fuzzing"))]
     #[allow(clippy::missing_const_for_fn)]
     pub fn part_two(text: &'a str) -> Self {
         Self {
@@ -53,6 +105,10 @@ impl<'a> Input<'a> {
             text,
             #[cfg(feature = "visualization")]
             rendered_svg: RefCell::new("".to_string()),
+            #[cfg(feature = "visualization")]
+            frame_listener: None,
+            #[cfg(feature = "painter")]
+            painter: None,
         }
     }
 }
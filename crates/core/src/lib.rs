@@ -25,10 +25,19 @@ assert_eq!(solution, Ok("2".to_string()));
 */
 #![crate_name = "advent_of_code"]
 
+// Solutions allocate differently when visualizing, so budgets are only checked without painting:
+#[cfg(all(test, feature = "count-allocations", not(feature = "painter")))]
+mod allocation_budgets;
 mod common;
+#[cfg(test)]
+mod fuzz_corpus;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
 #[cfg_attr(test, macro_use)]
 mod input;
 mod mod_exp;
+#[cfg(feature = "painter")]
+pub mod painter;
 mod year2015;
 mod year2016;
 mod year2017;
@@ -47,6 +56,230 @@ fn to_stringer_input<T: ToString>(
     function(input).map(|value| value.to_string())
 }
 
+type Solver = fn(&input::Input) -> Result<String, String>;
+
+macro_rules! solver {
+    ($year:literal, $day:literal, $($module:ident)::+) => {
+        ($year, $day, |input| to_stringer_input($($module)::+::solve, input))
+    };
+}
+
+/// The solver of every supported problem, ordered by year and day.
+const SOLVERS: &[(u16, u8, Solver)] = &[
+    solver!(2015, 1, year2015::day01),
+    solver!(2015, 2, year2015::day02),
+    solver!(2015, 3, year2015::day03),
+    solver!(2015, 4, year2015::day04),
+    solver!(2015, 5, year2015::day05),
+    solver!(2015, 6, year2015::day06),
+    solver!(2015, 7, year2015::day07),
+    solver!(2015, 8, year2015::day08),
+    solver!(2015, 9, year2015::day09),
+    solver!(2015, 10, year2015::day10),
+    solver!(2015, 11, year2015::day11),
+    solver!(2015, 12, year2015::day12),
+    solver!(2015, 13, year2015::day13),
+    solver!(2015, 14, year2015::day14),
+    solver!(2015, 15, year2015::day15),
+    solver!(2015, 16, year2015::day16),
+    solver!(2015, 17, year2015::day17),
+    solver!(2015, 18, year2015::day18),
+    solver!(2015, 19, year2015::day19),
+    solver!(2015, 20, year2015::day20),
+    solver!(2015, 21, year2015::day21),
+    solver!(2015, 22, year2015::day22),
+    solver!(2015, 23, year2015::day23),
+    solver!(2015, 24, year2015::day24),
+    solver!(2015, 25, year2015::day25),
+    solver!(2016, 1, year2016::day01),
+    solver!(2016, 2, year2016::day02),
+    solver!(2016, 3, year2016::day03),
+    solver!(2016, 4, year2016::day04),
+    solver!(2016, 5, year2016::day05),
+    solver!(2016, 6, year2016::day06),
+    solver!(2016, 7, year2016::day07),
+    solver!(2016, 8, year2016::day08),
+    solver!(2016, 9, year2016::day09),
+    solver!(2016, 10, year2016::day10),
+    solver!(2016, 11, year2016::day11),
+    solver!(2016, 12, year2016::day12),
+    solver!(2016, 13, year2016::day13),
+    solver!(2016, 14, year2016::day14),
+    solver!(2016, 15, year2016::day15),
+    solver!(2016, 16, year2016::day16),
+    solver!(2016, 17, year2016::day17),
+    solver!(2016, 18, year2016::day18),
+    solver!(2016, 19, year2016::day19),
+    solver!(2016, 20, year2016::day20),
+    solver!(2016, 21, year2016::day21),
+    solver!(2016, 22, year2016::day22),
+    solver!(2016, 23, year2016::day23),
+    solver!(2016, 24, year2016::day24),
+    solver!(2016, 25, year2016::day25),
+    solver!(2017, 1, year2017::day01),
+    solver!(2017, 2, year2017::day02),
+    solver!(2017, 3, year2017::day03),
+    solver!(2017, 4, year2017::day04),
+    solver!(2017, 5, year2017::day05),
+    solver!(2017, 6, year2017::day06),
+    solver!(2017, 7, year2017::day07),
+    solver!(2017, 8, year2017::day08),
+    solver!(2017, 9, year2017::day09),
+    solver!(2017, 10, year2017::day10),
+    solver!(2017, 11, year2017::day11),
+    solver!(2017, 12, year2017::day12),
+    solver!(2017, 13, year2017::day13),
+    solver!(2017, 14, year2017::day14),
+    solver!(2017, 15, year2017::day15),
+    solver!(2017, 16, year2017::day16),
+    solver!(2017, 17, year2017::day17),
+    solver!(2017, 18, year2017::day18),
+    solver!(2017, 19, year2017::day19),
+    solver!(2017, 20, year2017::day20),
+    solver!(2017, 21, year2017::day21),
+    solver!(2017, 22, year2017::day22),
+    solver!(2017, 23, year2017::day23),
+    solver!(2017, 24, year2017::day24),
+    solver!(2017, 25, year2017::day25),
+    solver!(2018, 1, year2018::day01),
+    solver!(2018, 2, year2018::day02),
+    solver!(2018, 3, year2018::day03),
+    solver!(2018, 4, year2018::day04),
+    solver!(2018, 5, year2018::day05),
+    solver!(2018, 6, year2018::day06),
+    solver!(2018, 7, year2018::day07),
+    solver!(2018, 8, year2018::day08),
+    solver!(2018, 9, year2018::day09),
+    solver!(2018, 10, year2018::day10),
+    solver!(2018, 11, year2018::day11),
+    solver!(2018, 12, year2018::day12),
+    solver!(2018, 13, year2018::day13),
+    solver!(2018, 14, year2018::day14),
+    solver!(2018, 15, year2018::day15),
+    solver!(2018, 16, year2018::day16),
+    solver!(2018, 17, year2018::day17),
+    solver!(2018, 18, year2018::day18),
+    solver!(2018, 19, year2018::day19),
+    solver!(2018, 20, year2018::day20),
+    solver!(2018, 21, year2018::day21),
+    solver!(2018, 22, year2018::day22),
+    solver!(2018, 23, year2018::day23),
+    solver!(2018, 24, year2018::day24),
+    solver!(2018, 25, year2018::day25),
+    solver!(2019, 1, year2019::day01),
+    solver!(2019, 2, year2019::day02),
+    solver!(2019, 3, year2019::day03),
+    solver!(2019, 4, year2019::day04),
+    solver!(2019, 5, year2019::day05),
+    solver!(2019, 6, year2019::day06),
+    solver!(2019, 7, year2019::day07),
+    solver!(2019, 8, year2019::day08),
+    solver!(2019, 9, year2019::day09),
+    solver!(2019, 10, year2019::day10),
+    solver!(2019, 11, year2019::day11),
+    solver!(2019, 12, year2019::day12),
+    solver!(2019, 13, year2019::day13),
+    solver!(2019, 14, year2019::day14),
+    solver!(2019, 15, year2019::day15),
+    solver!(2019, 16, year2019::day16),
+    solver!(2019, 17, year2019::day17),
+    solver!(2019, 18, year2019::day18),
+    solver!(2019, 19, year2019::day19),
+    solver!(2019, 20, year2019::day20),
+    solver!(2019, 21, year2019::day21),
+    solver!(2019, 22, year2019::day22),
+    solver!(2019, 23, year2019::day23),
+    solver!(2019, 24, year2019::day24),
+    solver!(2019, 25, year2019::day25),
+    solver!(2020, 1, year2020::day01),
+    solver!(2020, 2, year2020::day02),
+    solver!(2020, 3, year2020::day03),
+    solver!(2020, 4, year2020::day04),
+    solver!(2020, 5, year2020::day05),
+    solver!(2020, 6, year2020::day06),
+    solver!(2020, 7, year2020::day07),
+    solver!(2020, 8, year2020::day08),
+    solver!(2020, 9, year2020::day09),
+    solver!(2020, 10, year2020::day10),
+    solver!(2020, 11, year2020::day11),
+    solver!(2020, 12, year2020::day12),
+    solver!(2020, 13, year2020::day13),
+    solver!(2020, 14, year2020::day14),
+    solver!(2020, 15, year2020::day15),
+    solver!(2020, 16, year2020::day16),
+    solver!(2020, 17, year2020::day17),
+    solver!(2020, 18, year2020::day18),
+    solver!(2020, 19, year2020::day19),
+    solver!(2020, 20, year2020::day20),
+    solver!(2020, 21, year2020::day21),
+    solver!(2020, 22, year2020::day22),
+    solver!(2020, 23, year2020::day23),
+    solver!(2020, 24, year2020::day24),
+    solver!(2020, 25, year2020::day25),
+    solver!(2021, 1, year2021::day01),
+    solver!(2021, 2, year2021::day02),
+    solver!(2021, 3, year2021::day03),
+    solver!(2021, 4, year2021::day04),
+    solver!(2021, 5, year2021::day05),
+    solver!(2021, 6, year2021::day06),
+    solver!(2021, 7, year2021::day07),
+    solver!(2021, 8, year2021::day08),
+    solver!(2021, 9, year2021::day09),
+    solver!(2021, 10, year2021::day10),
+    solver!(2021, 11, year2021::day11),
+    solver!(2021, 12, year2021::day12),
+    solver!(2021, 13, year2021::day13),
+    solver!(2021, 14, year2021::day14),
+    solver!(2021, 15, year2021::day15),
+    solver!(2021, 16, year2021::day16),
+    solver!(2021, 17, year2021::day17),
+    solver!(2021, 18, year2021::day18),
+    solver!(2021, 19, year2021::day19),
+    solver!(2021, 20, year2021::day20),
+    solver!(2021, 21, year2021::day21),
+    solver!(2021, 22, year2021::day22),
+    solver!(2021, 23, year2021::day23),
+    solver!(2021, 24, year2021::day24),
+    solver!(2021, 25, year2021::day25),
+    solver!(2022, 1, year2022::day01),
+    solver!(2022, 2, year2022::day02),
+    solver!(2022, 3, year2022::day03),
+    solver!(2022, 4, year2022::day04),
+    solver!(2022, 5, year2022::day05),
+    solver!(2022, 6, year2022::day06),
+    solver!(2022, 7, year2022::day07),
+    solver!(2022, 8, year2022::day08),
+    solver!(2022, 9, year2022::day09),
+    solver!(2022, 10, year2022::day10),
+    solver!(2022, 11, year2022::day11),
+    solver!(2022, 12, year2022::day12),
+    solver!(2022, 13, year2022::day13),
+    solver!(2022, 14, year2022::day14),
+    solver!(2022, 15, year2022::day15),
+    solver!(2022, 16, year2022::day16),
+    solver!(2022, 17, year2022::day17),
+    solver!(2022, 18, year2022::day18),
+    solver!(2022, 19, year2022::day19),
+    solver!(2022, 20, year2022::day20),
+    solver!(2022, 21, year2022::day21),
+    solver!(2022, 22, year2022::day22),
+    solver!(2022, 23, year2022::day23),
+    solver!(2022, 24, year2022::day24),
+    solver!(2022, 25, year2022::day25),
+];
+
+fn find_solver(year: u16, day: u8, part: u8) -> Result<Solver, String> {
+    if !matches!(day, 1..=25) {
+        return Err(format!("Invalid day {day} - must be 1-25"));
+    } else if !matches!(part, 1 | 2) {
+        return Err(format!("Invalid part {part} - must be 1-2"));
+    }
+    SOLVERS
+        .binary_search_by_key(&(year, day), |&(year, day, _)| (year, day))
+        .map(|index| SOLVERS[index].2)
+        .map_err(|_| format!("Unsupported year={year}, day={day}"))
+}
+
 /// Returns the solution for the specified given problem and input.
 ///
 /// # Arguments
@@ -63,6 +296,135 @@ fn to_stringer_input<T: ToString>(
 /// assert_eq!(solution, Ok("2".to_string()));
 /// ```
 pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
+    #![allow(clippy::let_and_return)]
+    let result = solve_internal(
+        year,
+        day,
+        part,
+        input,
+        #[cfg(feature = "visualization")]
+        None,
+        #[cfg(feature = "painter")]
+        None,
+    );
+
+    #[cfg(feature = "visualization")]
+    return result.map(|solution| solution.answer);
+
+    #[cfg(not(feature = "visualization"))]
+    result
+}
+
+/// A version of [solve](fn.solve.html) which reports intermediate frames while solving.
+///
+/// Solutions with visualization support call `frame_listener` with an SVG document
+/// for each step of the solution, allowing the solving process to be streamed. Solving
+/// stops with an error if `frame_listener` returns `ControlFlow::Break`. The returned
+/// value is the final rendered SVG, which is empty for solutions without visualization
+/// support.
+///
+/// Solutions without visualization support never call `frame_listener`.
+#[cfg(feature = "visualization")]
+pub fn solve_with_frame_listener(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    frame_listener: &dyn Fn(String) -> std::ops::ControlFlow<()>,
+) -> Result<String, String> {
+    solve_internal(
+        year,
+        day,
+        part,
+        input,
+        Some(FrameListener::Svg(frame_listener)),
+        None,
+    )
+    .map(|solution| solution.svg)
+}
+
+/// A version of [solve](fn.solve.html) which draws the solving process on a painter.
+///
+/// Solutions with painter support draw frames using the [Painter](painter/trait.Painter.html)
+/// drawing commands, which allows rendering them with other backends than SVG.
+/// The returned value is the computed answer.
+///
+/// Solutions without painter support never draw on `painter`.
+#[cfg(feature = "painter")]
+pub fn solve_with_painter(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    painter: &mut dyn painter::Painter,
+) -> Result<String, String> {
+    #![allow(clippy::let_and_return)]
+    let result = solve_internal(
+        year,
+        day,
+        part,
+        input,
+        #[cfg(feature = "visualization")]
+        None,
+        Some(painter),
+    );
+
+    #[cfg(feature = "visualization")]
+    return result.map(|solution| solution.answer);
+
+    #[cfg(not(feature = "visualization"))]
+    result
+}
+
+/// The answer to a problem together with the SVG visualizing how it was solved.
+#[cfg(feature = "visualization")]
+pub struct VisualizedSolution {
+    /// The computed answer as text.
+    pub answer: String,
+    /// The rendered SVG, which is empty for solutions without visualization support.
+    pub svg: String,
+}
+
+/// Receives the intermediate frames of a solution with visualization support.
+///
+/// Returning `ControlFlow::Break` from a listener stops the solving with an error.
+#[cfg(feature = "visualization")]
+#[derive(Copy, Clone)]
+pub enum FrameListener<'a> {
+    /// Called with each frame rendered as an SVG document.
+    Svg(&'a dyn Fn(String) -> std::ops::ControlFlow<()>),
+    /// Called once for each frame, which is not rendered, to follow the progress of a solve.
+    Progress(&'a dyn Fn() -> std::ops::ControlFlow<()>),
+}
+
+/// A version of [solve](fn.solve.html) which returns both the answer and the rendered SVG.
+///
+/// If `frame_listener` is specified it is called for each intermediate frame, as in
+/// [solve_with_frame_listener](fn.solve_with_frame_listener.html).
+#[cfg(feature = "visualization")]
+pub fn solve_visualized(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    frame_listener: Option<FrameListener>,
+) -> Result<VisualizedSolution, String> {
+    solve_internal(year, day, part, input, frame_listener, None)
+}
+
+#[cfg(feature = "visualization")]
+type SolveOutput = VisualizedSolution;
+#[cfg(not(feature = "visualization"))]
+type SolveOutput = String;
+
+fn solve_internal(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    #[cfg(feature = "visualization")] frame_listener: Option<FrameListener>,
+    #[cfg(feature = "painter")] painter: Option<&mut dyn painter::Painter>,
+) -> Result<SolveOutput, String> {
     #![allow(clippy::let_and_return)]
     use crate::input::{Input, Part};
     let input = input.trim_end();
@@ -73,233 +435,62 @@ pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, String
         return Err("Too long input".to_string());
     } else if !input.is_ascii() {
         return Err("Non-ASCII input".to_string());
-    } else if !matches!(day, 1..=25) {
-        return Err(format!("Invalid day {day} - must be 1-25"));
-    } else if !matches!(part, 1 | 2) {
-        return Err(format!("Invalid part {part} - must be 1-2"));
     }
+    let solver = find_solver(year, day, part)?;
 
     #[cfg(feature = "visualization")]
     let rendered_svg = std::cell::RefCell::new(String::new());
+    #[cfg(feature = "painter")]
+    let painter =
+        painter.map(|painter| std::cell::RefCell::new(painter as &mut dyn painter::Painter));
 
     let input = Input {
         part: if part == 1 { Part::One } else { Part::Two },
         text: input,
         #[cfg(feature = "visualization")]
         rendered_svg,
+        #[cfg(feature = "visualization")]
+        frame_listener,
+        #[cfg(feature = "painter")]
+        painter: painter.as_ref(),
     };
 
-    let result = match (year, day) {
-        (2015, 1) => to_stringer_input(year2015::day01::solve, &input),
-        (2015, 2) => to_stringer_input(year2015::day02::solve, &input),
-        (2015, 3) => to_stringer_input(year2015::day03::solve, &input),
-        (2015, 4) => to_stringer_input(year2015::day04::solve, &input),
-        (2015, 5) => to_stringer_input(year2015::day05::solve, &input),
-        (2015, 6) => to_stringer_input(year2015::day06::solve, &input),
-        (2015, 7) => to_stringer_input(year2015::day07::solve, &input),
-        (2015, 8) => to_stringer_input(year2015::day08::solve, &input),
-        (2015, 9) => to_stringer_input(year2015::day09::solve, &input),
-        (2015, 10) => to_stringer_input(year2015::day10::solve, &input),
-        (2015, 11) => to_stringer_input(year2015::day11::solve, &input),
-        (2015, 12) => to_stringer_input(year2015::day12::solve, &input),
-        (2015, 13) => to_stringer_input(year2015::day13::solve, &input),
-        (2015, 14) => to_stringer_input(year2015::day14::solve, &input),
-        (2015, 15) => to_stringer_input(year2015::day15::solve, &input),
-        (2015, 16) => to_stringer_input(year2015::day16::solve, &input),
-        (2015, 17) => to_stringer_input(year2015::day17::solve, &input),
-        (2015, 18) => to_stringer_input(year2015::day18::solve, &input),
-        (2015, 19) => to_stringer_input(year2015::day19::solve, &input),
-        (2015, 20) => to_stringer_input(year2015::day20::solve, &input),
-        (2015, 21) => to_stringer_input(year2015::day21::solve, &input),
-        (2015, 22) => to_stringer_input(year2015::day22::solve, &input),
-        (2015, 23) => to_stringer_input(year2015::day23::solve, &input),
-        (2015, 24) => to_stringer_input(year2015::day24::solve, &input),
-        (2015, 25) => to_stringer_input(year2015::day25::solve, &input),
-        (2016, 1) => to_stringer_input(year2016::day01::solve, &input),
-        (2016, 2) => to_stringer_input(year2016::day02::solve, &input),
-        (2016, 3) => to_stringer_input(year2016::day03::solve, &input),
-        (2016, 4) => to_stringer_input(year2016::day04::solve, &input),
-        (2016, 5) => to_stringer_input(year2016::day05::solve, &input),
-        (2016, 6) => to_stringer_input(year2016::day06::solve, &input),
-        (2016, 7) => to_stringer_input(year2016::day07::solve, &input),
-        (2016, 8) => to_stringer_input(year2016::day08::solve, &input),
-        (2016, 9) => to_stringer_input(year2016::day09::solve, &input),
-        (2016, 10) => to_stringer_input(year2016::day10::solve, &input),
-        (2016, 11) => to_stringer_input(year2016::day11::solve, &input),
-        (2016, 12) => to_stringer_input(year2016::day12::solve, &input),
-        (2016, 13) => to_stringer_input(year2016::day13::solve, &input),
-        (2016, 14) => to_stringer_input(year2016::day14::solve, &input),
-        (2016, 15) => to_stringer_input(year2016::day15::solve, &input),
-        (2016, 16) => to_stringer_input(year2016::day16::solve, &input),
-        (2016, 17) => to_stringer_input(year2016::day17::solve, &input),
-        (2016, 18) => to_stringer_input(year2016::day18::solve, &input),
-        (2016, 19) => to_stringer_input(year2016::day19::solve, &input),
-        (2016, 20) => to_stringer_input(year2016::day20::solve, &input),
-        (2016, 21) => to_stringer_input(year2016::day21::solve, &input),
-        (2016, 22) => to_stringer_input(year2016::day22::solve, &input),
-        (2016, 23) => to_stringer_input(year2016::day23::solve, &input),
-        (2016, 24) => to_stringer_input(year2016::day24::solve, &input),
-        (2016, 25) => to_stringer_input(year2016::day25::solve, &input),
-        (2017, 1) => to_stringer_input(year2017::day01::solve, &input),
-        (2017, 2) => to_stringer_input(year2017::day02::solve, &input),
-        (2017, 3) => to_stringer_input(year2017::day03::solve, &input),
-        (2017, 4) => to_stringer_input(year2017::day04::solve, &input),
-        (2017, 5) => to_stringer_input(year2017::day05::solve, &input),
-        (2017, 6) => to_stringer_input(year2017::day06::solve, &input),
-        (2017, 7) => to_stringer_input(year2017::day07::solve, &input),
-        (2017, 8) => to_stringer_input(year2017::day08::solve, &input),
-        (2017, 9) => to_stringer_input(year2017::day09::solve, &input),
-        (2017, 10) => to_stringer_input(year2017::day10::solve, &input),
-        (2017, 11) => to_stringer_input(year2017::day11::solve, &input),
-        (2017, 12) => to_stringer_input(year2017::day12::solve, &input),
-        (2017, 13) => to_stringer_input(year2017::day13::solve, &input),
-        (2017, 14) => to_stringer_input(year2017::day14::solve, &input),
-        (2017, 15) => to_stringer_input(year2017::day15::solve, &input),
-        (2017, 16) => to_stringer_input(year2017::day16::solve, &input),
-        (2017, 17) => to_stringer_input(year2017::day17::solve, &input),
-        (2017, 18) => to_stringer_input(year2017::day18::solve, &input),
-        (2017, 19) => to_stringer_input(year2017::day19::solve, &input),
-        (2017, 20) => to_stringer_input(year2017::day20::solve, &input),
-        (2017, 21) => to_stringer_input(year2017::day21::solve, &input),
-        (2017, 22) => to_stringer_input(year2017::day22::solve, &input),
-        (2017, 23) => to_stringer_input(year2017::day23::solve, &input),
-        (2017, 24) => to_stringer_input(year2017::day24::solve, &input),
-        (2017, 25) => to_stringer_input(year2017::day25::solve, &input),
-        (2018, 1) => to_stringer_input(year2018::day01::solve, &input),
-        (2018, 2) => to_stringer_input(year2018::day02::solve, &input),
-        (2018, 3) => to_stringer_input(year2018::day03::solve, &input),
-        (2018, 4) => to_stringer_input(year2018::day04::solve, &input),
-        (2018, 5) => to_stringer_input(year2018::day05::solve, &input),
-        (2018, 6) => to_stringer_input(year2018::day06::solve, &input),
-        (2018, 7) => to_stringer_input(year2018::day07::solve, &input),
-        (2018, 8) => to_stringer_input(year2018::day08::solve, &input),
-        (2018, 9) => to_stringer_input(year2018::day09::solve, &input),
-        (2018, 10) => to_stringer_input(year2018::day10::solve, &input),
-        (2018, 11) => to_stringer_input(year2018::day11::solve, &input),
-        (2018, 12) => to_stringer_input(year2018::day12::solve, &input),
-        (2018, 13) => to_stringer_input(year2018::day13::solve, &input),
-        (2018, 14) => to_stringer_input(year2018::day14::solve, &input),
-        (2018, 15) => to_stringer_input(year2018::day15::solve, &input),
-        (2018, 16) => to_stringer_input(year2018::day16::solve, &input),
-        (2018, 17) => to_stringer_input(year2018::day17::solve, &input),
-        (2018, 18) => to_stringer_input(year2018::day18::solve, &input),
-        (2018, 19) => to_stringer_input(year2018::day19::solve, &input),
-        (2018, 20) => to_stringer_input(year2018::day20::solve, &input),
-        (2018, 21) => to_stringer_input(year2018::day21::solve, &input),
-        (2018, 22) => to_stringer_input(year2018::day22::solve, &input),
-        (2018, 23) => to_stringer_input(year2018::day23::solve, &input),
-        (2018, 24) => to_stringer_input(year2018::day24::solve, &input),
-        (2018, 25) => to_stringer_input(year2018::day25::solve, &input),
-        (2019, 1) => to_stringer_input(year2019::day01::solve, &input),
-        (2019, 2) => to_stringer_input(year2019::day02::solve, &input),
-        (2019, 3) => to_stringer_input(year2019::day03::solve, &input),
-        (2019, 4) => to_stringer_input(year2019::day04::solve, &input),
-        (2019, 5) => to_stringer_input(year2019::day05::solve, &input),
-        (2019, 6) => to_stringer_input(year2019::day06::solve, &input),
-        (2019, 7) => to_stringer_input(year2019::day07::solve, &input),
-        (2019, 8) => to_stringer_input(year2019::day08::solve, &input),
-        (2019, 9) => to_stringer_input(year2019::day09::solve, &input),
-        (2019, 10) => to_stringer_input(year2019::day10::solve, &input),
-        (2019, 11) => to_stringer_input(year2019::day11::solve, &input),
-        (2019, 12) => to_stringer_input(year2019::day12::solve, &input),
-        (2019, 13) => to_stringer_input(year2019::day13::solve, &input),
-        (2019, 14) => to_stringer_input(year2019::day14::solve, &input),
-        (2019, 15) => to_stringer_input(year2019::day15::solve, &input),
-        (2019, 16) => to_stringer_input(year2019::day16::solve, &input),
-        (2019, 17) => to_stringer_input(year2019::day17::solve, &input),
-        (2019, 18) => to_stringer_input(year2019::day18::solve, &input),
-        (2019, 19) => to_stringer_input(year2019::day19::solve, &input),
-        (2019, 20) => to_stringer_input(year2019::day20::solve, &input),
-        (2019, 21) => to_stringer_input(year2019::day21::solve, &input),
-        (2019, 22) => to_stringer_input(year2019::day22::solve, &input),
-        (2019, 23) => to_stringer_input(year2019::day23::solve, &input),
-        (2019, 24) => to_stringer_input(year2019::day24::solve, &input),
-        (2019, 25) => to_stringer_input(year2019::day25::solve, &input),
-        (2020, 1) => to_stringer_input(year2020::day01::solve, &input),
-        (2020, 2) => to_stringer_input(year2020::day02::solve, &input),
-        (2020, 3) => to_stringer_input(year2020::day03::solve, &input),
-        (2020, 4) => to_stringer_input(year2020::day04::solve, &input),
-        (2020, 5) => to_stringer_input(year2020::day05::solve, &input),
-        (2020, 6) => to_stringer_input(year2020::day06::solve, &input),
-        (2020, 7) => to_stringer_input(year2020::day07::solve, &input),
-        (2020, 8) => to_stringer_input(year2020::day08::solve, &input),
-        (2020, 9) => to_stringer_input(year2020::day09::solve, &input),
-        (2020, 10) => to_stringer_input(year2020::day10::solve, &input),
-        (2020, 11) => to_stringer_input(year2020::day11::solve, &input),
-        (2020, 12) => to_stringer_input(year2020::day12::solve, &input),
-        (2020, 13) => to_stringer_input(year2020::day13::solve, &input),
-        (2020, 14) => to_stringer_input(year2020::day14::solve, &input),
-        (2020, 15) => to_stringer_input(year2020::day15::solve, &input),
-        (2020, 16) => to_stringer_input(year2020::day16::solve, &input),
-        (2020, 17) => to_stringer_input(year2020::day17::solve, &input),
-        (2020, 18) => to_stringer_input(year2020::day18::solve, &input),
-        (2020, 19) => to_stringer_input(year2020::day19::solve, &input),
-        (2020, 20) => to_stringer_input(year2020::day20::solve, &input),
-        (2020, 21) => to_stringer_input(year2020::day21::solve, &input),
-        (2020, 22) => to_stringer_input(year2020::day22::solve, &input),
-        (2020, 23) => to_stringer_input(year2020::day23::solve, &input),
-        (2020, 24) => to_stringer_input(year2020::day24::solve, &input),
-        (2020, 25) => to_stringer_input(year2020::day25::solve, &input),
-        (2021, 1) => to_stringer_input(year2021::day01::solve, &input),
-        (2021, 2) => to_stringer_input(year2021::day02::solve, &input),
-        (2021, 3) => to_stringer_input(year2021::day03::solve, &input),
-        (2021, 4) => to_stringer_input(year2021::day04::solve, &input),
-        (2021, 5) => to_stringer_input(year2021::day05::solve, &input),
-        (2021, 6) => to_stringer_input(year2021::day06::solve, &input),
-        (2021, 7) => to_stringer_input(year2021::day07::solve, &input),
-        (2021, 8) => to_stringer_input(year2021::day08::solve, &input),
-        (2021, 9) => to_stringer_input(year2021::day09::solve, &input),
-        (2021, 10) => to_stringer_input(year2021::day10::solve, &input),
-        (2021, 11) => to_stringer_input(year2021::day11::solve, &input),
-        (2021, 12) => to_stringer_input(year2021::day12::solve, &input),
-        (2021, 13) => to_stringer_input(year2021::day13::solve, &input),
-        (2021, 14) => to_stringer_input(year2021::day14::solve, &input),
-        (2021, 15) => to_stringer_input(year2021::day15::solve, &input),
-        (2021, 16) => to_stringer_input(year2021::day16::solve, &input),
-        (2021, 17) => to_stringer_input(year2021::day17::solve, &input),
-        (2021, 18) => to_stringer_input(year2021::day18::solve, &input),
-        (2021, 19) => to_stringer_input(year2021::day19::solve, &input),
-        (2021, 20) => to_stringer_input(year2021::day20::solve, &input),
-        (2021, 21) => to_stringer_input(year2021::day21::solve, &input),
-        (2021, 22) => to_stringer_input(year2021::day22::solve, &input),
-        (2021, 23) => to_stringer_input(year2021::day23::solve, &input),
-        (2021, 24) => to_stringer_input(year2021::day24::solve, &input),
-        (2021, 25) => to_stringer_input(year2021::day25::solve, &input),
-        (2022, 1) => to_stringer_input(year2022::day01::solve, &input),
-        (2022, 2) => to_stringer_input(year2022::day02::solve, &input),
-        (2022, 3) => to_stringer_input(year2022::day03::solve, &input),
-        (2022, 4) => to_stringer_input(year2022::day04::solve, &input),
-        (2022, 5) => to_stringer_input(year2022::day05::solve, &input),
-        (2022, 6) => to_stringer_input(year2022::day06::solve, &input),
-        (2022, 7) => to_stringer_input(year2022::day07::solve, &input),
-        (2022, 8) => to_stringer_input(year2022::day08::solve, &input),
-        (2022, 9) => to_stringer_input(year2022::day09::solve, &input),
-        (2022, 10) => to_stringer_input(year2022::day10::solve, &input),
-        (2022, 11) => to_stringer_input(year2022::day11::solve, &input),
-        (2022, 12) => to_stringer_input(year2022::day12::solve, &input),
-        (2022, 13) => to_stringer_input(year2022::day13::solve, &input),
-        (2022, 14) => to_stringer_input(year2022::day14::solve, &input),
-        (2022, 15) => to_stringer_input(year2022::day15::solve, &input),
-        (2022, 16) => to_stringer_input(year2022::day16::solve, &input),
-        (2022, 17) => to_stringer_input(year2022::day17::solve, &input),
-        (2022, 18) => to_stringer_input(year2022::day18::solve, &input),
-        (2022, 19) => to_stringer_input(year2022::day19::solve, &input),
-        (2022, 20) => to_stringer_input(year2022::day20::solve, &input),
-        (2022, 21) => to_stringer_input(year2022::day21::solve, &input),
-        (2022, 22) => to_stringer_input(year2022::day22::solve, &input),
-        (2022, 23) => to_stringer_input(year2022::day23::solve, &input),
-        (2022, 24) => to_stringer_input(year2022::day24::solve, &input),
-        (2022, 25) => to_stringer_input(year2022::day25::solve, &input),
-        _ => Err(format!("Unsupported year={year}, day={day}, part={part}")),
-    };
+    let result = solver(&input);
 
     #[cfg(feature = "visualization")]
-    return result.map(|_| input.rendered_svg.take());
+    return result.map(|answer| VisualizedSolution {
+        answer,
+        svg: input.rendered_svg.take(),
+    });
 
     #[cfg(not(feature = "visualization"))]
     result
 }
 
+/// Returns the `(year, day)` of all problems which can be solved.
+///
+/// # Example
+/// ```
+/// use advent_of_code::supported_puzzles;
+/// assert!(supported_puzzles().any(|puzzle| puzzle == (2019, 1)));
+/// ```
+pub fn supported_puzzles() -> impl Iterator<Item = (u16, u8)> {
+    SOLVERS.iter().map(|&(year, day, _)| (year, day))
+}
+
+/// Checks that the year, day and part specifies a supported problem.
+///
+/// # Example
+/// ```
+/// use advent_of_code::check_puzzle;
+/// assert_eq!(check_puzzle(2019, 1, 2), Ok(()));
+/// assert_eq!(check_puzzle(2019, 1, 3), Err("Invalid part 3 - must be 1-2".to_string()));
+/// assert_eq!(check_puzzle(2014, 1, 1), Err("Unsupported year=2014, day=1".to_string()));
+/// ```
+pub fn check_puzzle(year: u16, day: u8, part: u8) -> Result<(), String> {
+    find_solver(year, day, part).map(|_| ())
+}
+
 /// A version of [solve](fn.solve.html) that takes strings as arguments and parses them to the required types.
 pub fn solve_raw(year: &str, day: &str, part: &str, input: &str) -> Result<String, String> {
     let year = year.parse::<u16>().map_err(|_| "Invalid year")?;
@@ -307,3 +498,23 @@ pub fn solve_raw(year: &str, day: &str, part: &str, input: &str) -> Result<Strin
     let part = part.parse::<u8>().map_err(|_| "Invalid part")?;
     solve(year, day, part, input)
 }
+
+#[test]
+fn test_solvers_sorted() {
+    // Solvers are looked up with a binary search:
+    assert!(SOLVERS
+        .windows(2)
+        .all(|pair| (pair[0].0, pair[0].1) < (pair[1].0, pair[1].1)));
+    assert_eq!(supported_puzzles().count(), 8 * 25);
+}
+
+#[cfg(feature = "visualization")]
+#[test]
+fn test_solve_visualized() {
+    #![allow(clippy::unwrap_used)]
+    let input = include_str!("year2022/day12_input.txt");
+    assert_eq!(solve(2022, 12, 1, input), Ok("528".to_string()));
+    let solution = solve_visualized(2022, 12, 1, input, None).unwrap();
+    assert_eq!(solution.answer, "528");
+    assert!(solution.svg.starts_with("<svg"));
+}
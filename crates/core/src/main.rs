@@ -3,15 +3,94 @@
 use std::env;
 use std::io::Read;
 
-use advent_of_code::solve_raw;
+mod cli;
+
+fn parse_puzzle(year: &str, day: &str, part: &str) -> Result<(u16, u8, u8), String> {
+    Ok((
+        year.parse::<u16>().map_err(|_| "Invalid year")?,
+        day.parse::<u8>().map_err(|_| "Invalid day")?,
+        part.parse::<u8>().map_err(|_| "Invalid part")?,
+    ))
+}
+
+/// Solve a problem, outputting the rendered SVG instead of the answer when built with
+/// the visualization feature.
+fn solve_for_output(year: &str, day: &str, part: &str, input: &str) -> Result<String, String> {
+    let (year, day, part) = parse_puzzle(year, day, part)?;
+
+    #[cfg(feature = "visualization")]
+    return advent_of_code::solve_visualized(year, day, part, input, None)
+        .map(|solution| solution.svg);
+
+    #[cfg(not(feature = "visualization"))]
+    advent_of_code::solve(year, day, part, input)
+}
+
+/// Animate the solving in the terminal, showing each frame of the visualization.
+#[cfg(feature = "raster")]
+fn animate(year: &str, day: &str, part: &str, input: &str) -> Result<(), String> {
+    use advent_of_code::painter::terminal::TerminalPainter;
+
+    let (year, day, part) = parse_puzzle(year, day, part)?;
+
+    let parse_env = |name: &str, default: u16| -> Result<u16, String> {
+        env::var(name).map_or(Ok(default), |value| {
+            value
+                .parse::<u16>()
+                .map_err(|_| format!("Unable to parse {name}"))
+        })
+    };
+    let frame_delay =
+        std::time::Duration::from_millis(u64::from(parse_env("AOC_FRAME_DELAY", 50)?));
+
+    let mut painter = TerminalPainter::new(
+        std::io::stdout().lock(),
+        parse_env("COLUMNS", 80)?,
+        parse_env("LINES", 24)?,
+        frame_delay,
+    );
+    let solution = advent_of_code::solve_with_painter(year, day, part, input, &mut painter)
+        .unwrap_or_else(|error| format!("Error: {error}"));
+    let stdout = painter
+        .finish()
+        .map_err(|error| format!("Error writing to terminal: {error}"))?;
+    drop(stdout);
+    println!("{solution}");
+    Ok(())
+}
+
+#[cfg(not(feature = "raster"))]
+fn animate(_year: &str, _day: &str, _part: &str, _input: &str) -> Result<(), String> {
+    Err("Built without the raster feature needed by --animate".to_string())
+}
 
 fn main() -> Result<(), String> {
     let usage = || -> ! {
-        eprintln!("usage: advent-of-code [year] [day] [part] < [input-file]");
+        eprintln!(
+            "usage: advent-of-code [--animate] [year] [day] [part] < [input-file]\n       {}\n       {}\n       {}\n       {}\n       {}\n       {}",
+            cli::solve::USAGE,
+            cli::run::USAGE,
+            cli::verify::USAGE,
+            cli::bench::USAGE,
+            cli::render::USAGE,
+            cli::add_fuzz_case::USAGE
+        );
         std::process::exit(1);
     };
 
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("run") => return cli::run::run(&args[2..]),
+        Some("verify") => return cli::verify::run(&args[2..]),
+        Some("bench") => return cli::bench::run(&args[2..]),
+        Some("solve") => return cli::solve::run(&args[2..]),
+        Some("render") => return cli::render::run(&args[2..]),
+        Some("add-fuzz-case") => return cli::add_fuzz_case::run(&args[2..]),
+        _ => {}
+    }
+
+    let animate_solution = args.iter().any(|s| s == "--animate");
+    args.retain(|s| s != "--animate");
 
     if args.iter().any(|s| s == "-v" || s == "--version") {
         println!(env!("CARGO_PKG_VERSION"));
@@ -35,8 +114,12 @@ fn main() -> Result<(), String> {
             .read_to_string(&mut input)
             .map_err(|error| format!("Error reading input: {error}"))?;
 
+        if animate_solution {
+            return animate(year, day, part, &input);
+        }
+
         for _ in 0..repeat {
-            let solution = solve_raw(year, day, part, input.as_ref())
+            let solution = solve_for_output(year, day, part, &input)
                 .unwrap_or_else(|error| format!("Error: {error}"));
             if repeat == 1 {
                 println!("{solution}");
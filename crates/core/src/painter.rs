@@ -0,0 +1,469 @@
+//! A frame based drawing abstraction for visualizing solutions.
+//!
+//! Coordinates are normalized so that the drawing area spans from 0 to 1 in both
+//! dimensions, with its actual shape determined by [Painter::set_aspect_ratio].
+//! Font sizes are relative to the height of the drawing area.
+//!
+//! Drawn content is kept between frames until [Painter::clear] is called, like
+//! on a HTML canvas.
+use svgplot::{SvgDominantBaseline, SvgImage, SvgPath, SvgRect, SvgShape, SvgText, SvgTextAnchor};
+
+#[cfg(feature = "raster")]
+pub mod raster;
+#[cfg(feature = "raster")]
+pub mod terminal;
+
+/// A drawing surface receiving commands from a visualized solution.
+pub trait Painter {
+    /// Clear everything drawn so far.
+    fn clear(&mut self);
+    /// Start a new path, discarding the current one.
+    fn begin_path(&mut self);
+    /// Add a straight line from the current point to the start of the current sub-path.
+    fn close_path(&mut self);
+    /// Fill a rectangle with the current fill style.
+    fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64);
+    /// Fill a square with the current fill style.
+    fn fill_square(&mut self, x: f64, y: f64, size: f64);
+    /// Set a status text shown in a fixed position above the drawing.
+    fn status_text(&mut self, text: &str);
+    /// Fill the current path with the current fill style.
+    fn fill(&mut self);
+    fn stroke_square(&mut self, x: i32, y: i32, size: i32);
+    fn stroke_style_rgb(&mut self, r: i32, g: i32, b: i32);
+    /// Stroke the current path with the current stroke style and line width.
+    fn stroke(&mut self);
+    fn line_width(&mut self, width: f64);
+    fn line_to(&mut self, x: f64, y: f64);
+    fn move_to(&mut self, x: f64, y: f64);
+    fn fill_style_rgb(&mut self, r: i32, g: i32, b: i32);
+    fn fill_style_rgba(&mut self, r: i32, g: i32, b: i32, a: f64);
+    fn shadow_color(&mut self, r: i32, g: i32, b: i32);
+    fn shadow_blur(&mut self, level: i32);
+    /// Add a clockwise circular arc, with angles in radians, to the current path.
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64);
+    /// Mark the end of a frame, making what has been drawn so far visible.
+    fn end_frame(&mut self);
+    /// Delay before showing the next frame.
+    fn meta_delay(&mut self, delay_ms: u16);
+    /// Switch the layer drawn on, where layer 0 is the bottom layer and 1 is an overlay.
+    fn meta_switch_layer(&mut self, to_layer: u16);
+    fn set_aspect_ratio(&mut self, width: i32, height: i32);
+    fn aspect_ratio(&self) -> f64;
+    /// Signal that the visualization is done and wait for it to be consumed.
+    fn await_forever(&mut self);
+    fn play_sound(&mut self, sound_id: i32);
+    fn draw_text_centered(&mut self, x: f64, y: f64, font_size: f64, text: &str);
+    fn draw_text_top_left(&mut self, x: f64, y: f64, font_size: f64, text: &str);
+    /// Set the CSS color used for drawn text.
+    fn fill_text_style(&mut self, style: &str);
+    fn log(&mut self, text: &str);
+}
+
+pub type PainterRef = Box<dyn Painter>;
+
+const LAYER_COUNT: usize = 2;
+
+/// The height of the view box of rendered SVG documents, which normalized coordinates are scaled to.
+const SVG_HEIGHT: f64 = 1000.;
+
+#[derive(Copy, Clone)]
+enum Paint {
+    Rgb(u8, u8, u8),
+    Rgba(u8, u8, u8, f64),
+}
+
+impl Paint {
+    fn rgb(r: i32, g: i32, b: i32) -> Self {
+        Self::Rgb(color_component(r), color_component(g), color_component(b))
+    }
+
+    fn to_css(self) -> String {
+        match self {
+            Self::Rgb(r, g, b) => format!("rgb({r}, {g}, {b})"),
+            Self::Rgba(r, g, b, a) => format!("rgba({r}, {g}, {b}, {a})"),
+        }
+    }
+}
+
+fn color_component(value: i32) -> u8 {
+    value.clamp(0, 255) as u8
+}
+
+enum Shape {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill: Paint,
+    },
+    FilledPath {
+        shape: SvgShape,
+        fill: Paint,
+    },
+    StrokedPath {
+        shape: SvgShape,
+        stroke: Paint,
+        width: f64,
+    },
+    Text {
+        x: f64,
+        y: f64,
+        font_size: f64,
+        text: String,
+        centered: bool,
+        fill: String,
+    },
+}
+
+/// A [Painter] rendering each frame to an SVG document.
+///
+/// Shadows, sounds and delays have no effect on the output.
+pub struct SvgPainter {
+    layers: [Vec<Shape>; LAYER_COUNT],
+    current_layer: usize,
+    path: SvgShape,
+    fill: Paint,
+    stroke: Paint,
+    line_width: f64,
+    text_fill: String,
+    aspect_ratio: f64,
+    frames: Vec<String>,
+}
+
+impl SvgPainter {
+    pub fn new() -> Self {
+        Self {
+            layers: Default::default(),
+            current_layer: 0,
+            path: SvgShape::new(),
+            fill: Paint::Rgb(0, 0, 0),
+            stroke: Paint::Rgb(0, 0, 0),
+            line_width: 1.,
+            text_fill: "black".to_string(),
+            aspect_ratio: 1.,
+            frames: Vec::new(),
+        }
+    }
+
+    /// The frames rendered so far, one SVG document for each call to [Painter::end_frame].
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<String> {
+        self.frames
+    }
+
+    /// Render what has been drawn so far to an SVG document.
+    pub fn render(&self) -> String {
+        let mut image =
+            SvgImage::new().view_box((0, 0, self.svg_width() as i64, SVG_HEIGHT as i64));
+        for shape in self.layers.iter().flatten() {
+            Self::add_shape(&mut image, shape);
+        }
+        image.to_svg_string()
+    }
+
+    fn svg_width(&self) -> f64 {
+        (SVG_HEIGHT * self.aspect_ratio).round()
+    }
+
+    fn add_shape(image: &mut SvgImage, shape: &Shape) {
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+            } => {
+                image.add(
+                    SvgRect::default()
+                        .x(*x)
+                        .y(*y)
+                        .width(*width)
+                        .height(*height)
+                        .style(format!("fill: {}", fill.to_css())),
+                );
+            }
+            Shape::FilledPath { shape, fill } => {
+                image.add(
+                    SvgPath::default()
+                        .shape(shape.clone())
+                        .style(format!("fill: {}", fill.to_css())),
+                );
+            }
+            Shape::StrokedPath {
+                shape,
+                stroke,
+                width,
+            } => {
+                image.add(SvgPath::default().shape(shape.clone()).style(format!(
+                    "fill: none; stroke: {}; stroke-width: {width}",
+                    stroke.to_css()
+                )));
+            }
+            Shape::Text {
+                x,
+                y,
+                font_size,
+                text,
+                centered,
+                fill,
+            } => {
+                let text = SvgText::new(text)
+                    .x(*x)
+                    .y(*y)
+                    .font_size(*font_size)
+                    .style(format!("fill: {fill}"));
+                image.add(if *centered {
+                    text.text_anchor(SvgTextAnchor::Middle)
+                        .dominant_baseline(SvgDominantBaseline::Middle)
+                } else {
+                    text.dominant_baseline(SvgDominantBaseline::Hanging)
+                });
+            }
+        }
+    }
+
+    fn push(&mut self, shape: Shape) {
+        self.layers[self.current_layer].push(shape);
+    }
+
+    fn push_text(&mut self, x: f64, y: f64, font_size: f64, text: &str, centered: bool) {
+        let (x, y) = self.path_point(x, y);
+        self.push(Shape::Text {
+            x,
+            y,
+            font_size: font_size * SVG_HEIGHT,
+            text: text.to_string(),
+            centered,
+            fill: self.text_fill.clone(),
+        });
+    }
+
+    /// Scale a point from normalized coordinates to the view box of rendered SVG documents.
+    fn path_point(&self, x: f64, y: f64) -> (f64, f64) {
+        (x * self.svg_width(), y * SVG_HEIGHT)
+    }
+}
+
+impl Default for SvgPainter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Painter for SvgPainter {
+    fn clear(&mut self) {
+        for layer in &mut self.layers {
+            layer.clear();
+        }
+    }
+
+    fn begin_path(&mut self) {
+        self.path = SvgShape::new();
+    }
+
+    fn close_path(&mut self) {
+        self.path = std::mem::take(&mut self.path).close();
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64) {
+        let (x, y) = self.path_point(x, y);
+        let (width, height) = self.path_point(w, h);
+        self.push(Shape::Rect {
+            x,
+            y,
+            width,
+            height,
+            fill: self.fill,
+        });
+    }
+
+    fn fill_square(&mut self, x: f64, y: f64, size: f64) {
+        self.fill_rect(x, y, size, size);
+    }
+
+    fn status_text(&mut self, _text: &str) {}
+
+    fn fill(&mut self) {
+        self.push(Shape::FilledPath {
+            shape: self.path.clone(),
+            fill: self.fill,
+        });
+    }
+
+    fn stroke_square(&mut self, x: i32, y: i32, size: i32) {
+        let (x, y, size) = (f64::from(x), f64::from(y), f64::from(size));
+        let (left, top) = self.path_point(x, y);
+        let (right, bottom) = self.path_point(x + size, y + size);
+        self.push(Shape::StrokedPath {
+            shape: SvgShape::at(left, top)
+                .line_to_absolute(right, top)
+                .line_to_absolute(right, bottom)
+                .line_to_absolute(left, bottom)
+                .close(),
+            stroke: self.stroke,
+            width: self.line_width * SVG_HEIGHT,
+        });
+    }
+
+    fn stroke_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.stroke = Paint::rgb(r, g, b);
+    }
+
+    fn stroke(&mut self) {
+        self.push(Shape::StrokedPath {
+            shape: self.path.clone(),
+            stroke: self.stroke,
+            width: self.line_width * SVG_HEIGHT,
+        });
+    }
+
+    fn line_width(&mut self, width: f64) {
+        self.line_width = width;
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        let (x, y) = self.path_point(x, y);
+        self.path = std::mem::take(&mut self.path).line_to_absolute(x, y);
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        let (x, y) = self.path_point(x, y);
+        self.path = std::mem::take(&mut self.path).move_to_absolute(x, y);
+    }
+
+    fn fill_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.fill = Paint::rgb(r, g, b);
+    }
+
+    fn fill_style_rgba(&mut self, r: i32, g: i32, b: i32, a: f64) {
+        self.fill = Paint::Rgba(
+            color_component(r),
+            color_component(g),
+            color_component(b),
+            a.clamp(0., 1.),
+        );
+    }
+
+    fn shadow_color(&mut self, _r: i32, _g: i32, _b: i32) {}
+
+    fn shadow_blur(&mut self, _level: i32) {}
+
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        use std::f64::consts::{PI, TAU};
+        let sweep = end_angle - start_angle;
+        let (center_x, center_y) = self.path_point(x, y);
+        let (radius_x, radius_y) = self.path_point(radius, radius);
+        let point_at = |angle: f64| {
+            (
+                radius_x.mul_add(angle.cos(), center_x),
+                radius_y.mul_add(angle.sin(), center_y),
+            )
+        };
+
+        let (start_x, start_y) = point_at(start_angle);
+        let mut path = std::mem::take(&mut self.path);
+        path = if path.is_empty() {
+            path.move_to_absolute(start_x, start_y)
+        } else {
+            path.line_to_absolute(start_x, start_y)
+        };
+
+        // An SVG arc can not draw a full circle, so draw it as two halves.
+        let sweep = if sweep >= TAU {
+            TAU
+        } else {
+            sweep.rem_euclid(TAU)
+        };
+        let half_count = if sweep > PI { 2 } else { 1 };
+        let mut from = (start_x, start_y);
+        for half in 1..=half_count {
+            let to = point_at(start_angle + sweep * f64::from(half) / f64::from(half_count));
+            path =
+                path.arc_to_relative(radius_x, radius_y, 0., 0., 1., to.0 - from.0, to.1 - from.1);
+            from = to;
+        }
+        self.path = path;
+    }
+
+    fn end_frame(&mut self) {
+        self.frames.push(self.render());
+    }
+
+    fn meta_delay(&mut self, _delay_ms: u16) {}
+
+    fn meta_switch_layer(&mut self, to_layer: u16) {
+        self.current_layer = usize::from(to_layer).min(LAYER_COUNT - 1);
+    }
+
+    fn set_aspect_ratio(&mut self, width: i32, height: i32) {
+        if width > 0 && height > 0 {
+            self.aspect_ratio = f64::from(width) / f64::from(height);
+        }
+    }
+
+    fn aspect_ratio(&self) -> f64 {
+        self.aspect_ratio
+    }
+
+    fn await_forever(&mut self) {}
+
+    fn play_sound(&mut self, _sound_id: i32) {}
+
+    fn draw_text_centered(&mut self, x: f64, y: f64, font_size: f64, text: &str) {
+        self.push_text(x, y, font_size, text, true);
+    }
+
+    fn draw_text_top_left(&mut self, x: f64, y: f64, font_size: f64, text: &str) {
+        self.push_text(x, y, font_size, text, false);
+    }
+
+    fn fill_text_style(&mut self, style: &str) {
+        self.text_fill = style.to_string();
+    }
+
+    fn log(&mut self, _text: &str) {}
+}
+
+#[test]
+fn svg_painter() {
+    let mut painter = SvgPainter::new();
+    painter.set_aspect_ratio(2, 1);
+    painter.fill_style_rgb(255, 0, 0);
+    painter.fill_square(0.25, 0.5, 0.25);
+    painter.end_frame();
+
+    painter.fill_style_rgba(0, 0, 255, 0.5);
+    painter.begin_path();
+    painter.move_to(0., 0.);
+    painter.line_to(0.5, 1.);
+    painter.fill();
+    painter.end_frame();
+
+    painter.fill_text_style("white");
+    painter.draw_text_centered(0.5, 0.5, 0.1, "A & B");
+    painter.end_frame();
+
+    painter.clear();
+    painter.end_frame();
+
+    let frames = painter.into_frames();
+    assert_eq!(frames.len(), 4);
+    assert!(frames[0].contains("viewBox=\"0 0 2000 1000\""));
+    assert!(frames[0].contains(
+        "<rect x=\"500\" y=\"500\" width=\"500\" height=\"250\" style=\"fill: rgb(255, 0, 0)\"/>"
+    ));
+    assert!(frames[1].contains("<rect"));
+    assert!(frames[1].contains("d=\"M 0 0L 1000 1000\""));
+    assert!(frames[1].contains("fill: rgba(0, 0, 255, 0.5)"));
+    assert!(frames[2].contains(
+        "<text x=\"1000\" y=\"500\" font-size=\"100\" text-anchor=\"middle\" \
+         dominant-baseline=\"middle\" style=\"fill: white\">A &amp; B</text>"
+    ));
+    assert!(!frames[3].contains("<rect"));
+}
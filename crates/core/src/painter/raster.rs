@@ -0,0 +1,105 @@
+//! Rasterizing of visualizations to pixels, by painting on the [RasterPainter] of the
+//! `advent-of-code-raster` crate. Text and shadows are not rendered.
+pub use advent_of_code_raster::{Frame, RasterPainter};
+
+use super::Painter;
+
+impl Painter for RasterPainter {
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+
+    fn begin_path(&mut self) {
+        Self::begin_path(self);
+    }
+
+    fn close_path(&mut self) {
+        Self::close_path(self);
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64) {
+        Self::fill_rect(self, x, y, w, h);
+    }
+
+    fn fill_square(&mut self, x: f64, y: f64, size: f64) {
+        Self::fill_square(self, x, y, size);
+    }
+
+    fn status_text(&mut self, _text: &str) {}
+
+    fn fill(&mut self) {
+        Self::fill(self);
+    }
+
+    fn stroke_square(&mut self, x: i32, y: i32, size: i32) {
+        Self::stroke_square(self, x, y, size);
+    }
+
+    fn stroke_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        Self::stroke_style_rgb(self, r, g, b);
+    }
+
+    fn stroke(&mut self) {
+        Self::stroke(self);
+    }
+
+    fn line_width(&mut self, width: f64) {
+        Self::line_width(self, width);
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        Self::line_to(self, x, y);
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        Self::move_to(self, x, y);
+    }
+
+    fn fill_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        Self::fill_style_rgb(self, r, g, b);
+    }
+
+    fn fill_style_rgba(&mut self, r: i32, g: i32, b: i32, a: f64) {
+        Self::fill_style_rgba(self, r, g, b, a);
+    }
+
+    fn shadow_color(&mut self, _r: i32, _g: i32, _b: i32) {}
+
+    fn shadow_blur(&mut self, _level: i32) {}
+
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        Self::arc(self, x, y, radius, start_angle, end_angle);
+    }
+
+    fn end_frame(&mut self) {
+        Self::end_frame(self);
+    }
+
+    fn meta_delay(&mut self, delay_ms: u16) {
+        Self::meta_delay(self, delay_ms);
+    }
+
+    fn meta_switch_layer(&mut self, to_layer: u16) {
+        Self::meta_switch_layer(self, to_layer);
+    }
+
+    fn set_aspect_ratio(&mut self, width: i32, height: i32) {
+        Self::set_aspect_ratio(self, width, height);
+    }
+
+    fn aspect_ratio(&self) -> f64 {
+        Self::aspect_ratio(self)
+    }
+
+    fn await_forever(&mut self) {}
+
+    fn play_sound(&mut self, _sound_id: i32) {}
+
+    fn draw_text_centered(&mut self, _x: f64, _y: f64, _font_size: f64, _text: &str) {}
+
+    fn draw_text_top_left(&mut self, _x: f64, _y: f64, _font_size: f64, _text: &str) {}
+
+    fn fill_text_style(&mut self, _style: &str) {}
+
+    fn log(&mut self, _text: &str) {}
+}
@@ -0,0 +1,243 @@
+//! Animation of frames in a terminal, using ANSI escape sequences.
+//!
+//! Each character shows two vertically stacked pixels, by drawing an upper half block
+//! with the upper pixel as foreground color and the lower pixel as background color.
+use std::fmt::Write as _;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use super::raster::{Frame, RasterPainter};
+use super::Painter;
+
+/// A [Painter] animating frames in a terminal using 24-bit colors.
+///
+/// Frames are shown at most once per frame delay, sleeping if frames are produced
+/// faster than that.
+pub struct TerminalPainter<W: Write> {
+    canvas: RasterPainter,
+    output: W,
+    frame_delay: Duration,
+    /// Additional delay before the next frame, from [Painter::meta_delay].
+    extra_delay: Duration,
+    last_frame_at: Option<Instant>,
+    status_text: String,
+    /// The first error writing to the output, after which nothing more is written.
+    error: Option<std::io::Error>,
+}
+
+impl<W: Write> TerminalPainter<W> {
+    /// Create a painter drawing frames fitting within the specified number of
+    /// columns and rows of text, of which the last row is used for status text.
+    pub fn new(output: W, columns: u16, rows: u16, frame_delay: Duration) -> Self {
+        let pixel_rows = 2 * u32::from(rows.max(2) - 1);
+        Self {
+            canvas: RasterPainter::new(pixel_rows).with_max_width(u32::from(columns)),
+            output,
+            frame_delay,
+            extra_delay: Duration::ZERO,
+            last_frame_at: None,
+            status_text: String::new(),
+            error: None,
+        }
+    }
+
+    /// Restore the terminal state and return the output, or the first error writing to it.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        if self.last_frame_at.is_some() {
+            self.write("\x1b[0m\x1b[?25h\n");
+        }
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.output),
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if self.error.is_none() {
+            if let Err(error) = self
+                .output
+                .write_all(text.as_bytes())
+                .and_then(|_| self.output.flush())
+            {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    fn to_text(&self, frame: &Frame) -> String {
+        let pixel = |x: u32, y: u32| {
+            if y < frame.height {
+                let offset = ((y * frame.width + x) * 4) as usize;
+                [
+                    frame.pixels[offset],
+                    frame.pixels[offset + 1],
+                    frame.pixels[offset + 2],
+                ]
+            } else {
+                [0; 3]
+            }
+        };
+
+        // Move the cursor to the top left instead of clearing, to avoid flickering.
+        let mut text = String::from("\x1b[H");
+        for y in (0..frame.height).step_by(2) {
+            let mut last_colors = None;
+            for x in 0..frame.width {
+                let colors = (pixel(x, y), pixel(x, y + 1));
+                if last_colors != Some(colors) {
+                    let ([r1, g1, b1], [r2, g2, b2]) = colors;
+                    let _ = write!(text, "\x1b[38;2;{r1};{g1};{b1};48;2;{r2};{g2};{b2}m");
+                    last_colors = Some(colors);
+                }
+                text.push('▀');
+            }
+            text.push_str("\x1b[0m\x1b[K\n");
+        }
+        // Clear the rest of the screen in case the previous frame was larger.
+        text.push_str(&self.status_text);
+        text.push_str("\x1b[J");
+        text
+    }
+}
+
+impl<W: Write> Painter for TerminalPainter<W> {
+    fn clear(&mut self) {
+        self.canvas.clear();
+    }
+
+    fn begin_path(&mut self) {
+        self.canvas.begin_path();
+    }
+
+    fn close_path(&mut self) {
+        self.canvas.close_path();
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64) {
+        self.canvas.fill_rect(x, y, w, h);
+    }
+
+    fn fill_square(&mut self, x: f64, y: f64, size: f64) {
+        self.canvas.fill_square(x, y, size);
+    }
+
+    fn status_text(&mut self, text: &str) {
+        self.status_text = text.to_string();
+    }
+
+    fn fill(&mut self) {
+        self.canvas.fill();
+    }
+
+    fn stroke_square(&mut self, x: i32, y: i32, size: i32) {
+        self.canvas.stroke_square(x, y, size);
+    }
+
+    fn stroke_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.canvas.stroke_style_rgb(r, g, b);
+    }
+
+    fn stroke(&mut self) {
+        self.canvas.stroke();
+    }
+
+    fn line_width(&mut self, width: f64) {
+        self.canvas.line_width(width);
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        self.canvas.line_to(x, y);
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        self.canvas.move_to(x, y);
+    }
+
+    fn fill_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.canvas.fill_style_rgb(r, g, b);
+    }
+
+    fn fill_style_rgba(&mut self, r: i32, g: i32, b: i32, a: f64) {
+        self.canvas.fill_style_rgba(r, g, b, a);
+    }
+
+    fn shadow_color(&mut self, _r: i32, _g: i32, _b: i32) {}
+
+    fn shadow_blur(&mut self, _level: i32) {}
+
+    fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.canvas.arc(x, y, radius, start_angle, end_angle);
+    }
+
+    fn end_frame(&mut self) {
+        let text = self.to_text(&self.canvas.render());
+        match self.last_frame_at {
+            Some(last_frame_at) => {
+                let delay = self.frame_delay + std::mem::take(&mut self.extra_delay);
+                if let Some(remaining) = delay.checked_sub(last_frame_at.elapsed()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+            None => {
+                // Clear the screen and hide the cursor before the first frame.
+                self.write("\x1b[2J\x1b[?25l");
+            }
+        }
+        self.write(&text);
+        self.last_frame_at = Some(Instant::now());
+    }
+
+    fn meta_delay(&mut self, delay_ms: u16) {
+        self.extra_delay += Duration::from_millis(u64::from(delay_ms));
+    }
+
+    fn meta_switch_layer(&mut self, to_layer: u16) {
+        self.canvas.meta_switch_layer(to_layer);
+    }
+
+    fn set_aspect_ratio(&mut self, width: i32, height: i32) {
+        self.canvas.set_aspect_ratio(width, height);
+    }
+
+    fn aspect_ratio(&self) -> f64 {
+        self.canvas.aspect_ratio()
+    }
+
+    fn await_forever(&mut self) {}
+
+    fn play_sound(&mut self, _sound_id: i32) {}
+
+    fn draw_text_centered(&mut self, _x: f64, _y: f64, _font_size: f64, _text: &str) {}
+
+    fn draw_text_top_left(&mut self, _x: f64, _y: f64, _font_size: f64, _text: &str) {}
+
+    fn fill_text_style(&mut self, _style: &str) {}
+
+    fn log(&mut self, _text: &str) {}
+}
+
+#[test]
+fn terminal_painter() {
+    #![allow(clippy::unwrap_used)]
+    let mut painter = TerminalPainter::new(Vec::new(), 80, 3, Duration::ZERO);
+    painter.set_aspect_ratio(1, 1);
+    painter.fill_style_rgb(255, 0, 0);
+    painter.fill_rect(0., 0., 0.5, 0.25);
+    painter.status_text("Round 1");
+    painter.end_frame();
+    painter.clear();
+    painter.end_frame();
+
+    let output = String::from_utf8(painter.finish().unwrap()).unwrap();
+    let frames: Vec<&str> = output.split("\x1b[H").collect();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0], "\x1b[2J\x1b[?25l");
+    assert_eq!(
+        frames[1],
+        "\x1b[38;2;255;0;0;48;2;0;0;0m▀▀\x1b[38;2;0;0;0;48;2;0;0;0m▀▀\x1b[0m\x1b[K\n\
+         \x1b[38;2;0;0;0;48;2;0;0;0m▀▀▀▀\x1b[0m\x1b[K\n\
+         Round 1\x1b[J"
+    );
+    assert!(frames[2].starts_with("\x1b[38;2;0;0;0;48;2;0;0;0m▀▀▀▀"));
+    assert!(frames[2].ends_with("Round 1\x1b[J\x1b[0m\x1b[?25h\n"));
+}
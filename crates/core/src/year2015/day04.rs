@@ -1,27 +1,49 @@
 use crate::common::int_to_ascii::IntToAsciiContext;
-use crate::common::md5::Context;
+use crate::common::md5::{self, LANES};
+use crate::common::parallel;
 use crate::input::Input;
+use std::ops::{ControlFlow, Range};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     const MAX_INDEX: u32 = 100_000_000;
+    // A multiple of LANES, so that chunks are hashed in full batches:
+    const CHUNK_SIZE: u32 = 1 << 16;
 
-    let mut ascii_bytes_context = IntToAsciiContext::new();
     let secret_key = input.text.as_bytes();
-    let mut hasher = Context::new();
-    hasher.consume(secret_key);
+    let max_third_byte = input.part_values(0x0F, 0);
+
+    parallel::search_chunks(
+        0..MAX_INDEX,
+        CHUNK_SIZE,
+        |chunk| search_chunk(secret_key, max_third_byte, chunk),
+        |found| found.map_or(ControlFlow::Continue(()), ControlFlow::Break),
+    )
+    .ok_or_else(|| format!("Aborting after {MAX_INDEX} iterations"))
+}
 
-    for index in 0..MAX_INDEX {
-        let mut index_hasher = hasher.clone();
-        index_hasher.consume(ascii_bytes_context.ascii_bytes(index));
-        let output: [u8; 16] = index_hasher.compute();
+/// Find the first index in a range where the hash starts with five/six zeros.
+fn search_chunk(secret_key: &[u8], max_third_byte: u8, chunk: Range<u32>) -> Option<u32> {
+    let mut ascii_bytes_context = IntToAsciiContext::new();
+    let prefix = md5::Prefix::new(secret_key);
+    let mut suffixes = [(); LANES].map(|_| Vec::new());
+
+    for batch_start in chunk.step_by(LANES) {
+        for (index, suffix) in (batch_start..).zip(suffixes.iter_mut()) {
+            suffix.clear();
+            suffix.extend_from_slice(ascii_bytes_context.ascii_bytes(index));
+        }
+        let outputs =
+            prefix.compute_lanes::<LANES>(std::array::from_fn(|lane| &suffixes[lane][..]));
 
         // Check if hash starts with five/six zeros without converting it to a string:
-        if output[..2] == [0, 0] && output[2] <= input.part_values(0x0F, 0) {
-            return Ok(index);
+        if let Some(lane) = outputs
+            .iter()
+            .position(|output| output[..2] == [0, 0] && output[2] <= max_third_byte)
+        {
+            return Some(batch_start + lane as u32);
         }
     }
-
-    Err(format!("Aborting after {MAX_INDEX} iterations"))
+    None
 }
 
 #[test]
@@ -1,81 +1,57 @@
+use crate::common::grid::{Grid, Neighborhood};
 use crate::input::Input;
 
-struct Grid {
-    data: [bool; 10_000],
-    stuck_corners: bool,
-}
+const SIZE: i32 = 100;
 
-impl Grid {
-    fn is_stuck_corner(&self, x: i32, y: i32) -> bool {
-        self.stuck_corners
-            && ((x, y) == (0, 0) || (x, y) == (99, 0) || (x, y) == (0, 99) || (x, y) == (99, 99))
-    }
+type Lights = Grid<bool, [bool; (SIZE * SIZE) as usize]>;
 
-    fn is_on(&self, x: i32, y: i32) -> bool {
-        if self.is_stuck_corner(x, y) {
-            true
-        } else if (0..100).contains(&x) && (0..100).contains(&y) {
-            self.data[(x + y * 100) as usize]
-        } else {
-            // "Lights on the edge of the grid might have fewer than eight neighbors; the missing ones always count as 'off'."
-            false
-        }
-    }
-
-    fn evolve(&self) -> Self {
-        let mut new_data = [false; 10_000];
-        for x in 0..100 {
-            for y in 0..100 {
-                let mut on_neighbors = 0;
-                for dx in -1..=1 {
-                    for dy in -1..=1 {
-                        if !(dx == 0 && dy == 0) && self.is_on(x + dx, y + dy) {
-                            on_neighbors += 1;
-                        }
-                    }
-                }
-                new_data[(x + y * 100) as usize] = if self.is_stuck_corner(x, y) {
-                    true
-                } else if self.is_on(x, y) {
-                    on_neighbors == 2 || on_neighbors == 3
-                } else {
-                    on_neighbors == 3
-                };
-            }
-        }
-        Self {
-            data: new_data,
-            stuck_corners: self.stuck_corners,
-        }
+fn stick_corners(grid: &mut Lights) {
+    for corner in [(0, 0), (SIZE - 1, 0), (0, SIZE - 1), (SIZE - 1, SIZE - 1)] {
+        grid[corner] = true;
     }
+}
 
-    fn parse(input: &str, stuck_corners: bool) -> Result<Self, String> {
-        let mut data = [false; 10_000];
-        for (y, line) in input.lines().enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                if y >= 100 || x >= 100 {
-                    return Err("Invalid grid (not 100x100)".into());
+fn evolve(grid: &Lights, new_grid: &mut Lights) {
+    let cells = grid.cells();
+    // "Lights on the edge of the grid might have fewer than eight neighbors; the missing ones always count as 'off'."
+    let is_on = |x: i32, y: i32| {
+        (0..SIZE).contains(&x) && (0..SIZE).contains(&y) && cells[(x + y * SIZE) as usize]
+    };
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            let mut on_neighbors = 0;
+            for &(dx, dy) in Neighborhood::All.offsets() {
+                if is_on(x + dx, y + dy) {
+                    on_neighbors += 1;
                 }
-                data[x + y * 100] = char == '#';
             }
+            new_grid[(x, y)] = if is_on(x, y) {
+                on_neighbors == 2 || on_neighbors == 3
+            } else {
+                on_neighbors == 3
+            };
         }
-        Ok(Self {
-            data,
-            stuck_corners,
-        })
-    }
-
-    fn count_lights(&self) -> u32 {
-        self.data.iter().filter(|&&b| b).count() as u32
     }
 }
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    let mut grid = Grid::parse(input.text, input.is_part_two())?;
+    let mut grid = Lights::parse_fixed_size(input.text, |c| Ok(c == b'#'))
+        .map_err(|_| "Invalid grid (not 100x100)".to_string())?;
+    if (grid.width(), grid.height()) != (SIZE as usize, SIZE as usize) {
+        return Err("Invalid grid (not 100x100)".into());
+    }
+    if input.is_part_two() {
+        stick_corners(&mut grid);
+    }
+    let mut new_grid = grid.clone();
     for _step in 0..100 {
-        grid = grid.evolve();
+        evolve(&grid, &mut new_grid);
+        std::mem::swap(&mut grid, &mut new_grid);
+        if input.is_part_two() {
+            stick_corners(&mut grid);
+        }
     }
-    Ok(grid.count_lights())
+    Ok(grid.cells().iter().filter(|&&on| on).count() as u32)
 }
 
 #[test]
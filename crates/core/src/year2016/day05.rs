@@ -1,55 +1,80 @@
 use crate::common::int_to_ascii::IntToAsciiContext;
-use crate::common::md5::Context;
+use crate::common::md5::{self, LANES};
+use crate::common::parallel;
 use crate::input::Input;
 use std::iter::FromIterator;
+use std::ops::{ControlFlow, Range};
 
 pub fn solve(input: &Input) -> Result<String, String> {
     const MAX_INDEX: u32 = 100_000_000;
+    // A multiple of LANES, so that chunks are hashed in full batches:
+    const CHUNK_SIZE: u32 = 1 << 16;
 
-    let mut ascii_bytes_context = IntToAsciiContext::new();
     let mut password = input.part_values(Vec::new(), vec![' '; 8]);
     let door_id = input.text.as_bytes();
     if door_id.len() > 8 {
         return Err("Too long door id (max length: 8)".to_string());
     }
 
-    let mut hasher = Context::new();
-    hasher.consume(door_id);
-
-    for index in 0..MAX_INDEX {
-        let mut new_hasher = hasher.clone();
-        new_hasher.consume(ascii_bytes_context.ascii_bytes(index));
-        let output: [u8; 16] = new_hasher.compute();
-
-        // Check if hash starts with five zeros without converting it to a string:
-        if output[..2] == [0, 0] && output[2] <= 0x0F {
-            if input.is_part_one() {
-                password.push(
-                    format!("{:x?}", (output[2] & 0x0F_u8))
-                        .as_str()
-                        .chars()
-                        .next()
-                        .unwrap_or('_'),
-                );
-                if password.len() == 8 {
-                    return Ok(String::from_iter(password));
-                }
-            } else {
-                let position = output[2] & 0x0F_u8;
-                let character = output[3] >> 4;
-                // "Use only the first result for each position, and ignore invalid positions.":
-                if position < 8 && password[position as usize] == ' ' {
-                    password[position as usize] =
-                        format!("{character:x?}").chars().next().unwrap_or('_');
-                    if !password.contains(&' ') {
-                        return Ok(String::from_iter(password));
+    parallel::search_chunks(
+        0..MAX_INDEX,
+        CHUNK_SIZE,
+        |chunk| search_chunk(door_id, chunk),
+        |outputs| {
+            for output in outputs {
+                if input.is_part_one() {
+                    password.push(
+                        format!("{:x?}", (output[2] & 0x0F_u8))
+                            .as_str()
+                            .chars()
+                            .next()
+                            .unwrap_or('_'),
+                    );
+                    if password.len() == 8 {
+                        return ControlFlow::Break(String::from_iter(password.iter()));
+                    }
+                } else {
+                    let position = output[2] & 0x0F_u8;
+                    let character = output[3] >> 4;
+                    // "Use only the first result for each position, and ignore invalid positions.":
+                    if position < 8 && password[position as usize] == ' ' {
+                        password[position as usize] =
+                            format!("{character:x?}").chars().next().unwrap_or('_');
+                        if !password.contains(&' ') {
+                            return ControlFlow::Break(String::from_iter(password.iter()));
+                        }
                     }
                 }
             }
+            ControlFlow::Continue(())
+        },
+    )
+    .ok_or_else(|| format!("Aborting after {MAX_INDEX} iterations"))
+}
+
+/// Find the hashes in a range of indices starting with five zeros, in index order.
+fn search_chunk(door_id: &[u8], chunk: Range<u32>) -> Vec<[u8; 16]> {
+    let mut ascii_bytes_context = IntToAsciiContext::new();
+    let prefix = md5::Prefix::new(door_id);
+    let mut suffixes = [(); LANES].map(|_| Vec::new());
+    let mut found = Vec::new();
+
+    for batch_start in chunk.step_by(LANES) {
+        for (index, suffix) in (batch_start..).zip(suffixes.iter_mut()) {
+            suffix.clear();
+            suffix.extend_from_slice(ascii_bytes_context.ascii_bytes(index));
         }
-    }
+        let outputs =
+            prefix.compute_lanes::<LANES>(std::array::from_fn(|lane| &suffixes[lane][..]));
 
-    Err(format!("Aborting after {MAX_INDEX} iterations"))
+        // Check if hash starts with five zeros without converting it to a string:
+        found.extend(
+            outputs
+                .iter()
+                .filter(|output| output[..2] == [0, 0] && output[2] <= 0x0F),
+        );
+    }
+    found
 }
 
 #[test]
@@ -84,7 +84,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     if input.is_part_one() {
         Ok(screen.pixels.iter().filter(|&&p| p).count().to_string())
     } else {
-        recognize(&screen.pixels)
+        recognize(&screen.pixels, Screen::WIDTH)
     }
 }
 
@@ -1,63 +1,64 @@
+use crate::common::search::{Graph, Search};
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::{HashSet, VecDeque};
+
+const TARGET: (i32, i32) = (31, 39);
 
 const fn is_wall(x: i32, y: i32, magic_number: i32) -> bool {
     let sum = x * x + 3 * x + 2 * x * y + y + y * y + magic_number;
     sum.count_ones() % 2 == 1
 }
 
-pub fn solve(input: &Input) -> Result<u32, String> {
-    const TARGET: (i32, i32) = (31, 39);
+/// The office building, with walls determined by the magic number.
+struct Office {
+    magic_number: i32,
+}
+
+impl Graph for Office {
+    type State = (i32, i32);
+
+    fn neighbors(&self, &(x, y): &(i32, i32), mut on_neighbor: impl FnMut((i32, i32), usize)) {
+        for (diffx, diffy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (new_x, new_y) = (x + diffx, y + diffy);
+            if new_x >= 0 && new_y >= 0 && !is_wall(new_x, new_y, self.magic_number) {
+                on_neighbor((new_x, new_y), 1);
+            }
+        }
+    }
+
+    fn is_goal(&self, &location: &(i32, i32)) -> bool {
+        location == TARGET
+    }
+}
 
+pub fn solve(input: &Input) -> Result<u32, String> {
     let magic_number = input
         .text
         .parse::<i32>()
         .map_err(|e| format!("Invalid magic number: {e}"))?;
 
-    let mut to_visit = BinaryHeap::new();
-    let mut visited_states = HashSet::new();
-
-    let initial_state = (1, 1);
-    let mut visit_count = 0;
-
-    to_visit.push(Reverse((0, 0, initial_state)));
-    visited_states.insert(initial_state);
-
-    while let Some(Reverse((_estimate, visited_state_cost, visited_state))) = to_visit.pop() {
-        if input.is_part_one() {
-            if visited_state == TARGET {
-                return Ok(visited_state_cost);
-            }
-        } else if visited_state_cost > 50 {
-            return Ok(visit_count);
-        } else {
-            visit_count += 1;
-        }
-
-        for (diffx, diffy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
-            let new_x = visited_state.0 + diffx;
-            let new_y = visited_state.1 + diffy;
-            if new_x < 0 || new_y < 0 || is_wall(new_x, new_y, magic_number) {
-                continue;
-            }
+    let office = Office { magic_number };
 
-            let new_cost = visited_state_cost + 1;
+    if input.is_part_one() {
+        return Search::new()
+            .bfs(&office, (1, 1))
+            .map(|found| found.cost as u32)
+            .ok_or_else(|| "No solution found".to_string());
+    }
 
-            let do_insert = visited_states.insert((new_x, new_y));
-            if do_insert {
-                let new_estimate = new_cost
-                    + if input.is_part_one() {
-                        (new_x - TARGET.0).unsigned_abs() + (new_y - TARGET.1).unsigned_abs()
-                    } else {
-                        0
-                    };
-                to_visit.push(Reverse((new_estimate, new_cost, (new_x, new_y))));
-            }
+    // Count the locations reachable in at most 50 steps:
+    let mut reached = HashSet::from([(1, 1)]);
+    let mut to_visit = VecDeque::from([(0, (1, 1))]);
+    while let Some((steps, location)) = to_visit.pop_front() {
+        if steps < 50 {
+            office.neighbors(&location, |neighbor, _| {
+                if reached.insert(neighbor) {
+                    to_visit.push_back((steps + 1, neighbor));
+                }
+            });
         }
     }
-
-    Err("No solution found".to_string())
+    Ok(reached.len() as u32)
 }
 
 #[test]
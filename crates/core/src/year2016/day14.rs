@@ -1,4 +1,4 @@
-use crate::common::md5::Context;
+use crate::common::md5::{self, LANES};
 use crate::input::Input;
 
 fn to_hash_chars(hash: &[u8]) -> [u8; 32] {
@@ -28,60 +28,52 @@ fn contains_five_in_a_row(hash: &[u8], desired_char: u8) -> bool {
         .any(|w| w[0] == desired_char && w.windows(2).all(|adjacent| adjacent[0] == adjacent[1]))
 }
 
+/// Compute the hashes of the salt followed by the indices `first_index..first_index+LANES`,
+/// stretched 2016 additional times if `stretch` is set.
+fn hash_batch(salt: &str, first_index: usize, stretch: bool) -> [[u8; 16]; LANES] {
+    let messages: [String; LANES] =
+        std::array::from_fn(|lane| format!("{}{}", salt, first_index + lane));
+    let mut hashes =
+        md5::compute_lanes::<LANES>(std::array::from_fn(|lane| messages[lane].as_bytes()));
+    if stretch {
+        for _ in 0..2016 {
+            let hash_strings = hashes.map(|hash| {
+                to_hash_chars(&hash).map(|b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
+            });
+            hashes =
+                md5::compute_lanes::<LANES>(std::array::from_fn(|lane| &hash_strings[lane][..]));
+        }
+    }
+    hashes
+}
+
 pub fn solve(input: &Input) -> Result<u32, String> {
+    const RING_SIZE: usize = 1024;
+
     let salt = input.text;
     if salt.len() > 8 {
         return Err("Too long salt (max length: 8)".to_string());
     }
 
-    let mut hash_cache = Vec::new();
-    let mut orig_hasher = Context::new();
-    orig_hasher.consume(salt.as_bytes());
-
-    for i in 0..1000 {
-        let mut hasher = orig_hasher.clone();
-        hasher.consume(i.to_string().as_bytes());
-        if input.is_part_two() {
-            for _ in 0..2016 {
-                let hash: [u8; 16] = hasher.compute();
-                let hash_str = to_hash_chars(&hash)
-                    .iter()
-                    .map(|&b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
-                    .collect::<Vec<_>>();
-                hasher = Context::new();
-                hasher.consume(&hash_str);
-            }
-        }
-        let hash: [u8; 16] = hasher.compute();
-        hash_cache.push(hash);
-    }
+    // A ring buffer of hashes, large enough to hold the current hash, the next 1000
+    // ones and the rest of the last computed batch:
+    let mut hashes = vec![[0; 16]; RING_SIZE];
+    let mut hash_count = 0;
 
     let mut valid_key_count = 0;
     let mut index = 0;
     loop {
-        let current_hash = hash_cache[index % 1000];
-        hash_cache[index % 1000] = {
-            let content_to_hash = format!("{}{}", salt, index + 1000);
-            let mut hasher = Context::new();
-            hasher.consume(content_to_hash.as_bytes());
-            if input.is_part_two() {
-                for _ in 0..2016 {
-                    let hash: [u8; 16] = hasher.compute();
-                    hasher = Context::new();
-                    let hash_str = to_hash_chars(&hash)
-                        .iter()
-                        .map(|&b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
-                        .collect::<Vec<_>>();
-                    hasher.consume(&hash_str);
-                }
+        while hash_count <= index + 1000 {
+            let batch = hash_batch(salt, hash_count, input.is_part_two());
+            for (offset, hash) in batch.into_iter().enumerate() {
+                hashes[(hash_count + offset) % RING_SIZE] = hash;
             }
-            hasher.compute()
-        };
+            hash_count += LANES;
+        }
 
-        if let Some(triplet_value) = first_triplet(&current_hash) {
-            if hash_cache
-                .iter()
-                .any(|hash| contains_five_in_a_row(hash, triplet_value))
+        if let Some(triplet_value) = first_triplet(&hashes[index % RING_SIZE]) {
+            if (index + 1..=index + 1000)
+                .any(|i| contains_five_in_a_row(&hashes[i % RING_SIZE], triplet_value))
             {
                 valid_key_count += 1;
                 if valid_key_count == 64 {
@@ -1,96 +1,94 @@
-use crate::common::md5::Context;
+use crate::common::md5;
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
 
-#[derive(Hash, Clone, Eq, Ord, PartialOrd, PartialEq)]
 struct State {
     position: (i32, i32),
     path_so_far: Vec<u8>,
-    doors: [bool; 4],
 }
 
-fn check_doors(passcode: &[u8], path_so_far: &[u8]) -> [bool; 4] {
-    let mut hasher = Context::new();
-    hasher.consume(passcode);
-    hasher.consume(path_so_far);
-    let output: [u8; 16] = hasher.compute();
+fn open_doors(hash: &[u8; 16]) -> [bool; 4] {
     //"Only the first four characters of the hash are used; they represent, respectively,
     // the doors up, down, left, and right from your current position.
     // Any b, c, d, e, or f means that the corresponding door is open; any other character
     // (any number or a) means that the corresponding door is closed and locked."
     let is_open = |byte: u8| (11..=16).contains(&byte);
     [
-        is_open((output[0] & 0xF0) >> 4),
-        is_open(output[0] & 0x0F),
-        is_open((output[1] & 0xF0) >> 4),
-        is_open(output[1] & 0x0F),
+        is_open((hash[0] & 0xF0) >> 4),
+        is_open(hash[0] & 0x0F),
+        is_open((hash[1] & 0xF0) >> 4),
+        is_open(hash[1] & 0x0F),
     ]
 }
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let passcode = input.text.as_bytes();
 
-    let mut to_visit = BinaryHeap::new();
-
-    let initial_state = State {
+    // Search breadth first, one path length at a time, so that the doors of all
+    // states at a path length can be computed with batched hashing:
+    let mut to_visit = vec![State {
         position: (0, 0),
         path_so_far: Vec::new(),
-        doors: check_doors(passcode, &[]),
-    };
-
-    to_visit.push(Reverse((0, initial_state)));
-
+    }];
+    let mut path_length = 0;
     let mut desired_path_length = None;
 
-    while let Some(Reverse((visited_state_cost, visited_state))) = to_visit.pop() {
-        if visited_state.position == (3, 3) {
-            if input.is_part_one() {
-                return Ok(visited_state
-                    .path_so_far
-                    .iter()
-                    .map(|&byte| byte as char)
-                    .collect::<String>());
-            } else {
-                desired_path_length = Some(visited_state_cost);
-                continue;
+    while !to_visit.is_empty() {
+        let messages = to_visit
+            .iter()
+            .map(|state| [passcode, &state.path_so_far].concat())
+            .collect::<Vec<_>>();
+        let hashes = md5::compute_all(&messages);
+
+        let mut next_to_visit = Vec::new();
+        for (visited_state, hash) in to_visit.iter().zip(hashes.iter()) {
+            let doors = open_doors(hash);
+            for (idx, direction) in [(0, -1), (0, 1), (-1, 0), (1, 0)].iter().enumerate() {
+                if doors[idx] {
+                    let new_position = (
+                        visited_state.position.0 + direction.0,
+                        visited_state.position.1 + direction.1,
+                    );
+
+                    if new_position.0 < 0
+                        || new_position.0 > 3
+                        || new_position.1 < 0
+                        || new_position.1 > 3
+                    {
+                        continue;
+                    }
+
+                    let direction_char = [b'U', b'D', b'L', b'R'];
+                    let mut new_path = visited_state.path_so_far.clone();
+                    new_path.push(direction_char[idx]);
+                    next_to_visit.push(State {
+                        position: new_position,
+                        path_so_far: new_path,
+                    });
+                }
             }
         }
-
-        for (idx, direction) in [(0, -1), (0, 1), (-1, 0), (1, 0)].iter().enumerate() {
-            if visited_state.doors[idx] {
-                let new_position = (
-                    visited_state.position.0 + direction.0,
-                    visited_state.position.1 + direction.1,
-                );
-
-                if new_position.0 < 0
-                    || new_position.0 > 3
-                    || new_position.1 < 0
-                    || new_position.1 > 3
-                {
-                    continue;
-                }
-
-                let direction_char = [b'U', b'D', b'L', b'R'];
-                let mut new_path = visited_state.path_so_far.clone();
-                new_path.push(direction_char[idx]);
-                let doors = check_doors(passcode, &new_path);
-
-                let new_state = State {
-                    position: new_position,
-                    path_so_far: new_path,
-                    doors,
-                };
-
-                let new_cost = visited_state_cost + 1;
-                to_visit.push(Reverse((new_cost, new_state)));
+        path_length += 1;
+
+        let mut reached_vault = next_to_visit
+            .iter()
+            .filter(|state| state.position == (3, 3))
+            .map(|state| &state.path_so_far)
+            .peekable();
+        if input.is_part_one() {
+            if let Some(path) = reached_vault.min() {
+                return Ok(path.iter().map(|&byte| byte as char).collect::<String>());
             }
+        } else if reached_vault.peek().is_some() {
+            desired_path_length = Some(path_length);
         }
+
+        // Reaching the vault ends a path:
+        next_to_visit.retain(|state| state.position != (3, 3));
+        to_visit = next_to_visit;
     }
 
     desired_path_length
-        .map(|length| length.to_string())
+        .map(|length: u32| length.to_string())
         .ok_or_else(|| "No path found".to_string())
 }
 
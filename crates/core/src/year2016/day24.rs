@@ -1,111 +1,85 @@
+use crate::common::grid::{Grid, Neighborhood, Position};
 use crate::common::permutation::all_permutations;
+use crate::common::search::{Graph, Search};
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::HashMap;
+
+fn parse(input: &str) -> Result<(Grid<u8>, Vec<Position>), String> {
+    let grid = Grid::parse(input, |c| match c {
+        b'#' | b'.' | b'0'..=b'7' => Ok(c),
+        _ => Err(format!("Invalid char in input: '{}'", c as char)),
+    })?;
+
+    let mut locations = Vec::new();
+    let (right, bottom) = (grid.width() as i32 - 1, grid.height() as i32 - 1);
+    for ((x, y), &c) in grid.iter() {
+        if c.is_ascii_digit() {
+            if x == 0 || y == 0 || x == right || y == bottom {
+                return Err("Number at edge".into());
+            }
+            let number = (c - b'0') as usize;
+            if number >= locations.len() {
+                locations.resize(number + 1, (0, 0));
+            }
+            locations[number] = (x, y);
+        }
+    }
+    Ok((grid, locations))
+}
 
-struct Grid {
-    cols: usize,
-    data: Vec<bool>,
-    locations: Vec<(usize, usize)>,
+/// Routes between two locations in the grid.
+struct Route<'a> {
+    grid: &'a Grid<u8>,
+    target: Position,
 }
 
-impl Grid {
-    fn parse(input: &str) -> Result<Self, String> {
-        let rows = input.lines().count();
-        let cols = input.lines().next().ok_or("Empty input")?.len();
-        let mut locations = Vec::new();
-        let mut data = vec![true; rows * cols];
+impl Graph for Route<'_> {
+    type State = Position;
 
-        for (y, line) in input.lines().enumerate() {
-            if line.len() != cols {
-                return Err("Not all rows have equal length".into());
-            }
-            for (x, &c) in line.as_bytes().iter().enumerate() {
-                data[y * cols + x] = match c {
-                    b'#' => false,
-                    b'.' => true,
-                    b'0'..=b'7' => {
-                        if x == 0 || y == 0 || x + 1 == cols || y + 1 == rows {
-                            return Err("Number at edge".into());
-                        }
-                        let number = (c - b'0') as usize;
-                        if number >= locations.len() {
-                            locations.resize(number + 1, (0, 0));
-                        }
-                        locations[number] = (x, y);
-                        true
-                    }
-                    _ => {
-                        return Err(format!("Invalid char in input: '{}'", c as char));
-                    }
-                };
+    fn neighbors(&self, &location: &Position, mut on_neighbor: impl FnMut(Position, usize)) {
+        for (new_location, &c) in self.grid.neighbors(location, Neighborhood::Orthogonal) {
+            if c != b'#' {
+                on_neighbor(new_location, 1);
             }
         }
+    }
 
-        Ok(Self {
-            cols,
-            data,
-            locations,
-        })
+    fn is_goal(&self, &location: &Position) -> bool {
+        location == self.target
     }
 
-    fn is_open(&self, location: (usize, usize)) -> bool {
-        self.data[location.1 * self.cols + location.0]
+    fn heuristic(&self, &location: &Position) -> usize {
+        (location.0.abs_diff(self.target.0) + location.1.abs_diff(self.target.1)) as usize
     }
 }
 
 pub fn solve(input: &Input) -> Result<usize, String> {
-    let grid = Grid::parse(input.text)?;
+    let (grid, locations) = parse(input.text)?;
     let mut distances: HashMap<(usize, usize), usize> = HashMap::new();
 
-    for from in 0..grid.locations.len() {
-        'toloop: for to in 0..grid.locations.len() {
+    for from in 0..locations.len() {
+        for to in 0..locations.len() {
             if to <= from {
                 continue;
             }
 
-            let starting_location = grid.locations[from];
-            let target_location = grid.locations[to];
+            let starting_location = locations[from];
+            let target_location = locations[to];
             if starting_location == (0, 0) || target_location == (0, 0) {
                 return Err("Not all digits in grid".into());
             }
 
-            let mut visited = HashSet::new();
-            let mut to_visit = BinaryHeap::new();
-            to_visit.push(Reverse((0, 0, starting_location)));
-
-            while let Some(Reverse((_heuristic, distance, location))) = to_visit.pop() {
-                if location == target_location {
-                    distances.insert((from, to), distance);
-                    continue 'toloop;
-                }
-
-                for diff in [(1_i32, 0_i32), (-1, 0), (0, 1), (0, -1)] {
-                    if diff.0 == -1 && location.0 == 0 || diff.1 == -1 && location.1 == 0 {
-                        continue;
-                    }
-                    let new_location = (
-                        (location.0 as i32 + diff.0) as usize,
-                        (location.1 as i32 + diff.1) as usize,
-                    );
-                    if grid.is_open(new_location) && visited.insert(new_location) {
-                        let new_distance = distance + 1;
-                        let heuristic = (new_location.0 as i32 - target_location.0 as i32)
-                            .unsigned_abs() as usize
-                            + (new_location.1 as i32 - target_location.1 as i32).unsigned_abs()
-                                as usize;
-                        to_visit.push(Reverse((
-                            new_distance + heuristic,
-                            new_distance,
-                            new_location,
-                        )));
-                    }
-                }
+            let route = Route {
+                grid: &grid,
+                target: target_location,
+            };
+            if let Some(found) = Search::new().a_star(&route, starting_location) {
+                distances.insert((from, to), found.cost);
             }
         }
     }
 
-    let mut initial_order = (1..grid.locations.len()).collect::<Vec<usize>>();
+    let mut initial_order = (1..locations.len()).collect::<Vec<usize>>();
     let mut answer = usize::MAX;
     all_permutations(
         &mut initial_order,
@@ -23,6 +23,10 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             part: Part::Two,
             #[cfg(feature = "visualization")]
             rendered_svg: RefCell::new("".to_string()),
+            #[cfg(feature = "visualization")]
+            frame_listener: None,
+            #[cfg(feature = "painter")]
+            painter: None,
         })?;
         for (index, digit) in hash.bytes().enumerate() {
             let byte = digit - if digit < b'a' { b'0' } else { b'a' - 10 };
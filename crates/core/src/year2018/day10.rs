@@ -1,3 +1,4 @@
+use crate::common::character_recognition::recognize;
 use crate::input::Input;
 use std::cmp::{max, min};
 use std::collections::HashSet;
@@ -75,62 +76,12 @@ pub fn solve(input: &Input) -> Result<String, String> {
         occupied.insert((point.x, point.y));
     }
 
-    let mut result = String::new();
-    for y in borders.0..=borders.2 {
-        for x in borders.3..=borders.1 {
-            result += if occupied.contains(&(x, y)) { "#" } else { "." }
-        }
-        result += "\n";
-    }
-
-    let identified_chars = identify_chars(&result)?;
-    Ok(identified_chars)
-}
-
-fn identify_char(input: &str) -> Result<char, String> {
-    Ok(match input {
-        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n" => 'A',
-        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.\n" => 'B',
-        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.\n" => 'C',
-        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######\n" => 'E',
-        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....\n" => 'F',
-        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#\n" => 'G',
-        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#\n" => 'H',
-        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..\n" => 'J',
-        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#\n" => 'K',
-        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######\n" => 'L',
-        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#\n" => 'N',
-        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....\n" => 'P',
-        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#\n" => 'R',
-        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#\n" => 'X',
-        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######\n" => 'Z',
-        _ => {
-            println!("###Unrecognized string:\n{input}###");
-            let mut shower = String::new();
-            shower.push('"');
-            shower.push_str(&input.replace('\n', "\\n"));
-            shower.push_str("\" => '?',");
-            println!("Shower:\n{shower}");
-            return Err(format!("Unrecognized char: {input}"));
-        }
-    })
-}
-
-fn identify_chars(input: &str) -> Result<String, String> {
-    let lines: Vec<&str> = input.lines().collect();
-    let mut i = 0;
-    let mut result = String::new();
-    while i + 5 < lines[0].len() {
-        let mut this_char_input = String::new();
-        for line in lines.iter() {
-            this_char_input.push_str(&line[i..i + 6]);
-            this_char_input.push('\n');
-        }
-        result.push(identify_char(&this_char_input)?);
-        i += 8;
+    let width = (borders.1 - borders.3 + 1) as usize;
+    let mut screen = vec![false; width * (borders.2 - borders.0 + 1) as usize];
+    for &(x, y) in &occupied {
+        screen[(y - borders.0) as usize * width + (x - borders.3) as usize] = true;
     }
-
-    Ok(result)
+    recognize(&screen, width)
 }
 
 #[test]
@@ -1,3 +1,4 @@
+use crate::common::parallel;
 use crate::input::Input;
 type GridValue = i32;
 
@@ -70,21 +71,31 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
     let table = SummedAreaTable::new(serial_number);
 
-    let mut optimal_power: GridValue = 0;
-    let mut optimal_square_width = 0;
-    let mut optimal_point = (0, 0);
-
-    for square_width in input.part_values(3..=3, 1..=300) {
+    let square_widths = input.part_values(3..=3, 1..=300).collect::<Vec<_>>();
+    let optimal_per_width = parallel::map(&square_widths, |&square_width| {
+        let mut optimal_power: GridValue = 0;
+        let mut optimal_point = (0, 0);
         for y in 1..=(SummedAreaTable::SIZE - square_width) {
             for x in 1..=(SummedAreaTable::SIZE - square_width) {
                 let square_power = table.square_power(x, y, square_width);
                 if square_power > optimal_power {
-                    optimal_square_width = square_width;
                     optimal_power = square_power;
                     optimal_point = (x, y);
                 }
             }
         }
+        (optimal_power, optimal_point)
+    });
+
+    let mut optimal_power: GridValue = 0;
+    let mut optimal_square_width = 0;
+    let mut optimal_point = (0, 0);
+    for (&square_width, &(power, point)) in square_widths.iter().zip(optimal_per_width.iter()) {
+        if power > optimal_power {
+            optimal_square_width = square_width;
+            optimal_power = power;
+            optimal_point = point;
+        }
     }
 
     Ok(if input.is_part_one() {
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 
 #[derive(Clone)]
 enum TrackPiece {
@@ -173,8 +175,47 @@ impl Track {
         Ok(Self { track, carts })
     }
 
-    fn find_position(&mut self, part1: bool) -> Result<Vector, String> {
-        for _ in 0..Self::MAX_TICKS {
+    #[cfg(feature = "painter")]
+    fn paint(&self, painter: &mut dyn Painter, input_string: &str, tick: u32) {
+        let width = input_string.lines().map(str::len).max().unwrap_or(1);
+        let height = input_string.lines().count().max(1);
+        let (cell_width, cell_height) = (1. / width as f64, 1. / height as f64);
+        painter.set_aspect_ratio(width as i32, height as i32);
+        painter.clear();
+
+        // Carts in the input are on straight track pieces.
+        painter.fill_style_rgb(0x50, 0x50, 0x50);
+        for (y, line) in input_string.lines().enumerate() {
+            for (x, c) in line.bytes().enumerate() {
+                if c != b' ' {
+                    painter.fill_rect(
+                        x as f64 * cell_width,
+                        y as f64 * cell_height,
+                        cell_width,
+                        cell_height,
+                    );
+                }
+            }
+        }
+
+        painter.fill_style_rgb(0xfd, 0xdb, 0x27);
+        for cart in &self.carts {
+            painter.fill_rect(
+                f64::from(cart.position.x) * cell_width,
+                f64::from(cart.position.y) * cell_height,
+                cell_width,
+                cell_height,
+            );
+        }
+        painter.status_text(&format!("Tick {tick}, {} carts", self.carts.len()));
+    }
+
+    fn find_position(&mut self, input: &Input) -> Result<Vector, String> {
+        let part1 = input.is_part_one();
+        for _tick in 0..Self::MAX_TICKS {
+            #[cfg(feature = "painter")]
+            input.paint_frame(|painter| self.paint(painter, input.text, _tick));
+
             self.carts.sort_by(|a, b| a.position.cmp(&b.position));
 
             let mut cart_idx = 0;
@@ -218,7 +259,7 @@ impl Track {
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut track = Track::parse(input.text)?;
-    let position = track.find_position(input.is_part_one())?;
+    let position = track.find_position(input)?;
     Ok(format!("{},{}", position.x, position.y))
 }
 
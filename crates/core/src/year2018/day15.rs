@@ -1,4 +1,6 @@
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 use std::collections::VecDeque;
 
 #[derive(Copy, Clone)]
@@ -108,6 +110,41 @@ impl Board {
         self.cells[(x + self.width * y) as usize] = value;
     }
 
+    #[cfg(feature = "painter")]
+    fn paint(&self, painter: &mut dyn Painter) {
+        let (cell_width, cell_height) = (1. / f64::from(self.width), 1. / f64::from(self.height));
+        painter.set_aspect_ratio(self.width as i32, self.height as i32);
+        painter.clear();
+        for (index, cell) in self.cells.iter().enumerate() {
+            match *cell {
+                MapCell::Wall => painter.fill_style_rgb(0x50, 0x50, 0x50),
+                MapCell::Open => continue,
+                // Units fade out as they lose hit points.
+                MapCell::Unit {
+                    hit_points, elf, ..
+                } => {
+                    let alpha = 0.2 + 0.8 * f64::from(hit_points.max(0)) / 200.;
+                    if elf {
+                        painter.fill_style_rgba(0x00, 0xcc, 0x00, alpha);
+                    } else {
+                        painter.fill_style_rgba(0xff, 0x33, 0x33, alpha);
+                    }
+                }
+            }
+            let (x, y) = (index as u32 % self.width, index as u32 / self.width);
+            painter.fill_rect(
+                f64::from(x) * cell_width,
+                f64::from(y) * cell_height,
+                cell_width,
+                cell_height,
+            );
+        }
+        painter.status_text(&format!(
+            "Round {}, elf attack power {}: {} elves, {} goblins",
+            self.round, self.elf_attack_power, self.elves_alive, self.goblins_alive
+        ));
+    }
+
     fn calculate_outcome(&self) -> Option<i32> {
         if self.elves_alive != 0 && self.goblins_alive != 0 {
             return None;
@@ -286,6 +323,8 @@ pub fn solve(input: &Input) -> Result<i32, String> {
             }
 
             board.perform_round();
+            #[cfg(feature = "painter")]
+            input.paint_frame(|painter| board.paint(painter));
 
             if input.is_part_two() && board.elf_died {
                 break;
@@ -1,4 +1,6 @@
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 use std::cmp::{max, min};
 #[cfg(feature = "debug-output")]
 use std::env;
@@ -23,6 +25,9 @@ struct Grid {
     cells: Vec<u8>,
     width: usize,
     height: usize,
+    /// Indices of cells changed since last painted.
+    #[cfg(feature = "painter")]
+    changed_cells: Vec<usize>,
 }
 
 impl Grid {
@@ -77,9 +82,57 @@ impl Grid {
             cells,
             width,
             height,
+            #[cfg(feature = "painter")]
+            changed_cells: Vec::new(),
         })
     }
 
+    #[cfg(feature = "painter")]
+    fn paint_cell(&self, painter: &mut dyn Painter, index: usize) {
+        match self.cells[index] {
+            b'#' => painter.fill_style_rgb(0x8b, 0x5a, 0x2b),
+            b'w' => painter.fill_style_rgb(0x1e, 0x5a, 0xd2),
+            b'|' => painter.fill_style_rgb(0x7d, 0xc8, 0xff),
+            _ => painter.fill_style_rgb(0x1a, 0x14, 0x0f),
+        }
+        let (cell_width, cell_height) = (1. / self.width as f64, 1. / self.height as f64);
+        painter.fill_rect(
+            (index % self.width) as f64 * cell_width,
+            (index / self.width) as f64 * cell_height,
+            cell_width,
+            cell_height,
+        );
+    }
+
+    #[cfg(feature = "painter")]
+    fn paint_all(&mut self, input: &Input) {
+        self.changed_cells.clear();
+        input.paint_frame(|painter| {
+            painter.set_aspect_ratio(self.width as i32, self.height as i32);
+            painter.clear();
+            painter.fill_style_rgb(0x1a, 0x14, 0x0f);
+            painter.fill_rect(0., 0., 1., 1.);
+            for index in 0..self.cells.len() {
+                if self.cells[index] != b'.' {
+                    self.paint_cell(painter, index);
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "painter")]
+    fn paint_changes(&mut self, input: &Input) {
+        if self.changed_cells.is_empty() {
+            return;
+        }
+        let changed_cells = std::mem::take(&mut self.changed_cells);
+        input.paint_frame(|painter| {
+            for index in changed_cells {
+                self.paint_cell(painter, index);
+            }
+        });
+    }
+
     #[cfg(feature = "debug-output")]
     fn print(&self, name: &str) {
         if env::var("ADVENT_DEBUG").is_err() {
@@ -103,11 +156,17 @@ impl Grid {
     }
 
     fn set_water_at(&mut self, x: u16, y: u16, solid: bool) {
-        self.cells[y as usize * self.width + x as usize] = if solid { b'w' } else { b'|' };
+        let index = y as usize * self.width + x as usize;
+        self.cells[index] = if solid { b'w' } else { b'|' };
+        #[cfg(feature = "painter")]
+        self.changed_cells.push(index);
     }
 
     fn dry_at(&mut self, x: u16, y: u16) {
-        self.cells[y as usize * self.width + x as usize] = b'.';
+        let index = y as usize * self.width + x as usize;
+        self.cells[index] = b'.';
+        #[cfg(feature = "painter")]
+        self.changed_cells.push(index);
     }
 
     fn wall_in_direction(&self, x_start: u16, y: u16, x_direction: i32) -> bool {
@@ -164,7 +223,7 @@ impl Grid {
         y
     }
 
-    fn pour_water(&mut self) {
+    fn pour_water(&mut self, _input: &Input) {
         let mut line = 1;
         while line < self.height {
             let mut top_y = line;
@@ -179,6 +238,8 @@ impl Grid {
             for x in to_fill {
                 top_y = min(top_y, self.spread_water_at(x as u16, line as u16) as usize);
             }
+            #[cfg(feature = "painter")]
+            self.paint_changes(_input);
             line = top_y + 1;
         }
     }
@@ -198,7 +259,7 @@ impl Grid {
         }
     }
 
-    fn dry_up(&mut self) {
+    fn dry_up(&mut self, _input: &Input) {
         let mut line = self.height as u16;
         while line > 0 {
             line -= 1;
@@ -218,6 +279,8 @@ impl Grid {
                     }
                 }
             }
+            #[cfg(feature = "painter")]
+            self.paint_changes(_input);
         }
     }
 
@@ -238,15 +301,17 @@ pub fn solve(input: &Input) -> Result<usize, String> {
     let mut grid = Grid::from(input.text)?;
     #[cfg(feature = "debug-output")]
     grid.print("Initial");
+    #[cfg(feature = "painter")]
+    grid.paint_all(input);
 
-    grid.pour_water();
+    grid.pour_water(input);
     #[cfg(feature = "debug-output")]
     grid.print("After pouring");
 
     if input.is_part_one() {
         Ok(grid.count_water())
     } else {
-        grid.dry_up();
+        grid.dry_up(input);
         #[cfg(feature = "debug-output")]
         grid.print("After drying up");
         Ok(grid.count_drained_water())
@@ -43,7 +43,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
         });
 
         let image_bytes = image.iter_mut().map(|b| *b == b'1').collect::<Vec<_>>();
-        recognize(&image_bytes)
+        recognize(&image_bytes, PIXELS_WIDE)
     }
 }
 
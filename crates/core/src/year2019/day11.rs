@@ -1,6 +1,5 @@
-use std::collections::HashMap;
-
 use crate::common::character_recognition::recognize;
+use crate::common::grid::SparseGrid;
 use crate::input::Input;
 
 use super::int_code::{Program, Word};
@@ -50,10 +49,10 @@ impl Direction {
     }
 }
 
-fn run(input_string: &str, initial_color: Color) -> Result<HashMap<(i32, i32), Color>, String> {
+fn run(input_string: &str, initial_color: Color) -> Result<SparseGrid<Color>, String> {
     let mut program = Program::parse(input_string)?;
-    let mut painted: HashMap<(i32, i32), Color> = HashMap::new();
-    let mut position = (0, 0);
+    let mut painted = SparseGrid::default();
+    let mut position = [0, 0];
     let mut current_direction = Direction::Up;
 
     if initial_color == Color::White {
@@ -61,7 +60,7 @@ fn run(input_string: &str, initial_color: Color) -> Result<HashMap<(i32, i32), C
     }
 
     loop {
-        program.input(*painted.get(&position).unwrap_or(&Color::Black) as Word);
+        program.input(*painted.get(position).unwrap_or(&Color::Black) as Word);
         let output = program.run_for_output()?;
 
         if program.is_halted() {
@@ -86,10 +85,10 @@ fn run(input_string: &str, initial_color: Color) -> Result<HashMap<(i32, i32), C
         };
 
         match current_direction {
-            Direction::Up => position = (position.0, position.1 + 1),
-            Direction::Right => position = (position.0 + 1, position.1),
-            Direction::Down => position = (position.0, position.1 - 1),
-            Direction::Left => position = (position.0 - 1, position.1),
+            Direction::Up => position[1] += 1,
+            Direction::Right => position[0] += 1,
+            Direction::Down => position[1] -= 1,
+            Direction::Left => position[0] -= 1,
         }
     }
 
@@ -97,34 +96,20 @@ fn run(input_string: &str, initial_color: Color) -> Result<HashMap<(i32, i32), C
 }
 
 pub fn solve(input: &Input) -> Result<String, String> {
-    let painted = run(input.text, input.part_values(Color::Black, Color::White))?;
+    let mut painted = run(input.text, input.part_values(Color::Black, Color::White))?;
 
     if input.is_part_one() {
         Ok(painted.len().to_string())
     } else {
-        let mut min_x = i32::MAX;
-        let mut max_x = i32::MIN;
-        let mut min_y = i32::MAX;
-        let mut max_y = i32::MIN;
-        painted.iter().for_each(|(&(x, y), color)| {
-            if *color == Color::White {
-                min_x = std::cmp::min(min_x, x);
-                // Add one column for end of last letter:
-                max_x = std::cmp::max(max_x, x + 1);
-                min_y = std::cmp::min(min_y, y);
-                max_y = std::cmp::max(max_y, y);
-            }
-        });
-
-        let mut screen = vec![false; (max_x - min_x + 1) as usize * (max_y - min_y + 1) as usize];
-        painted.iter().for_each(|(&(x, y), color)| {
-            if *color == Color::White {
-                screen
-                    [(max_y - y) as usize * (max_x - min_x + 1) as usize + (x - min_x) as usize] =
-                    true;
-            }
-        });
-        recognize(&screen)
+        painted.retain(|_, &mut color| color == Color::White);
+        let ([min_x, min_y], [max_x, max_y]) = painted.bounds().ok_or("No panels painted white")?;
+
+        let width = (max_x - min_x + 1) as usize;
+        let mut screen = vec![false; width * (max_y - min_y + 1) as usize];
+        for ([x, y], _) in painted.iter() {
+            screen[(max_y - y) as usize * width + (x - min_x) as usize] = true;
+        }
+        recognize(&screen, width)
     }
 }
 
@@ -1,5 +1,64 @@
 use super::int_code::{Program, Word};
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
+
+/// Paint the tiles in the output of the game, where the first output contains the whole screen.
+#[cfg(feature = "painter")]
+fn paint_tiles(
+    painter: &mut dyn Painter,
+    output: &[Word],
+    screen_size: &mut Option<(Word, Word)>,
+    score: Word,
+) {
+    let (width, height) = *screen_size.get_or_insert_with(|| {
+        let size = output
+            .chunks_exact(3)
+            .fold((1, 1), |(width, height), chunk| {
+                (width.max(chunk[0] + 1), height.max(chunk[1] + 1))
+            });
+        painter.set_aspect_ratio(size.0 as i32, size.1 as i32);
+        painter.clear();
+        size
+    });
+    let (cell_width, cell_height) = (1. / width as f64, 1. / height as f64);
+
+    for chunk in output.chunks_exact(3) {
+        let (x, y, tile) = (chunk[0], chunk[1], chunk[2]);
+        if x < 0 {
+            continue;
+        }
+        let (left, top) = (x as f64 * cell_width, y as f64 * cell_height);
+        painter.fill_style_rgb(0, 0, 0);
+        painter.fill_rect(left, top, cell_width, cell_height);
+        match tile {
+            1 => painter.fill_style_rgb(0x50, 0x50, 0x50),
+            // Blocks are colored by row.
+            2 => painter.fill_style_rgb(
+                0x40 + (y * 0x30 % 0xc0) as i32,
+                0x80,
+                0xff - (y * 0x30 % 0xc0) as i32,
+            ),
+            3 => painter.fill_style_rgb(0xff, 0xff, 0xff),
+            4 => {
+                painter.fill_style_rgb(0xfd, 0xdb, 0x27);
+                painter.begin_path();
+                painter.arc(
+                    left + cell_width / 2.,
+                    top + cell_height / 2.,
+                    cell_height / 2.,
+                    0.,
+                    std::f64::consts::TAU,
+                );
+                painter.fill();
+                continue;
+            }
+            _ => continue,
+        }
+        painter.fill_rect(left, top, cell_width, cell_height);
+    }
+    painter.status_text(&format!("Score: {score}"));
+}
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let mut program = Program::parse(input.text)?;
@@ -15,6 +74,8 @@ pub fn solve(input: &Input) -> Result<Word, String> {
     let mut current_score = 0;
     let mut ball_x = -1;
     let mut paddle_x = -1;
+    #[cfg(feature = "painter")]
+    let mut screen_size = None;
 
     loop {
         let output = program.run_for_output()?;
@@ -31,6 +92,9 @@ pub fn solve(input: &Input) -> Result<Word, String> {
             }
         });
 
+        #[cfg(feature = "painter")]
+        input.paint_frame(|painter| paint_tiles(painter, &output, &mut screen_size, current_score));
+
         if is_part_one {
             return Ok(output
                 .iter()
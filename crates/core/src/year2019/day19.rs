@@ -1,4 +1,5 @@
 use super::int_code::{Program, Word};
+use crate::common::parallel;
 use crate::input::Input;
 
 fn affected_by_beam(program: &Program, x: i32, y: i32) -> Result<bool, String> {
@@ -21,9 +22,14 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     let is_part_one = input.is_part_one();
 
     if is_part_one {
+        let coordinates = (0..50)
+            .flat_map(|x| (0..50).map(move |y| (x, y)))
+            .collect::<Vec<_>>();
+        let affected = parallel::map(&coordinates, |&(x, y)| affected_by_beam(&program, x, y));
+
         let mut affected_count = 0;
-        for (x, y) in (0..50).flat_map(|x| (0..50).map(move |y| (x, y))) {
-            if affected_by_beam(&program, x, y)? {
+        for is_affected in affected {
+            if is_affected? {
                 affected_count += 1;
             }
         }
@@ -1,6 +1,7 @@
+use crate::common::search::{Graph, Search};
 use crate::input::Input;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 
 const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (0, -1), (-1, 0), (1, 0)];
 
@@ -140,43 +141,40 @@ impl Maze {
     }
 }
 
-pub fn solve(input: &Input) -> Result<i32, String> {
-    let maze = Maze::parse(input.text, input.is_part_one())?;
-
-    let mut to_visit = VecDeque::new();
-    let mut visited = HashSet::new();
-    // Contains ((x,y), distance, level):
-    to_visit.push_back((maze.start_location, 0, 0));
-    // Contains ((x,y), level):
-    visited.insert((maze.start_location, 0));
-
-    while let Some((visiting, distance, level)) = to_visit.pop_front() {
-        let new_distance = distance + 1;
+impl Graph for Maze {
+    // The location and recursion level:
+    type State = ((i32, i32), i32);
 
+    fn neighbors(
+        &self,
+        &(location, level): &Self::State,
+        mut on_neighbor: impl FnMut(Self::State, usize),
+    ) {
+        let portal = self.portals.get(&location).copied();
         for (new_location, level_difference) in DIRECTIONS
             .iter()
-            .map(|&(dx, dy)| ((visiting.0 + dx, visiting.1 + dy), 0))
-            .chain(
-                if let Some(&(new_location, level_difference)) = maze.portals.get(&visiting) {
-                    Some((new_location, level_difference)).into_iter()
-                } else {
-                    None.into_iter()
-                },
-            )
+            .map(|&(dx, dy)| ((location.0 + dx, location.1 + dy), 0))
+            .chain(portal)
         {
             let new_level = level + level_difference;
-            if new_level >= 0
-                && maze.tile_at(new_location.0, new_location.1) == b'.'
-                && visited.insert((new_location, new_level))
-            {
-                if new_location == maze.end_location && new_level == 0 {
-                    return Ok(new_distance);
-                }
-                to_visit.push_back((new_location, new_distance, new_level));
+            if new_level >= 0 && self.tile_at(new_location.0, new_location.1) == b'.' {
+                on_neighbor((new_location, new_level), 1);
             }
         }
     }
-    Err("No path found".to_string())
+
+    fn is_goal(&self, &(location, level): &Self::State) -> bool {
+        location == self.end_location && level == 0
+    }
+}
+
+pub fn solve(input: &Input) -> Result<i32, String> {
+    let maze = Maze::parse(input.text, input.is_part_one())?;
+    // Steps through portals are reversible, so search from both ends:
+    Search::new()
+        .bidirectional_bfs(&maze, (maze.start_location, 0), (maze.end_location, 0))
+        .map(|found| found.cost as i32)
+        .ok_or_else(|| "No path found".to_string())
 }
 
 #[test]
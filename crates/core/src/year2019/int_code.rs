@@ -90,29 +90,48 @@ impl Program {
         });
     }
 
-    fn parameter_mode(&self, instruction: Word, parameter_position: u32) -> Parameter {
+    fn parameter_mode(
+        &self,
+        instruction: Word,
+        parameter_position: u32,
+    ) -> Result<Parameter, String> {
         let parameter = self.read_memory(self.instruction_pointer + parameter_position as usize);
         let divider = 10_i64.pow(parameter_position + 1);
         let mode = ((instruction / divider) % 10) as u8;
-        match mode {
-            1 => Parameter::Value(parameter),
-            2 => Parameter::Address((parameter + self.relative_base) as usize),
-            _ => Parameter::Address(parameter as usize),
+        let address = match mode {
+            1 => return Ok(Parameter::Value(parameter)),
+            2 => parameter
+                .checked_add(self.relative_base)
+                .ok_or("Overflow in program")?,
+            _ => parameter,
+        };
+        if address < 0 {
+            return Err(format!("Invalid memory address: {address}"));
         }
+        Ok(Parameter::Address(address as usize))
     }
 
     fn output_location(&self, instruction: Word, parameter_position: u32) -> Result<usize, String> {
-        if let Parameter::Address(location) = self.parameter_mode(instruction, parameter_position) {
-            return Ok(location);
+        // Avoid growing memory without bounds when writing to arbitrary addresses, while
+        // reading from them is fine as memory outside of what has been written is zero:
+        const MAX_WRITE_ADDRESS: usize = 1 << 20;
+
+        match self.parameter_mode(instruction, parameter_position)? {
+            Parameter::Address(location) if location > MAX_WRITE_ADDRESS => {
+                Err(format!("Too large memory address to write to: {location}"))
+            }
+            Parameter::Address(location) => Ok(location),
+            Parameter::Value(_) => Err("Invalid parameter mode for where to write".to_string()),
         }
-        Err("Invalid parameter mode for where to write".to_string())
     }
 
-    fn parameter_value(&self, instruction: Word, parameter_position: u32) -> Word {
-        match self.parameter_mode(instruction, parameter_position) {
-            Parameter::Value(value) => value,
-            Parameter::Address(location) => self.read_memory(location),
-        }
+    fn parameter_value(&self, instruction: Word, parameter_position: u32) -> Result<Word, String> {
+        Ok(
+            match self.parameter_mode(instruction, parameter_position)? {
+                Parameter::Value(value) => value,
+                Parameter::Address(location) => self.read_memory(location),
+            },
+        )
     }
 
     fn evaluate(&mut self) -> Result<(), String> {
@@ -121,8 +140,8 @@ impl Program {
 
         match opcode {
             1 | 2 => {
-                let parameter1 = self.parameter_value(instruction, 1);
-                let parameter2 = self.parameter_value(instruction, 2);
+                let parameter1 = self.parameter_value(instruction, 1)?;
+                let parameter2 = self.parameter_value(instruction, 2)?;
                 let output_location = self.output_location(instruction, 3)?;
                 let value = if opcode == 1 {
                     parameter1.checked_add(parameter2)
@@ -147,7 +166,7 @@ impl Program {
             4 => {
                 // Opcode 4 outputs the value of its only parameter.
                 self.output_values
-                    .push(self.parameter_value(instruction, 1));
+                    .push(self.parameter_value(instruction, 1)?);
                 self.instruction_pointer += 2;
             }
             5 | 6 => {
@@ -156,9 +175,9 @@ impl Program {
                 // Opcode 6 is jump-if-false: if the first parameter is zero, it sets the instruction pointer
                 // to the value from the second parameter. Otherwise, it does nothing.
                 let jump_if = opcode == 5;
-                let parameter_1_true = self.parameter_value(instruction, 1) != 0;
+                let parameter_1_true = self.parameter_value(instruction, 1)? != 0;
                 if parameter_1_true == jump_if {
-                    self.instruction_pointer = self.parameter_value(instruction, 2) as usize;
+                    self.instruction_pointer = self.parameter_value(instruction, 2)? as usize;
                 } else {
                     self.instruction_pointer += 3;
                 }
@@ -168,8 +187,8 @@ impl Program {
                 // it stores 1 in the position given by the third parameter. Otherwise, it stores 0.
                 // Opcode 8 is equals: if the first parameter is equal to the second parameter,
                 // it stores 1 in the position given by the third parameter. Otherwise, it stores 0.
-                let parameter_1 = self.parameter_value(instruction, 1);
-                let parameter_2 = self.parameter_value(instruction, 2);
+                let parameter_1 = self.parameter_value(instruction, 1)?;
+                let parameter_2 = self.parameter_value(instruction, 2)?;
                 let output_value = i64::from(
                     (opcode == 7 && (parameter_1 < parameter_2))
                         || (opcode == 8 && (parameter_1 == parameter_2)),
@@ -180,7 +199,10 @@ impl Program {
                 self.instruction_pointer += 4;
             }
             9 => {
-                self.relative_base += self.parameter_value(instruction, 1);
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(self.parameter_value(instruction, 1)?)
+                    .ok_or("Overflow in program")?;
                 self.instruction_pointer += 2;
             }
             99 => {
@@ -218,6 +218,64 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     }
 }
 
+/// A straightforward simulation running the program in order, used as an alternative to
+/// [solve] in differential testing. Masks which are [too slow](BitMask::too_slow) are refused.
+#[cfg(feature = "fuzzing")]
+pub fn solve_by_simulation(input: &Input) -> Result<u64, String> {
+    let mut memory = std::collections::HashMap::new();
+    let mut current_mask = None;
+    for (line_idx, line) in input.text.lines().enumerate() {
+        let on_error = || format!("Line {}: Invalid format", line_idx + 1);
+        if let Some(bit_mask_str) = line.strip_prefix("mask = ") {
+            if bit_mask_str.len() != 36
+                || bit_mask_str
+                    .bytes()
+                    .any(|c| !matches!(c, b'X' | b'1' | b'0'))
+            {
+                return Err(on_error());
+            }
+            current_mask = Some(bit_mask_str);
+        } else if let Some(remainder) = line.strip_prefix("mem[") {
+            let (part1, part2) = remainder.split_once("] = ").ok_or_else(on_error)?;
+            let address = part1.parse::<u64>().map_err(|_| on_error())?;
+            let value = part2.parse::<u64>().map_err(|_| on_error())?;
+            let bit_mask_str = current_mask.ok_or_else(on_error)?;
+
+            if input.is_part_one() {
+                let bit_mask = BitMaskV1::parse(bit_mask_str);
+                memory.insert(address, (value & bit_mask.zeroes) | bit_mask.ones);
+            } else {
+                if BitMaskV2::parse(bit_mask_str).too_slow() {
+                    return Err(format!("Line {}: Too many floating bits", line_idx + 1));
+                }
+                let mut base_address = address;
+                let mut floating_bits = Vec::new();
+                for (offset, c) in bit_mask_str.bytes().rev().enumerate() {
+                    match c {
+                        b'1' => base_address |= 1 << offset,
+                        b'X' => {
+                            base_address &= !(1 << offset);
+                            floating_bits.push(offset);
+                        }
+                        _ => {}
+                    }
+                }
+                for combination in 0_u64..(1 << floating_bits.len()) {
+                    let floating_address = floating_bits
+                        .iter()
+                        .enumerate()
+                        .filter(|&(bit_idx, _)| combination & (1 << bit_idx) != 0)
+                        .fold(base_address, |address, (_, offset)| address | 1 << offset);
+                    memory.insert(floating_address, value);
+                }
+            }
+        } else {
+            return Err(on_error());
+        }
+    }
+    Ok(memory.values().sum())
+}
+
 #[test]
 pub fn tests() {
     use crate::input::{test_part_one, test_part_two};
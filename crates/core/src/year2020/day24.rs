@@ -1,7 +1,8 @@
+use crate::common::grid::Neighborhood;
 use crate::input::Input;
 use std::collections::{HashMap, HashSet};
 
-/// Using double-width coordinates - see https://www.redblobgames.com/grids/hexagons/
+/// Using axial coordinates - see https://www.redblobgames.com/grids/hexagons/
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut black_tiles = HashSet::new();
 
@@ -12,15 +13,15 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         while string_position < line.len() {
             let first_char = line[string_position];
             let diff = match first_char {
-                b'e' => (2, 0),
-                b'w' => (-2, 0),
+                b'e' => (1, 0),
+                b'w' => (-1, 0),
                 b's' | b'n' => {
                     string_position += 1;
                     match (first_char, line.get(string_position)) {
-                        (b'n', Some(b'e')) => (1, 1),
-                        (b'n', Some(b'w')) => (-1, 1),
-                        (b's', Some(b'e')) => (1, -1),
-                        (b's', Some(b'w')) => (-1, -1),
+                        (b'n', Some(b'e')) => (1, -1),
+                        (b'n', Some(b'w')) => (0, -1),
+                        (b's', Some(b'e')) => (0, 1),
+                        (b's', Some(b'w')) => (-1, 1),
                         _ => {
                             return Err("Invalid input".to_string());
                         }
@@ -47,7 +48,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             let mut new_black_tiles = black_tiles.clone();
 
             for &black_tile in black_tiles.iter() {
-                for diff in [(2, 0), (1, -1), (-1, -1), (-2, 0), (-1, 1), (1, 1)] {
+                for diff in Neighborhood::Hex.offsets() {
                     let adjacent_location = (black_tile.0 + diff.0, black_tile.1 + diff.1);
                     *adjacent_blacks_count.entry(adjacent_location).or_insert(0) += 1;
                 }
@@ -52,7 +52,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     for (x, y) in dots {
         screen[usize::from(y) * NUM_LETTERS * CHAR_WIDTH + usize::from(x)] = true;
     }
-    recognize(&screen)
+    recognize(&screen, NUM_LETTERS * CHAR_WIDTH)
 }
 
 #[test]
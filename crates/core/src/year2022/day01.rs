@@ -82,9 +82,10 @@ pub fn tests() {
 pub fn no_memory_allocations() {
     use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day01_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         test_part_one!(real_input => 71_300);
         test_part_two!(real_input => 209_691);
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 0);
 }
@@ -57,9 +57,10 @@ CrZsJsPPZsGzwwsLwLmpwMDw";
 pub fn no_memory_allocations() {
     use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day03_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         test_part_one!(real_input => 8176);
         test_part_two!(real_input => 2689);
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 0);
 }
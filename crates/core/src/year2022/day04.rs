@@ -61,9 +61,10 @@ pub fn tests() {
 pub fn no_memory_allocations() {
     use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day04_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         test_part_one!(real_input => 569);
         test_part_two!(real_input => 936);
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 0);
 }
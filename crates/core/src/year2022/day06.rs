@@ -37,10 +37,11 @@ pub fn tests() {
 
     #[cfg(feature = "count-allocations")]
     {
-        let allocations = allocation_counter::count(|| {
+        let allocations = allocation_counter::measure(|| {
             test_part_one!(real_input => 1109);
             test_part_two!(real_input => 3965);
-        });
+        })
+        .count_total;
         assert_eq!(allocations, 0);
     }
 }
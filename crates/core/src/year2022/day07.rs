@@ -124,9 +124,10 @@ $ ls
 pub fn no_memory_allocations() {
     use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day07_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         test_part_one!(real_input => 1_428_881);
         test_part_two!(real_input => 10_475_598);
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 0);
 }
@@ -10,6 +10,7 @@ struct Device {
 
 impl Device {
     const NUM_PIXELS: usize = 240;
+    const SCREEN_WIDTH: usize = 40;
 
     const fn new() -> Self {
         Self {
@@ -21,7 +22,7 @@ impl Device {
     }
 
     fn on_cycle(&mut self, value_to_add: i32) {
-        if ((self.cycle - 1) % 40).abs_diff(self.register_x) <= 1 {
+        if ((self.cycle - 1) % Self::SCREEN_WIDTH as i32).abs_diff(self.register_x) <= 1 {
             self.screen[(self.cycle - 1) as usize] = true;
         }
 
@@ -54,7 +55,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     if input.is_part_one() {
         Ok(device.accumulated_signal_strength.to_string())
     } else {
-        recognize(&device.screen)
+        recognize(&device.screen, Device::SCREEN_WIDTH)
     }
 }
 
@@ -219,12 +220,14 @@ noop";
 #[test]
 pub fn single_to_string_memory_allocation() {
     let real_input = include_str!("day10_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         assert!(solve(&Input::part_one(real_input)).is_ok());
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 1);
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         assert!(solve(&Input::part_two(real_input)).is_ok());
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 1);
 }
@@ -1,7 +1,10 @@
 use std::collections::VecDeque;
 
 #[cfg(feature = "visualization")]
-use svgplot::{Coordinate, SvgColor, SvgImage, SvgPath, SvgScript, SvgShape, SvgStrokeLinecap};
+use svgplot::{
+    Coordinate, SvgAnimate, SvgCalcMode, SvgColor, SvgImage, SvgPath, SvgSet, SvgShape,
+    SvgStrokeLinecap,
+};
 
 use crate::input::Input;
 
@@ -88,40 +91,20 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     let mut svg = SvgImage::new().view_box((0, 0, graph.width as i64, graph.height as i64));
     #[cfg(feature = "visualization")]
     let mut current_render_step = 0;
+    // Path data of the circles at, and the paths taken to, the positions reached in each step:
     #[cfg(feature = "visualization")]
-    let mut circles_render_script = String::from("const circlesPerStep = ['");
+    let mut circles_per_step = vec![String::new()];
     #[cfg(feature = "visualization")]
-    let mut path_render_script = String::from("const pathsPerStep = ['");
+    let mut paths_per_step = vec![String::new()];
+
+    #[cfg(feature = "visualization")]
+    let mut frame_path = SvgShape::new();
+    #[cfg(feature = "visualization")]
+    let mut frame_circles = SvgShape::new();
 
     #[cfg(feature = "visualization")]
     {
-        for draw_height in 0..26 {
-            let mut shape = SvgShape::new();
-            let hue = (f64::from(draw_height)).mul_add(-10., 225.);
-            for x in 0..graph.width {
-                for y in 0..graph.height {
-                    let height = graph.height_at(x, y);
-                    if height == draw_height {
-                        shape = shape
-                            .move_to_absolute(x as i32, y as i32)
-                            .line_to_relative(1, 0)
-                            .line_to_relative(0, 1)
-                            .line_to_relative(-1, 0)
-                            .close();
-                    }
-                }
-            }
-            if !shape.is_empty() {
-                svg.add(
-                    SvgPath {
-                        shape,
-                        ..Default::default()
-                    }
-                    .title(format!("Elevation: {draw_height}"))
-                    .fill(SvgColor::Hsl(hue, 70, 40)),
-                );
-            }
-        }
+        render_elevations(&mut svg, &mut graph);
 
         if input.is_part_one() {
             svg.add(
@@ -165,12 +148,14 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                 #[cfg(feature = "visualization")]
                 {
                     if new_cost != current_render_step {
-                        path_render_script.push_str("', '");
-                        circles_render_script.push_str("', '");
+                        input.emit_frame(|| render_frame(&mut graph, &frame_path, &frame_circles))?;
+                        frame_circles = SvgShape::new();
+                        paths_per_step.push(String::new());
+                        circles_per_step.push(String::new());
                         current_render_step = new_cost;
                     }
                     let circle_radius = 0.4;
-                    circles_render_script.push_str(
+                    circles_per_step[current_render_step as usize].push_str(
                         &SvgShape::new()
                             .circle_absolute(
                                 new_pos.0 as f64 + 0.5,
@@ -179,37 +164,70 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                             )
                             .data_string(),
                     );
-                    path_render_script.push_str(
+                    paths_per_step[current_render_step as usize].push_str(
                         &SvgShape::at(new_pos.0 as f64 + 0.5, new_pos.1 as f64 + 0.5)
                             .line_to_relative(f64::from(-dx), f64::from(-dy))
                             .data_string(),
                     );
+                    if input.renders_frames() {
+                        frame_circles = frame_circles.circle_absolute(
+                            new_pos.0 as f64 + 0.5,
+                            new_pos.1 as f64 + 0.5,
+                            circle_radius,
+                        );
+                        frame_path = frame_path
+                            .move_to_absolute(new_pos.0 as f64 + 0.5, new_pos.1 as f64 + 0.5)
+                            .line_to_relative(f64::from(-dx), f64::from(-dy));
+                    }
                 }
 
                 if at_goal {
                     #[cfg(feature = "visualization")]
                     {
-                        let visited_path_id = svg.add_with_id(
+                        input.emit_frame(|| render_frame(&mut graph, &frame_path, &frame_circles))?;
+
+                        // Animate using SMIL, so that the image is animated by itself and
+                        // can be stepped through by pausing and seeking its timeline.
+                        let step_duration = 100;
+                        for (step, path) in paths_per_step.iter().enumerate() {
+                            if !path.is_empty() {
+                                svg.add(
+                                    SvgPath::default()
+                                        .stroke(SvgColor::Rgb(255, 255, 255))
+                                        .stroke_width(0.2)
+                                        .stroke_linecap(SvgStrokeLinecap::Round)
+                                        .animate(
+                                            SvgSet::new("d", path)
+                                                .begin((step * step_duration) as f64)
+                                                .freeze(),
+                                        ),
+                                );
+                            }
+                        }
+                        svg.add(
                             SvgPath::default()
-                                .stroke(SvgColor::Rgb(255, 255, 255))
-                                .stroke_width(0.2)
-                                .stroke_linecap(SvgStrokeLinecap::Round),
+                                .fill(SvgColor::Rgb(255, 255, 255))
+                                .animate(
+                                    SvgAnimate::new("d")
+                                        .values(circles_per_step.iter().map(|circles| {
+                                            // Empty path data would be an invalid value:
+                                            if circles.is_empty() {
+                                                "M 0 0"
+                                            } else {
+                                                circles.as_str()
+                                            }
+                                        }))
+                                        .calc_mode(SvgCalcMode::Discrete)
+                                        .duration((circles_per_step.len() * step_duration) as f64)
+                                        .freeze(),
+                                ),
                         );
-                        let circles_path_id =
-                            svg.add_with_id(SvgPath::default().fill(SvgColor::Rgb(255, 255, 255)));
-
-                        circles_render_script.push_str("'];");
-                        path_render_script.push_str(&format!("'];\n window.onNewStep = (step) => {{\n\
-                                                              document.getElementById('{circles_path_id}').setAttribute('d', circlesPerStep[step]);\n\
-                                                              const pathData = pathsPerStep.slice(0, step+1).join('');\n\
-                                                              document.getElementById('{visited_path_id}').setAttribute('d', pathData);\n\
-                                                             }}"));
-                        svg.add(SvgScript::new(format!(
-                            "{circles_render_script}{path_render_script}"
-                        )));
                         input.rendered_svg.replace(
                             svg.data_attribute("steps".to_string(), format!("{new_cost}"))
-                                .data_attribute("step-duration".to_string(), format!("{}", 100))
+                                .data_attribute(
+                                    "step-duration".to_string(),
+                                    format!("{step_duration}"),
+                                )
                                 .to_svg_string(),
                         );
                     }
@@ -222,6 +240,56 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     Err("No solution found".to_string())
 }
 
+#[cfg(feature = "visualization")]
+fn render_elevations(svg: &mut SvgImage, graph: &mut Graph) {
+    for draw_height in 0..26 {
+        let mut shape = SvgShape::new();
+        let hue = (f64::from(draw_height)).mul_add(-10., 225.);
+        for x in 0..graph.width {
+            for y in 0..graph.height {
+                let height = graph.height_at(x, y);
+                if height == draw_height {
+                    shape = shape
+                        .move_to_absolute(x as i32, y as i32)
+                        .line_to_relative(1, 0)
+                        .line_to_relative(0, 1)
+                        .line_to_relative(-1, 0)
+                        .close();
+                }
+            }
+        }
+        if !shape.is_empty() {
+            svg.add(
+                SvgPath {
+                    shape,
+                    ..Default::default()
+                }
+                .title(format!("Elevation: {draw_height}"))
+                .fill(SvgColor::Hsl(hue, 70, 40)),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "visualization")]
+fn render_frame(graph: &mut Graph, visited_path: &SvgShape, circles: &SvgShape) -> String {
+    let mut svg = SvgImage::new().view_box((0, 0, graph.width as i64, graph.height as i64));
+    render_elevations(&mut svg, graph);
+    svg.add(
+        SvgPath::default()
+            .shape(visited_path.clone())
+            .stroke(SvgColor::Rgb(255, 255, 255))
+            .stroke_width(0.2)
+            .stroke_linecap(SvgStrokeLinecap::Round),
+    );
+    svg.add(
+        SvgPath::default()
+            .shape(circles.clone())
+            .fill(SvgColor::Rgb(255, 255, 255)),
+    );
+    svg.to_svg_string()
+}
+
 #[test]
 pub fn tests() {
     use crate::input::{test_part_one, test_part_two};
@@ -234,3 +302,60 @@ pub fn tests() {
     test_part_one!(real_input => 528);
     test_part_two!(real_input => 522);
 }
+
+#[cfg(feature = "visualization")]
+#[test]
+pub fn frames() {
+    use std::ops::ControlFlow;
+
+    let test_input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+    let frames = std::cell::RefCell::new(Vec::new());
+    let listener = |frame: String| {
+        frames.borrow_mut().push(frame);
+        ControlFlow::Continue(())
+    };
+    let result = crate::solve_with_frame_listener(2022, 12, 1, test_input, &listener);
+    assert!(result.is_ok());
+    let frames = frames.take();
+    // One frame per step of the 31 steps, plus the final one:
+    assert_eq!(frames.len(), 32);
+    assert!(frames.iter().all(|frame| frame.starts_with("<svg")));
+
+    let frame_count = std::cell::Cell::new(0);
+    let progress = || {
+        frame_count.set(frame_count.get() + 1);
+        ControlFlow::Continue(())
+    };
+    let listener = crate::FrameListener::Progress(&progress);
+    let result = crate::solve_visualized(2022, 12, 1, test_input, Some(listener));
+    assert!(result.is_ok());
+    assert_eq!(frame_count.get(), 32);
+
+    // Solving stops when the listener asks for it:
+    frame_count.set(0);
+    let stopping_progress = || {
+        frame_count.set(frame_count.get() + 1);
+        if frame_count.get() == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    };
+    let listener = crate::FrameListener::Progress(&stopping_progress);
+    let result = crate::solve_visualized(2022, 12, 1, test_input, Some(listener));
+    assert_eq!(result.err(), Some("Stopped by the frame listener".to_string()));
+    assert_eq!(frame_count.get(), 3);
+}
+
+/// Compare the visualization of the example with the one in `day12_golden.svg`, which can be
+/// updated by running `cargo run --features visualization 2022 12 1` with the example as input.
+#[cfg(feature = "visualization")]
+#[test]
+pub fn golden_svg() {
+    #![allow(clippy::unwrap_used)]
+    let test_input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+    let svg = crate::solve_visualized(2022, 12, 1, test_input, None)
+        .unwrap()
+        .svg;
+    svgplot::assert_matches_golden(&svg, include_str!("day12_golden.svg"));
+}
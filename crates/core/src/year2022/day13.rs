@@ -200,9 +200,10 @@ pub fn troublesome_packet() {
 pub fn no_memory_allocations() {
     use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day13_input.txt");
-    let allocations = allocation_counter::count(|| {
+    let allocations = allocation_counter::measure(|| {
         test_part_one!(real_input => 4821);
         test_part_two!(real_input => 21_890);
-    });
+    })
+    .count_total;
     assert_eq!(allocations, 0);
 }
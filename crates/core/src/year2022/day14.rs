@@ -1,10 +1,15 @@
 use crate::common::tuple_window_iterator::TupleWindowIteratorExt;
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 
 struct Grid {
     data: Vec<bool>,
     highest_y_coordinate: usize,
     sand_count: usize,
+    /// Positions of sand placed since last painted.
+    #[cfg(feature = "painter")]
+    placed_sand: Vec<(i32, i32)>,
 }
 
 impl Grid {
@@ -47,9 +52,60 @@ impl Grid {
             data,
             highest_y_coordinate: highest_y_coordinate as usize,
             sand_count: 0,
+            #[cfg(feature = "painter")]
+            placed_sand: Vec::new(),
         })
     }
 
+    /// The area painted, as (left x, width, height), which is where sand can end up
+    /// when falling from (500, 0).
+    #[cfg(feature = "painter")]
+    const fn painted_area(&self) -> (i32, i32, i32) {
+        let height = self.highest_y_coordinate as i32 + 3;
+        (500 - height, 2 * height + 1, height)
+    }
+
+    #[cfg(feature = "painter")]
+    fn paint_cell(&self, painter: &mut dyn Painter, x: i32, y: i32) {
+        let (left_x, width, height) = self.painted_area();
+        painter.fill_rect(
+            f64::from(x - left_x) / f64::from(width),
+            f64::from(y) / f64::from(height),
+            1. / f64::from(width),
+            1. / f64::from(height),
+        );
+    }
+
+    #[cfg(feature = "painter")]
+    fn paint_rocks(&self, painter: &mut dyn Painter, with_floor: bool) {
+        let (left_x, width, height) = self.painted_area();
+        painter.set_aspect_ratio(width, height);
+        painter.clear();
+        painter.fill_style_rgb(0x8b, 0x5a, 0x2b);
+        for y in 0..height {
+            for x in left_x..left_x + width {
+                if !self.is_free(x, y) || (with_floor && y == height - 1) {
+                    self.paint_cell(painter, x, y);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "painter")]
+    fn paint_placed_sand(&mut self, input: &Input) {
+        if self.placed_sand.is_empty() {
+            return;
+        }
+        let placed_sand = std::mem::take(&mut self.placed_sand);
+        input.paint_frame(|painter| {
+            painter.fill_style_rgb(0xf4, 0xd0, 0x3f);
+            for &(x, y) in &placed_sand {
+                self.paint_cell(painter, x, y);
+            }
+            painter.status_text(&format!("{} units of sand", self.sand_count));
+        });
+    }
+
     fn is_free(&self, x: i32, y: i32) -> bool {
         if x < 0 || y < 0 || x >= Self::SQUARE_WIDTH as i32 || y >= Self::SQUARE_WIDTH as i32 {
             return false;
@@ -61,10 +117,12 @@ impl Grid {
         if !(x < 0 || y < 0 || x >= Self::SQUARE_WIDTH as i32 || y >= Self::SQUARE_WIDTH as i32) {
             self.data[y as usize * Self::SQUARE_WIDTH + x as usize] = true;
             self.sand_count += 1;
+            #[cfg(feature = "painter")]
+            self.placed_sand.push((x, y));
         }
     }
 
-    fn fill_part_1(&mut self) {
+    fn fill_part_1(&mut self, _input: &Input) {
         let (mut x, mut y) = (500, 0);
         'outer: while y != self.highest_y_coordinate as i32 {
             for dx in [0, -1, 1] {
@@ -75,15 +133,22 @@ impl Grid {
                 }
             }
             self.set_sand(x, y);
+            #[cfg(feature = "painter")]
+            self.paint_placed_sand(_input);
             (x, y) = (500, 0);
         }
     }
 
-    fn fill_part_2(&mut self, x: i32, y: i32) {
+    fn fill_part_2(&mut self, x: i32, y: i32, _input: &Input) {
         if self.is_free(x, y) && y < self.highest_y_coordinate as i32 + 2 {
             self.set_sand(x, y);
+            // Paint in batches, as the whole area below the source is filled.
+            #[cfg(feature = "painter")]
+            if self.placed_sand.len() >= 100 {
+                self.paint_placed_sand(_input);
+            }
             for dx in [0, -1, 1] {
-                self.fill_part_2(x + dx, y + 1);
+                self.fill_part_2(x + dx, y + 1, _input);
             }
         }
     }
@@ -91,11 +156,15 @@ impl Grid {
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let mut grid = Grid::parse(input.text)?;
+    #[cfg(feature = "painter")]
+    input.paint_frame(|painter| grid.paint_rocks(painter, input.is_part_two()));
     if input.is_part_one() {
-        grid.fill_part_1();
+        grid.fill_part_1(input);
     } else {
-        grid.fill_part_2(500, 0);
+        grid.fill_part_2(500, 0, input);
     }
+    #[cfg(feature = "painter")]
+    grid.paint_placed_sand(input);
     Ok(grid.sand_count)
 }
 
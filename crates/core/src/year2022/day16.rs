@@ -18,7 +18,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
                     distances
                         .iter()
                         .copied()
-                        .filter(|&distance| distance != 0)
+                        .filter(|&distance| distance != 0 && distance != usize::MAX)
                         .min()
                         .unwrap_or(usize::MAX - 1)
                 })
@@ -167,7 +167,12 @@ fn parse(input: &str) -> Option<(Vec<Vec<usize>>, Vec<usize>)> {
     //   - If valve_idx has a zero flow: usize::MAX
     //   - If valve_idx has a non-zero flow: index into flows
     let mut nonzero = vec![usize::MAX; flow_rates.len()];
-    nonzero[*name_to_valve_idx.get("AA")?] = 0;
+    let start_idx = *name_to_valve_idx.get("AA")?;
+    if flow_rates[start_idx] != 0 {
+        // The start valve is flow index 0, which is never opened.
+        return None;
+    }
+    nonzero[start_idx] = 0;
 
     for (valve_idx, &flow_rate) in flow_rates.iter().enumerate() {
         if flow_rate != 0 {
@@ -220,7 +225,7 @@ struct SearchState {
 
 #[test]
 pub fn tests() {
-    use crate::input::{test_part_one, test_part_two};
+    use crate::input::{test_part_one, test_part_one_error, test_part_two};
 
     let test_input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
@@ -344,4 +349,13 @@ Valve CY has flow rate=10; tunnels lead to valves CK
 Valve CZ has flow rate=10; tunnels lead to valves CK";
     test_part_one!(test_input => 2400);
     test_part_two!(test_input => 3680);
+
+    // Found when fuzzing - a start valve with a flow rate and an unreachable valve:
+    test_part_one_error!("Valve AA has flow rate=5; tunnel leads to valve BB
+Valve BB has flow rate=3; tunnel leads to valve AA" => "Invalid input");
+    let test_input = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=3; tunnel leads to valve AA
+Valve CC has flow rate=4; tunnel leads to valve DD
+Valve DD has flow rate=0; tunnel leads to valve CC";
+    test_part_one!(test_input => 84);
 }
@@ -2,6 +2,8 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let target_rocks_count = input.part_values(2022, 1_000_000_000_000_u64);
@@ -31,6 +33,12 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                 rock_left_x = pushed_x;
             }
 
+            #[cfg(feature = "painter")]
+            input.paint_frame(|painter| {
+                grid.paint(painter, rock_bitmask, rock_left_x as usize, rock_bottom_y);
+                painter.status_text(&format!("Rock {}", current_rock_count + 1));
+            });
+
             if rock_bottom_y != 0
                 && grid.can_place_rock(rock_bitmask, rock_left_x as usize, rock_bottom_y - 1)
             {
@@ -112,6 +120,65 @@ impl Grid {
         }
     }
 
+    /// The number of rows shown when painting, from the top of the falling rock and down.
+    #[cfg(feature = "painter")]
+    const PAINTED_HEIGHT: usize = 40;
+
+    #[cfg(feature = "painter")]
+    fn paint(
+        &self,
+        painter: &mut dyn Painter,
+        rock: Rock,
+        left_edge_x: usize,
+        bottom_edge_y: usize,
+    ) {
+        // Surrounded by walls, with the floor at y=-1:
+        let (width, height) = (Self::WIDTH + 2, Self::PAINTED_HEIGHT);
+        let top_y = (bottom_edge_y + 4).max(height - 1);
+        let (cell_width, cell_height) = (1. / width as f64, 1. / height as f64);
+        let paint_cell = |painter: &mut dyn Painter, x: usize, y: usize| {
+            painter.fill_rect(
+                x as f64 * cell_width,
+                (top_y - y) as f64 * cell_height,
+                cell_width,
+                cell_height,
+            );
+        };
+
+        painter.set_aspect_ratio(width as i32, height as i32);
+        painter.clear();
+        painter.fill_style_rgb(0x50, 0x50, 0x50);
+        for y in (top_y + 1 - height)..=top_y {
+            paint_cell(painter, 0, y);
+            paint_cell(painter, width - 1, y);
+            if y == 0 {
+                for x in 1..width - 1 {
+                    paint_cell(painter, x, y);
+                }
+            }
+        }
+
+        painter.fill_style_rgb(0x8b, 0x5a, 0x2b);
+        for y in (top_y + 1 - height).max(1)..=top_y {
+            for x in 0..Self::WIDTH {
+                if self.data[y - 1] & (1 << x) != 0 {
+                    paint_cell(painter, x + 1, y);
+                }
+            }
+        }
+
+        painter.fill_style_rgb(0xfd, 0xdb, 0x27);
+        for bit in 0..16 {
+            if rock & (1 << bit) != 0 {
+                paint_cell(
+                    painter,
+                    left_edge_x + bit % 4 + 1,
+                    bottom_edge_y + bit / 4 + 1,
+                );
+            }
+        }
+    }
+
     fn can_place_rock(&self, rock: Rock, left_edge_x: usize, bottom_edge_y: usize) -> bool {
         rock & ((u16::from(self.data[bottom_edge_y] >> left_edge_x) & 0b1111)
             + ((u16::from(self.data[bottom_edge_y + 1] >> left_edge_x) & 0b1111) << 4)
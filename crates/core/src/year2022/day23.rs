@@ -1,12 +1,13 @@
 #[cfg(feature = "visualization")]
-#[cfg(not(feature = "simd"))]
+#[cfg(any(not(feature = "simd"), feature = "fuzzing"))]
 use svgplot::{SvgImage, SvgRect, SvgScript, SvgStyle};
 
-#[cfg(not(feature = "simd"))]
+#[cfg(any(not(feature = "simd"), feature = "fuzzing"))]
 use crate::input::Input;
 
-#[cfg(not(feature = "simd"))]
-pub fn solve(input: &Input) -> Result<usize, String> {
+// Also built with the simd feature when fuzzing, to test the simd solution against:
+#[cfg(any(not(feature = "simd"), feature = "fuzzing"))]
+pub fn solve_scalar(input: &Input) -> Result<usize, String> {
     const DIRECTIONS: [(i16, i16); 8] = [
         // NW
         (-1, -1),
@@ -138,6 +139,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
         #[cfg(feature = "visualization")]
         {
             elf_positions_per_step.push(elves.clone());
+            input.emit_frame(|| render_frame(&elves))?;
 
             if num_moves == 0 || (input.is_part_one() && round == 9) {
                 let mut svg = SvgImage::new();
@@ -213,7 +215,7 @@ window.onNewStep = (step) => {{\n\
             }
         }
 
-        if num_moves == 0 {
+        if num_moves == 0 && input.is_part_two() {
             return Ok(round + 1);
         }
     }
@@ -233,6 +235,43 @@ window.onNewStep = (step) => {{\n\
     Ok(rectangle_size - elves.len())
 }
 
+#[cfg(feature = "visualization")]
+#[cfg(any(not(feature = "simd"), feature = "fuzzing"))]
+fn render_frame(elves: &[(i16, i16)]) -> String {
+    let (min_x, max_x, min_y, max_y) =
+        elves
+            .iter()
+            .fold((i16::MAX, i16::MIN, i16::MAX, i16::MIN), |acc, e| {
+                (
+                    acc.0.min(e.0),
+                    acc.1.max(e.0),
+                    acc.2.min(e.1),
+                    acc.3.max(e.1),
+                )
+            });
+    let mut svg = SvgImage::new()
+        .view_box((
+            i64::from(min_x),
+            i64::from(min_y),
+            i64::from(max_x + 1 - min_x),
+            i64::from(max_y + 1 - min_y),
+        ))
+        .style("background: black;");
+    svg.add(SvgStyle::new("rect { fill: #00B1D2; }".to_string()));
+    for elf in elves {
+        svg.add(
+            SvgRect::default()
+                .x(f64::from(elf.0))
+                .y(f64::from(elf.1))
+                .width(1)
+                .height(1),
+        );
+    }
+    svg.to_svg_string()
+}
+
+#[cfg(not(feature = "simd"))]
+pub use self::solve_scalar as solve;
 #[cfg(feature = "simd")]
 pub use super::day23_simd::solve;
 
@@ -251,6 +290,9 @@ pub fn tests() {
     test_part_one!(test_input => 110);
     test_part_two!(test_input => 20);
 
+    // Elves stopping to move before the tenth round:
+    test_part_one!(".##..##.####" => 44);
+
     let real_input = include_str!("day23_input.txt");
     test_part_one!(real_input => 3920);
     test_part_two!(real_input => 889);
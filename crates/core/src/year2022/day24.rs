@@ -1,4 +1,6 @@
 use crate::input::Input;
+#[cfg(feature = "painter")]
+use crate::painter::Painter;
 
 #[cfg(feature = "visualization")]
 use super::day24_renderer::Renderer;
@@ -40,9 +42,14 @@ pub fn solve(input: &Input) -> Result<i32, String> {
             remaining_trips -= 1;
 
             #[cfg(feature = "visualization")]
-            renderer
-                .reachable_per_step
-                .push((reachable.clone(), !heading_down));
+            {
+                renderer
+                    .reachable_per_step
+                    .push((reachable.clone(), !heading_down));
+                input.emit_frame(|| Renderer::frame_svg(&valley, &reachable))?;
+            }
+            #[cfg(feature = "painter")]
+            input.paint_frame(|painter| paint(painter, &valley, &reachable));
 
             if remaining_trips == 0 {
                 #[cfg(feature = "visualization")]
@@ -70,14 +77,62 @@ pub fn solve(input: &Input) -> Result<i32, String> {
         }
 
         #[cfg(feature = "visualization")]
-        renderer
-            .reachable_per_step
-            .push((reachable.clone(), heading_down));
+        {
+            renderer
+                .reachable_per_step
+                .push((reachable.clone(), heading_down));
+            input.emit_frame(|| Renderer::frame_svg(&valley, &reachable))?;
+        }
+        #[cfg(feature = "painter")]
+        input.paint_frame(|painter| paint(painter, &valley, &reachable));
     }
 
     Err(format!("No solution found in {MAX_STEPS} minutes"))
 }
 
+/// Draw the current blizzard and reachable positions on a painter.
+#[cfg(feature = "painter")]
+fn paint(painter: &mut dyn Painter, valley: &Valley, reachable: &[u64]) {
+    painter.set_aspect_ratio(valley.width as i32, valley.height as i32);
+    painter.clear();
+    let cell_width = 1. / valley.width as f64;
+    let cell_height = 1. / valley.height as f64;
+
+    painter.fill_style_rgb(0, 0, 0);
+    painter.fill_rect(0., 0., 1., 1.);
+
+    for (x, &reachable_column) in reachable.iter().enumerate() {
+        let free = valley.blizzards_up[x]
+            & valley.blizzards_down[x]
+            & valley.blizzards_right[x]
+            & valley.blizzards_left[x];
+        for y in 0..valley.height {
+            let (left, top) = (x as f64 * cell_width, y as f64 * cell_height);
+            if free & (1 << y) == 0 {
+                painter.fill_style_rgb(0x00, 0xB1, 0xD2);
+                painter.fill_rect(
+                    left + cell_width / 4.,
+                    top + cell_height / 4.,
+                    cell_width / 2.,
+                    cell_height / 2.,
+                );
+            }
+            if reachable_column & (1 << y) != 0 {
+                painter.fill_style_rgb(0xfd, 0xdb, 0x27);
+                painter.begin_path();
+                painter.arc(
+                    left + cell_width / 2.,
+                    top + cell_height / 2.,
+                    cell_height / 4.,
+                    0.,
+                    std::f64::consts::TAU,
+                );
+                painter.fill();
+            }
+        }
+    }
+}
+
 pub struct Valley {
     pub width: usize,
     pub height: usize,
@@ -146,3 +201,21 @@ pub fn tests() {
     test_part_one!(real_input => 242);
     test_part_two!(real_input => 720);
 }
+
+/// Compare the visualization of the example with the one in `day24_golden.svg`, which can be
+/// updated by running `cargo run --features visualization 2022 24 1` with the example as input.
+#[cfg(feature = "visualization")]
+#[test]
+pub fn golden_svg() {
+    #![allow(clippy::unwrap_used)]
+    let test_input = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+    let svg = crate::solve_visualized(2022, 24, 1, test_input, None)
+        .unwrap()
+        .svg;
+    svgplot::assert_matches_golden(&svg, include_str!("day24_golden.svg"));
+}
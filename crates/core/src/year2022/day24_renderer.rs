@@ -54,7 +54,7 @@ impl Renderer {
             (&valley.blizzards_right, -1., -1., 1., "right"),
             (&valley.blizzards_left, 1., 1., 0., "left"),
         ] {
-            for (_x, col) in blizzard.iter().enumerate() {
+            for col in blizzard.iter() {
                 let mut group = SvgGroup::new().class(format!("blizzard blizzard-{dir}"));
                 for y in 0..valley.height {
                     if (col & (1 << y)) == 0 {
@@ -80,6 +80,50 @@ impl Renderer {
         }
     }
 
+    /// Render the current blizzard and reachable positions as a standalone frame.
+    pub fn frame_svg(valley: &Valley, reachable: &[u64]) -> String {
+        let mut svg = SvgImage::new().style("background:black").view_box((
+            0,
+            0,
+            valley.width as i64,
+            valley.height as i64,
+        ));
+
+        let mut blizzards = SvgShape::new();
+        let mut reachable_circles = SvgShape::new();
+        for (x, &reachable_column) in reachable.iter().enumerate() {
+            let free = valley.blizzards_up[x]
+                & valley.blizzards_down[x]
+                & valley.blizzards_right[x]
+                & valley.blizzards_left[x];
+            for y in 0..valley.height {
+                if free & (1 << y) == 0 {
+                    blizzards = blizzards
+                        .move_to_absolute(x as f64 + 0.25, y as f64 + 0.25)
+                        .line_to_relative(0.5, 0.)
+                        .line_to_relative(0., 0.5)
+                        .line_to_relative(-0.5, 0.)
+                        .close();
+                }
+                if reachable_column & (1 << y) != 0 {
+                    reachable_circles =
+                        reachable_circles.circle_absolute(x as f64 + 0.5, y as f64 + 0.5, 0.25);
+                }
+            }
+        }
+        svg.add(
+            SvgPath::default()
+                .shape(blizzards)
+                .fill(SvgColor::Rgb(0x00, 0xB1, 0xD2)),
+        );
+        svg.add(
+            SvgPath::default()
+                .shape(reachable_circles)
+                .fill(SvgColor::Rgb(0xfd, 0xdb, 0x27)),
+        );
+        svg.to_svg_string()
+    }
+
     pub fn final_svg(mut self, valley: &Valley, minute: usize) -> String {
         let step_duration = 1000;
         let animation_duration = step_duration - 200;
@@ -18,6 +18,15 @@ void test(uint16_t year, uint8_t day, uint8_t part, std::string input) {
     //printf("%d-%d-%d: Input='%s' -> ok=%s, output='%s'\n", year, day, part, input, ok ? "true" : "false", message);
 }
 
+void test_both(uint16_t year, uint8_t day, std::string input) {
+    try {
+        aoc::Answers answers = aoc::solve_both(year, day, input);
+        std::cout << year << '-' << (int) day << ": Input='" << input << "' -> part_one='" << std::string(answers.part_one) << "', part_two='" << std::string(answers.part_two) << "'" << std::endl;
+    } catch (std::exception& e) {
+        std::cout << year << '-' << (int) day << ": Input='" << input << "' -> error='" << e.what() << "'" << std::endl;
+    }
+}
+
 int main() {
     test(2019, 1, 1, "14");
     test(2019, 1, 1, "hej");
@@ -26,5 +35,26 @@ int main() {
     // test(2019, 1, 1, NULL);
     test(2019, 1, 1, "\xc3\x28");
     test(2021, 7, 2, "16,1,2,0,4,2,7,1,2,14");
+    test(2019, 26, 1, "14");
+    test(2019, 1, 3, "14");
+
+    test_both(2019, 1, "14");
+    test_both(2019, 1, "hej");
+
+    rust::Vec<aoc::Puzzle> puzzles = aoc::list_puzzles();
+    std::cout << "Supported puzzles: " << puzzles.size() << ", first: " << puzzles.front().year << '-' << (int) puzzles.front().day << ", last: " << puzzles.back().year << '-' << (int) puzzles.back().day << std::endl;
+
+    if (aoc::has_visualization()) {
+        std::string svg(aoc::visualize(2022, 12, 1, "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi"));
+        std::cout << "Visualization starts with: " << svg.substr(0, 4) << std::endl;
+    } else {
+        try {
+            aoc::visualize(2022, 12, 1, "Sabqponm");
+        } catch (std::exception& e) {
+            std::cout << "Visualization: " << e.what() << std::endl;
+        }
+    }
+
+    std::cout << "Version is non-empty: " << (aoc::version().size() > 0 ? "true" : "false") << std::endl;
     return 0;
 }
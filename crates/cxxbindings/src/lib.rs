@@ -1,11 +1,35 @@
+use advent_of_code::{check_puzzle, supported_puzzles};
+
 #[cxx::bridge(namespace = "aoc")]
 mod ffi {
     #![allow(clippy::items_after_statements)]
+
+    /// A supported problem, as returned from list_puzzles().
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct Puzzle {
+        year: u16,
+        day: u8,
+    }
+
+    /// The answers to both parts of a problem, as returned from solve_both().
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Answers {
+        part_one: String,
+        part_two: String,
+    }
+
     extern "Rust" {
-        pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String>;
+        fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String>;
+        fn solve_both(year: u16, day: u8, input: &str) -> Result<Answers>;
+        fn visualize(year: u16, day: u8, part: u8, input: &str) -> Result<String>;
+        fn has_visualization() -> bool;
+        fn list_puzzles() -> Vec<Puzzle>;
+        fn version() -> &'static str;
     }
 }
 
+use ffi::{Answers, Puzzle};
+
 /// Returns the answer for the specified problem and input.
 ///
 /// Arguments:
@@ -17,14 +41,54 @@ mod ffi {
 /// Returns:
 /// The computed answer as text.
 ///
-/// Raises:
-/// ValueError: If the input was invalid.
-fn solve(
-    year: u16,
-    day: u8,
-    part: u8,
-    input: &str,
-    #[cfg(feature = "visualization")] painter: PainterRef,
-) -> Result<String, String> {
+/// Throws:
+/// rust::Error: If the problem is not supported or the input was invalid.
+fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
+    check_puzzle(year, day, part)?;
     advent_of_code::solve(year, day, part, input)
 }
+
+/// Returns the answers to both parts of the specified problem and input.
+///
+/// Throws:
+/// rust::Error: If the problem is not supported or the input was invalid.
+fn solve_both(year: u16, day: u8, input: &str) -> Result<Answers, String> {
+    Ok(Answers {
+        part_one: solve(year, day, 1, input)?,
+        part_two: solve(year, day, 2, input)?,
+    })
+}
+
+/// Returns a rendered SVG visualizing the solving of the specified problem and input.
+///
+/// Throws:
+/// rust::Error: If built without the visualization feature, the problem is
+/// not supported or the input was invalid.
+#[cfg(feature = "visualization")]
+fn visualize(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
+    check_puzzle(year, day, part)?;
+    advent_of_code::solve_visualized(year, day, part, input, None).map(|solution| solution.svg)
+}
+
+#[cfg(not(feature = "visualization"))]
+fn visualize(_year: u16, _day: u8, _part: u8, _input: &str) -> Result<String, String> {
+    Err("Built without the visualization feature".to_string())
+}
+
+/// Returns whether the library was built with the visualization feature,
+/// which is required by visualize().
+const fn has_visualization() -> bool {
+    cfg!(feature = "visualization")
+}
+
+/// Returns the supported problems, ordered by year and day.
+fn list_puzzles() -> Vec<Puzzle> {
+    supported_puzzles()
+        .map(|(year, day)| Puzzle { year, day })
+        .collect()
+}
+
+/// Returns the version of the library.
+const fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
@@ -0,0 +1,39 @@
+use axum::{
+    body::{Bytes, Full},
+    http::{Method, StatusCode, Uri},
+    response::Response,
+    Router,
+};
+
+use advent_of_code_api::{handle, Request};
+
+#[cfg(feature = "visualization")]
+mod visualize;
+
+/// The router of the server, with the shared API routes and the visualization WebSocket.
+pub fn app() -> Router {
+    let app = Router::new().fallback(handle_request);
+
+    #[cfg(feature = "visualization")]
+    let app = app.route(
+        "/visualize/:year/:day/:part",
+        axum::routing::get(visualize::handle_visualize),
+    );
+
+    app
+}
+
+async fn handle_request(method: Method, uri: Uri, body: String) -> Response<Full<Bytes>> {
+    #![allow(clippy::unwrap_used)]
+    let response = handle(&Request {
+        method: advent_of_code_api::Method::parse(method.as_str()),
+        path: uri.path(),
+        body: &body,
+    });
+
+    let mut builder = Response::builder().status(StatusCode::from_u16(response.status).unwrap());
+    for (name, value) in response.headers() {
+        builder = builder.header(name, value);
+    }
+    builder.body(Full::from(response.body)).unwrap()
+}
@@ -0,0 +1,146 @@
+use axum::{
+    extract::{
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        Path,
+    },
+    response::Response,
+};
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use tokio::sync::mpsc;
+
+use advent_of_code::solve_with_frame_listener;
+
+/// Close code used when solving fails, with the error message as close reason.
+const SOLVE_ERROR_CLOSE_CODE: u16 = 4000;
+
+/// Close code used when solving succeeds without emitting any frames, as the problem has no
+/// visualization support.
+const NO_FRAMES_CLOSE_CODE: u16 = 4001;
+
+/// The number of frames buffered before solving waits for them to be sent to the client.
+const FRAME_BUFFER_SIZE: usize = 16;
+
+/// The maximum length of a close reason, as specified by RFC 6455.
+const MAX_CLOSE_REASON_LENGTH: usize = 123;
+
+enum Event {
+    Frame(String),
+    Done {
+        result: Result<String, String>,
+        frame_count: usize,
+    },
+}
+
+/// Stream visualization frames over a WebSocket while solving.
+///
+/// The client sends the problem input as the first text message. Each
+/// intermediate frame is then sent as a text message containing a SVG
+/// document, followed by the final rendered SVG. The socket is closed with
+/// a normal close code on success, with code 4001 if no frames were emitted,
+/// or with code 4000 and the error message as reason if solving failed.
+///
+/// Solving waits while frames are not yet sent to a slow client, and stops
+/// if the client disconnects.
+pub async fn handle_visualize(
+    Path(params): Path<HashMap<String, String>>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    ws.on_upgrade(move |socket| stream_frames(socket, params))
+}
+
+async fn stream_frames(mut socket: WebSocket, params: HashMap<String, String>) {
+    let input = loop {
+        match socket.recv().await {
+            Some(Ok(Message::Text(input))) => break input,
+            Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+            _ => return,
+        }
+    };
+
+    let parsed_params = parse_params(&params);
+    let (year, day, part) = match parsed_params {
+        Ok(params) => params,
+        Err(error) => {
+            close_with(socket, SOLVE_ERROR_CLOSE_CODE, error).await;
+            return;
+        }
+    };
+
+    let (sender, mut receiver) = mpsc::channel(FRAME_BUFFER_SIZE);
+    tokio::task::spawn_blocking(move || {
+        let frame_count = Cell::new(0);
+        let frame_listener = |frame: String| {
+            frame_count.set(frame_count.get() + 1);
+            // The receiver is gone if the client disconnected, so stop solving:
+            match sender.blocking_send(Event::Frame(frame)) {
+                Ok(()) => ControlFlow::Continue(()),
+                Err(_) => ControlFlow::Break(()),
+            }
+        };
+        let result = solve_with_frame_listener(year, day, part, &input, &frame_listener);
+        let _ = sender.blocking_send(Event::Done {
+            result,
+            frame_count: frame_count.get(),
+        });
+    });
+
+    while let Some(event) = receiver.recv().await {
+        match event {
+            Event::Frame(frame) => {
+                if socket.send(Message::Text(frame)).await.is_err() {
+                    return;
+                }
+            }
+            Event::Done {
+                result: Ok(_),
+                frame_count: 0,
+            } => {
+                let reason = "No frames to visualize for this problem".to_string();
+                close_with(socket, NO_FRAMES_CLOSE_CODE, reason).await;
+                return;
+            }
+            Event::Done {
+                result: Ok(svg), ..
+            } => {
+                if socket.send(Message::Text(svg)).await.is_ok() {
+                    let _ = socket.send(Message::Close(None)).await;
+                }
+                return;
+            }
+            Event::Done {
+                result: Err(error), ..
+            } => {
+                close_with(socket, SOLVE_ERROR_CLOSE_CODE, error).await;
+                return;
+            }
+        }
+    }
+}
+
+fn parse_params(params: &HashMap<String, String>) -> Result<(u16, u8, u8), String> {
+    let param = |name: &str| params.get(name).map(String::as_str).unwrap_or_default();
+    Ok((
+        param("year").parse().map_err(|_| "Invalid year")?,
+        param("day").parse().map_err(|_| "Invalid day")?,
+        param("part").parse().map_err(|_| "Invalid part")?,
+    ))
+}
+
+async fn close_with(mut socket: WebSocket, code: u16, mut reason: String) {
+    if reason.len() > MAX_CLOSE_REASON_LENGTH {
+        let mut end = MAX_CLOSE_REASON_LENGTH;
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        reason.truncate(end);
+    }
+    let _ = socket
+        .send(Message::Close(Some(CloseFrame {
+            code,
+            reason: Cow::Owned(reason),
+        })))
+        .await;
+}
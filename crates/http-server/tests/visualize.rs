@@ -0,0 +1,73 @@
+//! Streams visualizations from the server running on a local port, using a WebSocket client.
+#![cfg(feature = "visualization")]
+#![allow(clippy::unwrap_used)]
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+fn start_server() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let server = axum::Server::from_tcp(listener)
+        .unwrap()
+        .serve(advent_of_code_server::app().into_make_service());
+    tokio::spawn(server);
+    address
+}
+
+/// The text messages received after sending the input, and the code and reason of the close frame if any.
+async fn visualize(address: &str, path: &str, input: &str) -> (Vec<String>, Option<(u16, String)>) {
+    let (mut socket, _) = connect_async(format!("ws://{address}{path}"))
+        .await
+        .unwrap();
+    socket.send(Message::Text(input.to_string())).await.unwrap();
+
+    let mut texts = Vec::new();
+    while let Some(message) = socket.next().await {
+        match message.unwrap() {
+            Message::Text(text) => texts.push(text),
+            Message::Close(frame) => {
+                let frame = frame.map(|frame| (frame.code.into(), frame.reason.into_owned()));
+                return (texts, frame);
+            }
+            _ => {}
+        }
+    }
+    panic!("Socket ended without a close frame");
+}
+
+#[tokio::test]
+async fn streams_frames() {
+    let address = start_server();
+
+    let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+    let (texts, close_frame) = visualize(&address, "/visualize/2022/12/1", input).await;
+    // One frame per step of the 31 steps, plus the final one, followed by the rendered SVG:
+    assert_eq!(texts.len(), 33);
+    assert!(texts.iter().all(|text| text.starts_with("<svg")));
+    assert_eq!(close_frame, None);
+}
+
+#[tokio::test]
+async fn closes_with_error() {
+    let address = start_server();
+
+    let (texts, close_frame) = visualize(&address, "/visualize/2022/12/1", "").await;
+    assert!(texts.is_empty());
+    assert_eq!(close_frame, Some((4000, "Empty input".to_string())));
+
+    let (texts, close_frame) = visualize(&address, "/visualize/2022/x/1", "14").await;
+    assert!(texts.is_empty());
+    assert_eq!(close_frame, Some((4000, "Invalid day".to_string())));
+}
+
+#[tokio::test]
+async fn closes_without_frames() {
+    let address = start_server();
+
+    let (texts, close_frame) = visualize(&address, "/visualize/2019/1/1", "14").await;
+    assert!(texts.is_empty());
+    assert_eq!(
+        close_frame,
+        Some((4001, "No frames to visualize for this problem".to_string()))
+    );
+}
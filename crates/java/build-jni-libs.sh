@@ -8,7 +8,9 @@ mkdir -p java-src/src/main/resources
 if [ "$AOC_BUILD_TYPE" = "debug" ]; then
     BUILD_COMMAND="build"
 elif [ "$AOC_BUILD_TYPE" = "release" ]; then
-    BUILD_COMMAND="build --release"
+    # Unwind instead of the workspace default of aborting on panic, so that
+    # panics can be caught and thrown as exceptions instead of killing the JVM:
+    BUILD_COMMAND="build --release --config profile.release.panic='unwind'"
 else
     echo "Unsupported build type: '$AOC_BUILD_TYPE'"
     exit 1
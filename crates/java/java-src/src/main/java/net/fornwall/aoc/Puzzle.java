@@ -0,0 +1,63 @@
+package net.fornwall.aoc;
+
+import java.util.Objects;
+
+/**
+ * A problem which can be solved, as returned by {@link Solver#supportedPuzzles()}.
+ */
+public final class Puzzle {
+
+    private final int year;
+    private final int day;
+
+    /**
+     * Creates a problem identifier.
+     *
+     * @param year the year of the problem, such as 2019
+     * @param day  the day of the problem, from 1 to 25
+     */
+    public Puzzle(int year, int day) {
+        this.year = year;
+        this.day = day;
+    }
+
+    /**
+     * Returns the year of the problem.
+     *
+     * @return the year, such as 2019
+     */
+    public int getYear() {
+        return year;
+    }
+
+    /**
+     * Returns the day of the problem.
+     *
+     * @return the day, from 1 to 25
+     */
+    public int getDay() {
+        return day;
+    }
+
+    @Override
+    public boolean equals(Object other) {
+        if (this == other) {
+            return true;
+        } else if (!(other instanceof Puzzle)) {
+            return false;
+        }
+        var puzzle = (Puzzle) other;
+        return year == puzzle.year && day == puzzle.day;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(year, day);
+    }
+
+    @Override
+    public String toString() {
+        return "Puzzle{year=" + year + ", day=" + day + "}";
+    }
+
+}
@@ -1,5 +1,11 @@
 package net.fornwall.aoc;
 
+import java.nio.ByteBuffer;
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+
 /**
  * A solver of <a href="https://adventofcode.com">Advent of Code</a> problems.
  * <p>
@@ -26,4 +32,66 @@ public final class Solver {
      */
     public static native String solve(int year, int day, int part, String input) throws SolverException;
 
+    /**
+     * Solve the specified problem with the given input as UTF-8 encoded bytes.
+     * <p>
+     * The remaining bytes of the buffer, from its position to its limit, are used as input.
+     * A direct buffer is read in place without being copied, which avoids copying large inputs.
+     * The position of the buffer is not changed.
+     *
+     * @param year  the year of the problem being solved
+     * @param day   the day of the problem being solved (1-25)
+     * @param part  the part of the problem being solved (1 or 2)
+     * @param input the UTF-8 encoded input to the problem
+     * @return the answer of the specified problem and input
+     * @throws SolverException in case of an error happened and the problem could not be solved
+     */
+    public static String solve(int year, int day, int part, ByteBuffer input) throws SolverException {
+        if (input == null) {
+            throw new SolverException(SolverException.Kind.INVALID_INPUT.name(), "Input is null");
+        } else if (input.isDirect()) {
+            return solveDirect(year, day, part, input, input.position(), input.remaining());
+        } else {
+            var bytes = new byte[input.remaining()];
+            input.duplicate().get(bytes);
+            return solve(year, day, part, new String(bytes, StandardCharsets.UTF_8));
+        }
+    }
+
+    /**
+     * Solve both parts of the specified problem with the given input.
+     *
+     * @param year  the year of the problem being solved
+     * @param day   the day of the problem being solved (1-25)
+     * @param input the input text to the problem
+     * @return an array with the answers to part 1 and part 2 of the specified problem and input
+     * @throws SolverException in case of an error happened and the problem could not be solved
+     */
+    public static native String[] solveBoth(int year, int day, String input) throws SolverException;
+
+    /**
+     * Returns the problems which can be solved.
+     *
+     * @return the supported problems, ordered by year and day
+     */
+    public static List<Puzzle> supportedPuzzles() {
+        var yearAndDays = supportedPuzzlesNative();
+        var puzzles = new ArrayList<Puzzle>(yearAndDays.length / 2);
+        for (var i = 0; i < yearAndDays.length; i += 2) {
+            puzzles.add(new Puzzle(yearAndDays[i], yearAndDays[i + 1]));
+        }
+        return Collections.unmodifiableList(puzzles);
+    }
+
+    /**
+     * Returns the version of the native library.
+     *
+     * @return the version, such as "2022.0.53"
+     */
+    public static native String version();
+
+    private static native String solveDirect(int year, int day, int part, ByteBuffer input, int offset, int length) throws SolverException;
+
+    private static native int[] supportedPuzzlesNative();
+
 }
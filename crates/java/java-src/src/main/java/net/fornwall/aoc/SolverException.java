@@ -7,8 +7,38 @@ package net.fornwall.aoc;
  */
 public class SolverException extends RuntimeException {
 
-    SolverException(String message) {
+    /**
+     * The reason for a problem not being solved.
+     */
+    public enum Kind {
+        /**
+         * The year, day or part does not specify a supported problem.
+         */
+        UNSUPPORTED_PUZZLE,
+        /**
+         * The input to the problem could not be solved.
+         */
+        INVALID_INPUT,
+        /**
+         * An unexpected error occurred in the native library.
+         */
+        INTERNAL,
+    }
+
+    private final Kind kind;
+
+    SolverException(String kind, String message) {
         super(message);
+        this.kind = Kind.valueOf(kind);
+    }
+
+    /**
+     * Returns the reason for the problem not being solved.
+     *
+     * @return the kind of error
+     */
+    public Kind getKind() {
+        return kind;
     }
 
 }
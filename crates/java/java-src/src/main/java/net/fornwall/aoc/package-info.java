@@ -1,4 +1,4 @@
 /**
- * This package contains a single {@link net.fornwall.aoc.Solver} class for solving <a href="https://adventofcode.com/">Advent of Code</a> problems.
+ * This package contains the {@link net.fornwall.aoc.Solver} class for solving <a href="https://adventofcode.com/">Advent of Code</a> problems.
  */
 package net.fornwall.aoc;
\ No newline at end of file
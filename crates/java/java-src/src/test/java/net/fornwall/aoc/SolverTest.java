@@ -3,6 +3,9 @@ package net.fornwall.aoc;
 import org.junit.jupiter.api.Assertions;
 import org.junit.jupiter.api.Test;
 
+import java.nio.ByteBuffer;
+import java.nio.charset.StandardCharsets;
+
 public class SolverTest {
 
     @Test
@@ -11,10 +14,44 @@ public class SolverTest {
         Assertions.assertEquals("2", answer);
     }
 
+    @Test
+    void testSolveBoth() {
+        var answers = Solver.solveBoth(2019, 1, "14");
+        Assertions.assertArrayEquals(new String[]{"2", "2"}, answers);
+    }
+
+    @Test
+    void testSolveByteBuffer() {
+        var bytes = "xx14".getBytes(StandardCharsets.UTF_8);
+        var direct = ByteBuffer.allocateDirect(bytes.length).put(bytes).position(2);
+        Assertions.assertEquals("2", Solver.solve(2019, 1, 1, direct));
+        Assertions.assertEquals(2, direct.position());
+
+        var heap = ByteBuffer.wrap(bytes).position(2);
+        Assertions.assertEquals("2", Solver.solve(2019, 1, 1, heap));
+    }
+
+    @Test
+    void testSupportedPuzzles() {
+        var puzzles = Solver.supportedPuzzles();
+        Assertions.assertEquals(new Puzzle(2015, 1), puzzles.get(0));
+        Assertions.assertTrue(puzzles.contains(new Puzzle(2022, 25)));
+    }
+
+    @Test
+    void testVersion() {
+        Assertions.assertFalse(Solver.version().isEmpty());
+    }
+
     @Test
     void testException() {
-        Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, -1, 1, "14"));
-        Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, 1, 1, "hello"));
+        var unsupported = Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, -1, 1, "14"));
+        Assertions.assertEquals(SolverException.Kind.UNSUPPORTED_PUZZLE, unsupported.getKind());
+        var invalidInput = Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, 1, 1, "hello"));
+        Assertions.assertEquals(SolverException.Kind.INVALID_INPUT, invalidInput.getKind());
+        var invalidUtf8 = ByteBuffer.allocateDirect(1).put((byte) 0xff).flip();
+        var invalidBytes = Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, 1, 1, invalidUtf8));
+        Assertions.assertEquals(SolverException.Kind.INVALID_INPUT, invalidBytes.getKind());
     }
 
 }
@@ -1,46 +1,201 @@
-#![allow(clippy::expect_used)]
-#![allow(clippy::panic)]
-use advent_of_code::solve;
-use jni::objects::{JClass, JString};
-use jni::sys::jstring;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use advent_of_code::{check_puzzle, solve, supported_puzzles};
+use jni::objects::{JByteBuffer, JClass, JObject, JString, JThrowable, JValue};
+use jni::sys::{jint, jintArray, jobjectArray, jstring};
 use jni::JNIEnv;
 
+/// The kind of a `SolverException`, matching the `SolverException.Kind` enum in Java.
+#[derive(Copy, Clone)]
+enum ErrorKind {
+    UnsupportedPuzzle,
+    InvalidInput,
+    Internal,
+}
+
+impl ErrorKind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::UnsupportedPuzzle => "UNSUPPORTED_PUZZLE",
+            Self::InvalidInput => "INVALID_INPUT",
+            Self::Internal => "INTERNAL",
+        }
+    }
+}
+
+struct SolverError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl SolverError {
+    fn new<S: Into<String>>(kind: ErrorKind, message: S) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<jni::errors::Error> for SolverError {
+    fn from(error: jni::errors::Error) -> Self {
+        Self::new(ErrorKind::Internal, format!("JNI error: {error}"))
+    }
+}
+
+/// Throw a `SolverException` for the error.
+///
+/// Nothing more can be done if constructing or throwing the exception fails,
+/// in which case a pending exception from the failure is left for Java to see.
+fn throw_error(env: &mut JNIEnv, error: SolverError) {
+    let _ = (|| -> jni::errors::Result<()> {
+        let kind = env.new_string(error.kind.name())?;
+        let message = env.new_string(error.message)?;
+        let exception = env.new_object(
+            "net/fornwall/aoc/SolverException",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+            &[JValue::Object(&kind), JValue::Object(&message)],
+        )?;
+        env.throw(JThrowable::from(exception))
+    })();
+}
+
+/// Run a function, throwing a `SolverException` and returning null if it fails or panics.
+fn run_or_throw<'local, T, F>(env: &mut JNIEnv<'local>, function: F) -> *mut T
+where
+    F: FnOnce(&mut JNIEnv<'local>) -> Result<*mut T, SolverError>,
+{
+    let result = catch_unwind(AssertUnwindSafe(|| function(env))).unwrap_or_else(|_| {
+        Err(SolverError::new(
+            ErrorKind::Internal,
+            "Panic when solving problem",
+        ))
+    });
+    result.unwrap_or_else(|error| {
+        throw_error(env, error);
+        std::ptr::null_mut()
+    })
+}
+
+fn convert_params(year: jint, day: jint, part: jint) -> Result<(u16, u8, u8), SolverError> {
+    let unsupported = |message: String| SolverError::new(ErrorKind::UnsupportedPuzzle, message);
+    let year = u16::try_from(year).map_err(|_| unsupported(format!("Invalid year: {year}")))?;
+    let day = u8::try_from(day).map_err(|_| unsupported(format!("Invalid day: {day}")))?;
+    let part = u8::try_from(part).map_err(|_| unsupported(format!("Invalid part: {part}")))?;
+    check_puzzle(year, day, part).map_err(unsupported)?;
+    Ok((year, day, part))
+}
+
+fn solve_checked(year: jint, day: jint, part: jint, input: &str) -> Result<String, SolverError> {
+    let (year, day, part) = convert_params(year, day, part)?;
+    solve(year, day, part, input)
+        .map_err(|message| SolverError::new(ErrorKind::InvalidInput, message))
+}
+
+fn get_input(env: &mut JNIEnv, input: &JString) -> Result<String, SolverError> {
+    if input.is_null() {
+        return Err(SolverError::new(ErrorKind::InvalidInput, "Input is null"));
+    }
+    Ok(env.get_string(input)?.into())
+}
+
+#[no_mangle]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_solve<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    year: jint,
+    day: jint,
+    part: jint,
+    input: JString<'local>,
+) -> jstring {
+    run_or_throw(&mut env, |env| {
+        let input = get_input(env, &input)?;
+        let answer = solve_checked(year, day, part, &input)?;
+        Ok(env.new_string(answer)?.into_raw())
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_solveBoth<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    year: jint,
+    day: jint,
+    input: JString<'local>,
+) -> jobjectArray {
+    run_or_throw(&mut env, |env| {
+        let input = get_input(env, &input)?;
+        let answers = env.new_object_array(2, "java/lang/String", JObject::null())?;
+        for part in [1, 2] {
+            let answer = env.new_string(solve_checked(year, day, part, &input)?)?;
+            env.set_object_array_element(&answers, part - 1, answer)?;
+        }
+        Ok(answers.into_raw())
+    })
+}
+
 #[no_mangle]
-pub extern "system" fn Java_net_fornwall_aoc_Solver_solve(
-    mut env: JNIEnv,
-    _class: JClass,
-    year: i32,
-    day: i32,
-    part: i32,
-    input: JString,
+pub extern "system" fn Java_net_fornwall_aoc_Solver_solveDirect<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    year: jint,
+    day: jint,
+    part: jint,
+    input: JByteBuffer<'local>,
+    offset: jint,
+    length: jint,
 ) -> jstring {
-    let input_str: String = env
-        .get_string(&input)
-        .expect("Unable to get input string")
-        .into();
-
-    let exception_message = match convert_params(year, day, part) {
-        Ok((year, day, part)) => match solve(year, day, part, &input_str) {
-            Ok(output) => {
-                return env
-                    .new_string(output)
-                    .expect("Unable to create output string")
-                    .into_raw();
+    run_or_throw(&mut env, |env| {
+        let address = env.get_direct_buffer_address(&input)?;
+        let capacity = env.get_direct_buffer_capacity(&input)?;
+        let (offset, length) = match (usize::try_from(offset), usize::try_from(length)) {
+            (Ok(offset), Ok(length)) if offset.saturating_add(length) <= capacity => {
+                (offset, length)
+            }
+            _ => {
+                return Err(SolverError::new(
+                    ErrorKind::InvalidInput,
+                    "Invalid buffer range",
+                ));
             }
-            Err(msg) => msg,
-        },
-        Err(message) => message,
-    };
-
-    env.throw_new("net/fornwall/aoc/SolverException", exception_message)
-        .expect("Unable to throw exception");
-    ::std::ptr::null_mut()
-}
-
-fn convert_params(year: i32, day: i32, part: i32) -> Result<(u16, u8, u8), String> {
-    Ok((
-        u16::try_from(year).map_err(|_| format!("Invalid year: {year}"))?,
-        u8::try_from(day).map_err(|_| format!("Invalid day: {day}"))?,
-        u8::try_from(part).map_err(|_| format!("Invalid part: {part}"))?,
-    ))
+        };
+        // SAFETY: The range has been checked against the capacity of the direct buffer,
+        // which is kept alive by the Java caller during this call.
+        let bytes = unsafe { std::slice::from_raw_parts(address.add(offset), length) };
+        let input = std::str::from_utf8(bytes).map_err(|error| {
+            SolverError::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid UTF-8 input: {error}"),
+            )
+        })?;
+        let answer = solve_checked(year, day, part, input)?;
+        Ok(env.new_string(answer)?.into_raw())
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_supportedPuzzlesNative<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+) -> jintArray {
+    run_or_throw(&mut env, |env| {
+        let year_and_days = supported_puzzles()
+            .flat_map(|(year, day)| [jint::from(year), jint::from(day)])
+            .collect::<Vec<_>>();
+        let length = jint::try_from(year_and_days.len())
+            .map_err(|_| SolverError::new(ErrorKind::Internal, "Too many puzzles"))?;
+        let array = env.new_int_array(length)?;
+        env.set_int_array_region(&array, 0, &year_and_days)?;
+        Ok(array.into_raw())
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_version<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+) -> jstring {
+    run_or_throw(&mut env, |env| {
+        Ok(env.new_string(env!("CARGO_PKG_VERSION"))?.into_raw())
+    })
 }
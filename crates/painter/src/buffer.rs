@@ -26,6 +26,12 @@ macro_rules! console_log {
     ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
 }
 
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[macro_export]
+macro_rules! console_log {
+    ($($t:tt)*) => (eprintln!($($t)*))
+}
+
 pub struct CircularOutputBuffer {
     shared_buffer: Vec<i32>,
     non_flushed_writes: i32,
@@ -33,6 +39,7 @@ pub struct CircularOutputBuffer {
 
 impl CircularOutputBuffer {
     pub fn new() -> Self {
+        #[allow(unused_mut)]
         let mut result = Self {
             shared_buffer: vec![0; 16 * 1024 * 1024],
             non_flushed_writes: 0,
@@ -101,9 +108,7 @@ impl CircularOutputBuffer {
             let byte_pointer = self.shared_buffer.as_mut_ptr() as *mut u8;
             let buffer_start = self.writer_offset() * 4;
             for i in 0..text.len() {
-                byte_pointer
-                    .offset((buffer_start + i) as isize)
-                    .write(text.as_bytes()[i]);
+                byte_pointer.add(buffer_start + i).write(text.as_bytes()[i]);
             }
             self.non_flushed_writes +=
                 (text.len() / 4 + if text.len() % 4 == 0 { 0 } else { 1 }) as i32;
@@ -125,9 +130,9 @@ impl CircularOutputBuffer {
                 self.shared_buffer.len(),
             ));
              */
-            self.shared_buffer[HEADER_WRITE_OFFSET] =
-                (self.shared_buffer[HEADER_WRITE_OFFSET] + self.non_flushed_writes) as i32
-                    % (self.data_len() as i32);
+            self.shared_buffer[HEADER_WRITE_OFFSET] = (self.shared_buffer[HEADER_WRITE_OFFSET]
+                + self.non_flushed_writes)
+                % (self.data_len() as i32);
             self.non_flushed_writes = 0;
         }
         self.perhaps_wait();
@@ -147,29 +152,27 @@ impl CircularOutputBuffer {
         self.shared_buffer[HEADER_READER_WANT_MORE_OFFSET] = 0;
 
         let used = self.used_space();
-        if used * 3 < (self.data_len() as i32) * 2 {
-            return;
-        }
-
-        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
-        unsafe {
-            let timeout_ns = -1;
-            let raw_pointer: *mut i32 = self.shared_buffer.as_mut_ptr();
-            if raw_pointer as usize == 1 {
-                self.log(
-                    "This check of raw_pointer is necessary for (wasm-opt|compiler)? to keep it",
-                );
+        if used * 3 >= (self.data_len() as i32) * 2 {
+            #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+            unsafe {
+                let timeout_ns = -1;
+                let raw_pointer: *mut i32 = self.shared_buffer.as_mut_ptr();
+                if raw_pointer as usize == 1 {
+                    self.log(
+                        "This check of raw_pointer is necessary for (wasm-opt|compiler)? to keep it",
+                    );
+                }
+
+                self.log("About do wait for more");
+                core::arch::wasm32::memory_atomic_wait32(raw_pointer, 0, timeout_ns);
+                self.log("Waiting done");
+
+                // A variant calling out to javascript, requires lines to be uncommented in
+                // worker-visualiser.js. Still needs nightly build with atomics feature to
+                // make wasm-bindgen create the wasm memory with the shared flag:
+                // let data_buffer_offset = unsafe { self.shared_buffer.as_mut_ptr() as u32 } / 4;
+                // do_wait(data_buffer_offset, 0);
             }
-
-            self.log("About do wait for more");
-            core::arch::wasm32::memory_atomic_wait32(raw_pointer, 0, timeout_ns);
-            self.log("Waiting done");
-
-            // A variant calling out to javascript, requires lines to be uncommented in
-            // worker-visualiser.js. Still needs nightly build with atomics feature to
-            // make wasm-bindgen create the wasm memory with the shared flag:
-            // let data_buffer_offset = unsafe { self.shared_buffer.as_mut_ptr() as u32 } / 4;
-            // do_wait(data_buffer_offset, 0);
         }
     }
 
@@ -198,7 +201,7 @@ impl CircularOutputBuffer {
         }
     }
 
-    pub fn log(&mut self, text: &str) {
+    pub fn log(&self, text: &str) {
         console_log!("[rust] {}", text);
     }
 }
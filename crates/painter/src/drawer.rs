@@ -40,8 +40,14 @@ pub struct CommandBufferPainter {
     aspect_ratio: f64,
 }
 
+impl Default for CommandBufferPainter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CommandBufferPainter {
-    pub fn new() -> CommandBufferPainter {
+    pub fn new() -> Self {
         Self {
             output_buffer: CircularOutputBuffer::new(),
             aspect_ratio: 1.0,
@@ -194,19 +200,19 @@ impl Painter for CommandBufferPainter {
 
     fn meta_delay(&mut self, delay_ms: u16) {
         self.output_buffer.write(Command::Delay as i32);
-        self.output_buffer.write(delay_ms as i32);
+        self.output_buffer.write(i32::from(delay_ms));
         self.output_buffer.flush_if_necessary();
     }
 
     fn meta_switch_layer(&mut self, to_layer: u16) {
         self.output_buffer.write(Command::SwitchLayer as i32);
-        self.output_buffer.write(to_layer as i32);
+        self.output_buffer.write(i32::from(to_layer));
         self.output_buffer.flush_if_necessary();
     }
 
     fn set_aspect_ratio(&mut self, width: i32, height: i32) {
         self.output_buffer.write(Command::SetAspectRatio as i32);
-        self.aspect_ratio = width as f64 / height as f64;
+        self.aspect_ratio = f64::from(width) / f64::from(height);
         self.output_buffer.write_float(self.aspect_ratio);
         self.output_buffer.flush_if_necessary();
     }
@@ -1,3 +1,6 @@
-#![feature(stdsimd)]
+#![cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    feature(stdarch_wasm_atomic_wait)
+)]
 mod buffer;
 pub mod drawer;
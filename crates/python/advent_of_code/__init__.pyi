@@ -1 +1,13 @@
+from concurrent.futures import Executor
+from typing import Awaitable, Optional
+
+class AdventOfCodeError(ValueError): ...
+class UnsupportedPuzzleError(AdventOfCodeError): ...
+class InvalidInputError(AdventOfCodeError): ...
+
 def solve(year: int, day: int, part: int, input: str) -> str: ...
+def solve_both(year: int, day: int, input: str) -> tuple[str, str]: ...
+def solve_async(
+    year: int, day: int, part: int, input: str, executor: Optional[Executor] = None
+) -> Awaitable[str]: ...
+def list_puzzles() -> list[tuple[int, int]]: ...
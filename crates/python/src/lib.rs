@@ -1,29 +1,54 @@
 #![allow(clippy::panic, clippy::borrow_deref_ref)]
 
-use core::fmt::Display;
-
-use pyo3::exceptions::PyValueError;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
-use pyo3::FromPyObject;
 use pyo3::PyAny;
 
-use ::advent_of_code::solve_raw;
+use ::advent_of_code::{check_puzzle, supported_puzzles};
 
-fn try_to_string<'a, T: Display + FromPyObject<'a>>(object: &'a PyAny) -> String {
-    if let Ok(value) = object.extract::<String>() {
-        return value;
-    }
+create_exception!(
+    advent_of_code,
+    AdventOfCodeError,
+    PyValueError,
+    "Base class for errors raised when solving a problem."
+);
+create_exception!(
+    advent_of_code,
+    UnsupportedPuzzleError,
+    AdventOfCodeError,
+    "Raised when the year, day or part does not specify a supported problem."
+);
+create_exception!(
+    advent_of_code,
+    InvalidInputError,
+    AdventOfCodeError,
+    "Raised when the input to a problem could not be solved."
+);
 
-    if let Ok(value) = object.extract::<T>() {
-        return value.to_string();
-    }
+/// Extract a year, day or part, raising `UnsupportedPuzzleError` instead of `OverflowError`
+/// for integers out of range.
+fn puzzle_number<'a, T: FromPyObject<'a>>(value: &'a PyAny, name: &str) -> PyResult<T> {
+    value.extract().map_err(|error| {
+        if error.is_instance_of::<PyOverflowError>(value.py()) {
+            UnsupportedPuzzleError::new_err(format!("Invalid {name} {value}"))
+        } else {
+            error
+        }
+    })
+}
 
-    "".to_string()
+fn solve_without_gil(py: Python, year: u16, day: u8, part: u8, input: &str) -> PyResult<String> {
+    check_puzzle(year, day, part).map_err(UnsupportedPuzzleError::new_err)?;
+    py.allow_threads(|| ::advent_of_code::solve(year, day, part, input))
+        .map_err(InvalidInputError::new_err)
 }
 
 /// Returns the answer for the specified problem and input.
 ///
+/// The global interpreter lock is released while solving.
+///
 /// Args:
 /// year (int): The year of the problem, as in 2018 or 2019.
 /// day (int): The day of the problem, from 1 to 25.
@@ -34,14 +59,102 @@ fn try_to_string<'a, T: Display + FromPyObject<'a>>(object: &'a PyAny) -> String
 /// str: The computed answer as text.
 ///
 /// Raises:
-/// ValueError: If the input was invalid.
+/// TypeError: If an argument has the wrong type.
+/// UnsupportedPuzzleError: If the year, day or part is not supported.
+/// InvalidInputError: If the input was invalid.
 #[pyfunction]
 #[pyo3(text_signature = "(year, day, part, input)")]
-pub fn solve(year: &PyAny, day: &PyAny, part: &PyAny, input: &str) -> PyResult<String> {
-    let year_value = try_to_string::<u16>(year);
-    let day_value = try_to_string::<u8>(day);
-    let part_value = try_to_string::<u8>(part);
-    solve_raw(&year_value, &day_value, &part_value, input).map_err(PyValueError::new_err)
+pub fn solve(py: Python, year: &PyAny, day: &PyAny, part: &PyAny, input: &str) -> PyResult<String> {
+    solve_without_gil(
+        py,
+        puzzle_number(year, "year")?,
+        puzzle_number(day, "day")?,
+        puzzle_number(part, "part")?,
+        input,
+    )
+}
+
+/// Returns the answers to both parts of the specified problem and input.
+///
+/// The global interpreter lock is released while solving.
+///
+/// Args:
+/// year (int): The year of the problem, as in 2018 or 2019.
+/// day (int): The day of the problem, from 1 to 25.
+/// input (str): The input to the problem.
+///
+/// Returns:
+/// tuple[str, str]: The computed answers to part 1 and part 2.
+///
+/// Raises:
+/// TypeError: If an argument has the wrong type.
+/// UnsupportedPuzzleError: If the year or day is not supported.
+/// InvalidInputError: If the input was invalid.
+#[pyfunction]
+#[pyo3(text_signature = "(year, day, input)")]
+pub fn solve_both(
+    py: Python,
+    year: &PyAny,
+    day: &PyAny,
+    input: &str,
+) -> PyResult<(String, String)> {
+    let year = puzzle_number(year, "year")?;
+    let day = puzzle_number(day, "day")?;
+    Ok((
+        solve_without_gil(py, year, day, 1, input)?,
+        solve_without_gil(py, year, day, 2, input)?,
+    ))
+}
+
+/// Returns an awaitable answer for the specified problem and input.
+///
+/// Solving is done in a thread pool executor of the running event loop,
+/// so that the event loop is not blocked.
+///
+/// Args:
+/// year (int): The year of the problem, as in 2018 or 2019.
+/// day (int): The day of the problem, from 1 to 25.
+/// part (int): The part of the problem, either 1 or 2.
+/// input (str): The input to the problem.
+/// executor (concurrent.futures.Executor, optional): The executor to solve in,
+///     with the default executor of the event loop used if not specified.
+///
+/// Returns:
+/// Awaitable[str]: The computed answer as text.
+///
+/// Raises:
+/// TypeError: If an argument has the wrong type.
+/// UnsupportedPuzzleError: If the year, day or part is out of range.
+/// RuntimeError: If there is no running event loop.
+#[pyfunction]
+#[pyo3(text_signature = "(year, day, part, input, executor=None)")]
+pub fn solve_async<'py>(
+    py: Python<'py>,
+    year: &'py PyAny,
+    day: &'py PyAny,
+    part: &'py PyAny,
+    input: String,
+    executor: Option<&'py PyAny>,
+) -> PyResult<&'py PyAny> {
+    let year: u16 = puzzle_number(year, "year")?;
+    let day: u8 = puzzle_number(day, "day")?;
+    let part: u8 = puzzle_number(part, "part")?;
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let solve_function = wrap_pyfunction!(solve, py)?;
+    event_loop.call_method1(
+        "run_in_executor",
+        (executor, solve_function, year, day, part, input),
+    )
+}
+
+/// Returns the problems which can be solved.
+///
+/// Returns:
+/// list[tuple[int, int]]: The (year, day) of each supported problem.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub fn list_puzzles() -> Vec<(u16, u8)> {
+    supported_puzzles().collect()
 }
 
 // This defines a python module. pyo3 will copy the rust doc comment
@@ -50,7 +163,7 @@ pub fn solve(year: &PyAny, day: &PyAny, part: &PyAny, input: &str) -> PyResult<S
 
 /// Solve Advent of Code problems.
 ///
-/// This module provides a function to compute answers for
+/// This module provides functions to compute answers for
 /// Advent of Code (https://adventofcode.com) problems.
 ///
 /// See https://github.com/fornwall/advent-of-code for source code.
@@ -61,8 +174,17 @@ pub fn solve(year: &PyAny, day: &PyAny, part: &PyAny, input: &str) -> PyResult<S
 /// >>> solve(year=2019, day=1, part=1, input='14')
 /// '2'
 #[pymodule]
-pub fn advent_of_code(_py: Python, m: &PyModule) -> PyResult<()> {
+pub fn advent_of_code(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(solve))?;
+    m.add_wrapped(wrap_pyfunction!(solve_both))?;
+    m.add_wrapped(wrap_pyfunction!(solve_async))?;
+    m.add_wrapped(wrap_pyfunction!(list_puzzles))?;
+    m.add("AdventOfCodeError", py.get_type::<AdventOfCodeError>())?;
+    m.add(
+        "UnsupportedPuzzleError",
+        py.get_type::<UnsupportedPuzzleError>(),
+    )?;
+    m.add("InvalidInputError", py.get_type::<InvalidInputError>())?;
 
     Ok(())
 }
@@ -1,8 +1,8 @@
+import asyncio
 import unittest
 
 import advent_of_code
 
-assert advent_of_code.solve("2019", "1", "1", "12") == "2"
 assert advent_of_code.solve(2019, 1, 1, "12") == "2"
 assert advent_of_code.solve(2019, 1, 1, "14") == "2"
 assert advent_of_code.solve(2019, 1, 1, "1969") == "654"
@@ -10,6 +10,48 @@ assert advent_of_code.solve(2019, 1, 1, "100756") == "33583"
 assert advent_of_code.solve(2019, 9, 1, "104,1125899906842624,99") == "1125899906842624"
 
 
-class TestStringMethods(unittest.TestCase):
-    def test_upper(self):
-        self.assertEqual("foo".upper(), "FOO")
+class TestSolve(unittest.TestCase):
+    def test_solve_both(self):
+        self.assertEqual(advent_of_code.solve_both(2019, 1, "1969"), ("654", "966"))
+
+    def test_bad_argument_types(self):
+        with self.assertRaises(TypeError):
+            advent_of_code.solve("2019", 1, 1, "14")
+        with self.assertRaises(TypeError):
+            advent_of_code.solve(2019, 1, 1, 14)
+
+    def test_errors(self):
+        with self.assertRaises(advent_of_code.UnsupportedPuzzleError):
+            advent_of_code.solve(2019, 26, 1, "14")
+        with self.assertRaises(advent_of_code.UnsupportedPuzzleError):
+            advent_of_code.solve(2019, 1, 3, "14")
+        with self.assertRaises(advent_of_code.UnsupportedPuzzleError):
+            advent_of_code.solve(2019, 1, 300, "14")
+        with self.assertRaises(advent_of_code.UnsupportedPuzzleError):
+            advent_of_code.solve(-2019, 1, 1, "14")
+        with self.assertRaises(advent_of_code.UnsupportedPuzzleError):
+            advent_of_code.solve_both(2019, 2**64, "14")
+        with self.assertRaises(advent_of_code.InvalidInputError):
+            advent_of_code.solve(2019, 1, 1, "")
+        self.assertTrue(
+            issubclass(advent_of_code.InvalidInputError, advent_of_code.AdventOfCodeError)
+        )
+        self.assertTrue(issubclass(advent_of_code.AdventOfCodeError, ValueError))
+
+    def test_list_puzzles(self):
+        puzzles = advent_of_code.list_puzzles()
+        self.assertIn((2019, 1), puzzles)
+        self.assertEqual(len(puzzles), len(set(puzzles)))
+
+    def test_solve_async(self):
+        async def solve_concurrently():
+            return await asyncio.gather(
+                advent_of_code.solve_async(2019, 1, 1, "14"),
+                advent_of_code.solve_async(2019, 1, 2, "1969"),
+            )
+
+        self.assertEqual(asyncio.run(solve_concurrently()), ["2", "966"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,126 @@
+use crate::Frame;
+
+fn check_same_size(frames: &[Frame]) -> Result<(u32, u32), String> {
+    let first = frames.first().ok_or("No frames to encode")?;
+    if frames
+        .iter()
+        .any(|frame| (frame.width, frame.height) != (first.width, first.height))
+    {
+        return Err("Frames of different sizes can not be animated".to_string());
+    }
+    Ok((first.width, first.height))
+}
+
+fn png_encoder(output: &mut Vec<u8>, width: u32, height: u32) -> png::Encoder<'_, &mut Vec<u8>> {
+    let mut encoder = png::Encoder::new(output, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+}
+
+/// Encode a single frame as a PNG image.
+pub fn encode_png(frame: &Frame) -> Result<Vec<u8>, String> {
+    let mut output = Vec::new();
+    let mut writer = png_encoder(&mut output, frame.width, frame.height)
+        .write_header()
+        .map_err(|error| format!("Error writing PNG: {error}"))?;
+    writer
+        .write_image_data(&frame.pixels)
+        .and_then(|_| writer.finish())
+        .map_err(|error| format!("Error writing PNG: {error}"))?;
+    Ok(output)
+}
+
+/// Encode frames of the same size as an infinitely looping animated PNG.
+pub fn encode_apng(frames: &[Frame]) -> Result<Vec<u8>, String> {
+    let (width, height) = check_same_size(frames)?;
+    let to_error = |error: png::EncodingError| format!("Error writing APNG: {error}");
+
+    let mut output = Vec::new();
+    let mut encoder = png_encoder(&mut output, width, height);
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(to_error)?;
+    let mut writer = encoder.write_header().map_err(to_error)?;
+    for frame in frames {
+        let delay_ms = u16::try_from(frame.delay_ms).unwrap_or(u16::MAX);
+        writer.set_frame_delay(delay_ms, 1000).map_err(to_error)?;
+        writer.write_image_data(&frame.pixels).map_err(to_error)?;
+    }
+    writer.finish().map_err(to_error)?;
+    Ok(output)
+}
+
+/// Encode frames of the same size as an infinitely looping animated GIF.
+///
+/// Colors are quantized to a palette of 256 colors per frame, and delays are
+/// rounded to the 10 millisecond precision of the format.
+pub fn encode_gif(frames: &[Frame]) -> Result<Vec<u8>, String> {
+    let (width, height) = check_same_size(frames)?;
+    let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => return Err(format!("Too large image for GIF: {width}x{height}")),
+    };
+    let to_error = |error: gif::EncodingError| format!("Error writing GIF: {error}");
+
+    let mut output = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut output, width, height, &[]).map_err(to_error)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(to_error)?;
+        for frame in frames {
+            let mut pixels = frame.pixels.clone();
+            let mut gif_frame = gif::Frame::from_rgba_speed(width, height, &mut pixels, 10);
+            gif_frame.delay = u16::try_from((frame.delay_ms + 5) / 10).unwrap_or(u16::MAX);
+            encoder.write_frame(&gif_frame).map_err(to_error)?;
+        }
+    }
+    Ok(output)
+}
+
+#[test]
+fn encode() {
+    #![allow(clippy::unwrap_used)]
+    let frame = |color: u8| Frame {
+        width: 3,
+        height: 2,
+        pixels: [color, 0, 0, 255].repeat(6),
+        delay_ms: 100,
+    };
+
+    let png = encode_png(&frame(255)).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    let decoder = png::Decoder::new(png.as_slice());
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    reader.next_frame(&mut pixels).unwrap();
+    assert_eq!(pixels, frame(255).pixels);
+
+    let apng = encode_apng(&[frame(255), frame(0)]).unwrap();
+    let reader = png::Decoder::new(apng.as_slice()).read_info().unwrap();
+    let animation_control = reader.info().animation_control().unwrap();
+    assert_eq!(animation_control.num_frames, 2);
+    assert_eq!(animation_control.num_plays, 0);
+
+    let gif = encode_gif(&[frame(255), frame(0)]).unwrap();
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(gif.as_slice()).unwrap();
+    let mut decoded_frames = 0;
+    while let Some(decoded) = decoder.read_next_frame().unwrap() {
+        assert_eq!(decoded.delay, 10);
+        decoded_frames += 1;
+    }
+    assert_eq!(decoded_frames, 2);
+
+    assert!(encode_gif(&[]).is_err());
+    assert!(encode_apng(&[
+        frame(0),
+        Frame {
+            width: 1,
+            ..frame(0)
+        }
+    ])
+    .is_err());
+}
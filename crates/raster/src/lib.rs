@@ -0,0 +1,603 @@
+#![forbid(unsafe_code)]
+//! A software rasterizer for visualized solutions, rendering canvas-like drawing
+//! commands to RGBA frames which can be encoded as PNG, APNG or GIF images.
+//!
+//! Paths are filled using the nonzero winding rule, sampling each pixel at its center
+//! without anti-aliasing. Text and shadows are not rendered.
+mod encode;
+
+pub use encode::{encode_apng, encode_gif, encode_png};
+
+const LAYER_COUNT: usize = 2;
+
+/// The delay of frames, unless increased by [RasterPainter::meta_delay].
+const DEFAULT_FRAME_DELAY_MS: u32 = 50;
+
+/// A rendered frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub width: u32,
+    pub height: u32,
+    /// The RGBA pixels of the frame, row by row.
+    pub pixels: Vec<u8>,
+    /// How long the frame should be shown when animated.
+    pub delay_ms: u32,
+}
+
+type Color = [f32; 4];
+
+fn color(r: i32, g: i32, b: i32, a: f64) -> Color {
+    let component = |value: i32| value.clamp(0, 255) as f32 / 255.;
+    [
+        component(r),
+        component(g),
+        component(b),
+        a.clamp(0., 1.) as f32,
+    ]
+}
+
+/// Blend `source` over `destination`, both with non-premultiplied alpha.
+fn blend(destination: &mut Color, source: Color) {
+    let source_alpha = source[3];
+    if source_alpha >= 1. {
+        *destination = source;
+        return;
+    }
+    let destination_weight = destination[3] * (1. - source_alpha);
+    let alpha = source_alpha + destination_weight;
+    if alpha <= 0. {
+        *destination = [0.; 4];
+        return;
+    }
+    for i in 0..3 {
+        destination[i] =
+            source[i].mul_add(source_alpha, destination[i] * destination_weight) / alpha;
+    }
+    destination[3] = alpha;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+/// A polygon edge, where the winding direction is determined by the order of the points.
+struct Edge {
+    from: Point,
+    to: Point,
+}
+
+/// A painter rasterizing frames into pixels, which implements the `Painter` trait of
+/// the `advent-of-code` crate when it is built with the `raster` feature.
+///
+/// Drawn content is kept between frames until cleared, and the painted layers are
+/// composited over a black background when a frame ends.
+pub struct RasterPainter {
+    max_width: u32,
+    max_height: u32,
+    height: u32,
+    width: u32,
+    aspect_ratio: f64,
+    layers: [Vec<Color>; LAYER_COUNT],
+    current_layer: usize,
+    /// The sub-paths of the current path, in pixel coordinates.
+    path: Vec<Vec<Point>>,
+    fill: Color,
+    stroke: Color,
+    line_width: f64,
+    frames: Vec<Frame>,
+    max_frames: usize,
+    /// Only every `frame_stride`:th ended frame is kept, to stay within `max_frames`.
+    frame_stride: usize,
+    ended_frames: usize,
+}
+
+impl RasterPainter {
+    /// Create a painter rendering frames of the specified height in pixels, with the
+    /// width determined by the aspect ratio.
+    pub fn new(height: u32) -> Self {
+        let mut painter = Self {
+            max_width: u32::MAX,
+            max_height: height.max(1),
+            height: 0,
+            width: 0,
+            aspect_ratio: 1.,
+            layers: [Vec::new(), Vec::new()],
+            current_layer: 0,
+            path: Vec::new(),
+            fill: color(0, 0, 0, 1.),
+            stroke: color(0, 0, 0, 1.),
+            line_width: 0.,
+            frames: Vec::new(),
+            max_frames: usize::MAX,
+            frame_stride: 1,
+            ended_frames: 0,
+        };
+        painter.resize();
+        painter
+    }
+
+    /// Limit the width of frames, reducing their height if needed to keep the aspect ratio.
+    pub fn with_max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width.max(1);
+        self.resize();
+        self
+    }
+
+    /// Limit the number of kept frames to bound memory usage.
+    ///
+    /// When the limit is reached every other frame is dropped, with the delay of
+    /// dropped frames added to the preceding kept one, and from then on only every
+    /// other ended frame is kept.
+    pub fn with_max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames.max(2);
+        self
+    }
+
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+
+    /// Composite the layers over a black background.
+    pub fn render(&self) -> Frame {
+        let mut pixels = Vec::with_capacity(self.layers[0].len() * 4);
+        for i in 0..self.layers[0].len() {
+            let mut pixel = [0., 0., 0., 1.];
+            for layer in &self.layers {
+                blend(&mut pixel, layer[i]);
+            }
+            pixels.extend(pixel.iter().map(|c| (c * 255.).round() as u8));
+        }
+        Frame {
+            width: self.width,
+            height: self.height,
+            pixels,
+            delay_ms: DEFAULT_FRAME_DELAY_MS,
+        }
+    }
+
+    fn resize(&mut self) {
+        let mut height = self.max_height;
+        let mut width = (f64::from(height) * self.aspect_ratio).round().max(1.) as u32;
+        if width > self.max_width {
+            width = self.max_width;
+            height = (f64::from(width) / self.aspect_ratio).round().max(1.) as u32;
+        }
+        if (width, height) != (self.width, self.height) {
+            self.width = width;
+            self.height = height;
+            for layer in &mut self.layers {
+                *layer = vec![[0.; 4]; (self.width * self.height) as usize];
+            }
+        }
+    }
+
+    /// Scale a point from normalized coordinates to pixels.
+    fn pixel_point(&self, x: f64, y: f64) -> Point {
+        Point {
+            x: x * f64::from(self.width),
+            y: y * f64::from(self.height),
+        }
+    }
+
+    fn current_point(&self) -> Option<Point> {
+        self.path
+            .last()
+            .and_then(|sub_path| sub_path.last())
+            .copied()
+    }
+
+    /// Fill the pixels whose centers are inside the polygons formed by `edges`.
+    fn fill_edges(&mut self, edges: &[Edge], paint: Color) {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let layer = &mut self.layers[self.current_layer];
+        let (min_y, max_y) = edges
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min_y, max_y), edge| {
+                (
+                    min_y.min(edge.from.y).min(edge.to.y),
+                    max_y.max(edge.from.y).max(edge.to.y),
+                )
+            });
+        let mut crossings: Vec<(f64, i32)> = Vec::new();
+        for row in pixel_start(min_y, height)..pixel_start(max_y, height) {
+            let y = row as f64 + 0.5;
+            crossings.clear();
+            for edge in edges {
+                let (top, bottom, winding) = if edge.from.y < edge.to.y {
+                    (edge.from, edge.to, 1)
+                } else {
+                    (edge.to, edge.from, -1)
+                };
+                if y >= top.y && y < bottom.y {
+                    let x = top.x + (y - top.y) * (bottom.x - top.x) / (bottom.y - top.y);
+                    crossings.push((x, winding));
+                }
+            }
+            crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            for i in 0..crossings.len() {
+                winding += crossings[i].1;
+                if winding != 0 && i + 1 < crossings.len() {
+                    let start = pixel_start(crossings[i].0, width);
+                    let end = pixel_start(crossings[i + 1].0, width);
+                    for pixel in &mut layer[row * width + start..row * width + end] {
+                        blend(pixel, paint);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The first pixel whose center is at or to the right of `x`.
+fn pixel_start(x: f64, size: usize) -> usize {
+    (x - 0.5).ceil().clamp(0., size as f64) as usize
+}
+
+/// The edges of a rectangle around the line segment from `from` to `to`, oriented
+/// so that overlapping segments of a stroke do not cancel each other out.
+fn stroke_segment(from: Point, to: Point, width: f64, edges: &mut Vec<Edge>) {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = dx.hypot(dy);
+    if length == 0. {
+        return;
+    }
+    let (nx, ny) = (-dy / length * width / 2., dx / length * width / 2.);
+    let corners = [
+        Point {
+            x: from.x + nx,
+            y: from.y + ny,
+        },
+        Point {
+            x: to.x + nx,
+            y: to.y + ny,
+        },
+        Point {
+            x: to.x - nx,
+            y: to.y - ny,
+        },
+        Point {
+            x: from.x - nx,
+            y: from.y - ny,
+        },
+    ];
+    for i in 0..corners.len() {
+        edges.push(Edge {
+            from: corners[i],
+            to: corners[(i + 1) % corners.len()],
+        });
+    }
+}
+
+impl RasterPainter {
+    /// Clear everything drawn so far.
+    pub fn clear(&mut self) {
+        for layer in &mut self.layers {
+            layer.fill([0.; 4]);
+        }
+    }
+
+    /// Start a new path, discarding the current one.
+    pub fn begin_path(&mut self) {
+        self.path.clear();
+    }
+
+    /// Add a straight line from the current point to the start of the current sub-path.
+    pub fn close_path(&mut self) {
+        if let Some(sub_path) = self.path.last_mut() {
+            if let (Some(&first), Some(&last)) = (sub_path.first(), sub_path.last()) {
+                if first != last {
+                    sub_path.push(first);
+                }
+                // A new sub-path starts at the start of the closed one.
+                self.path.push(vec![first]);
+            }
+        }
+    }
+
+    /// Fill a rectangle with the current fill style.
+    pub fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64) {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let top_left = self.pixel_point(x, y);
+        let bottom_right = self.pixel_point(x + w, y + h);
+        let (start_x, end_x) = (
+            pixel_start(top_left.x.min(bottom_right.x), width),
+            pixel_start(top_left.x.max(bottom_right.x), width),
+        );
+        let (start_y, end_y) = (
+            pixel_start(top_left.y.min(bottom_right.y), height),
+            pixel_start(top_left.y.max(bottom_right.y), height),
+        );
+        let paint = self.fill;
+        let layer = &mut self.layers[self.current_layer];
+        for row in start_y..end_y {
+            for pixel in &mut layer[row * width + start_x..row * width + end_x] {
+                blend(pixel, paint);
+            }
+        }
+    }
+
+    /// Fill a square with the current fill style.
+    pub fn fill_square(&mut self, x: f64, y: f64, size: f64) {
+        self.fill_rect(x, y, size, size);
+    }
+
+    /// Fill the current path with the current fill style.
+    pub fn fill(&mut self) {
+        let mut edges = Vec::new();
+        for sub_path in &self.path {
+            for i in 0..sub_path.len() {
+                // Sub-paths are implicitly closed when filled.
+                edges.push(Edge {
+                    from: sub_path[i],
+                    to: sub_path[(i + 1) % sub_path.len()],
+                });
+            }
+        }
+        self.fill_edges(&edges, self.fill);
+    }
+
+    /// Stroke the outline of a square with the current stroke style and line width.
+    pub fn stroke_square(&mut self, x: i32, y: i32, size: i32) {
+        let (x, y, size) = (f64::from(x), f64::from(y), f64::from(size));
+        let line_width = (self.line_width * f64::from(self.height)).max(1.);
+        let corners = [
+            self.pixel_point(x, y),
+            self.pixel_point(x + size, y),
+            self.pixel_point(x + size, y + size),
+            self.pixel_point(x, y + size),
+        ];
+        let mut edges = Vec::new();
+        for i in 0..corners.len() {
+            stroke_segment(
+                corners[i],
+                corners[(i + 1) % corners.len()],
+                line_width,
+                &mut edges,
+            );
+        }
+        self.fill_edges(&edges, self.stroke);
+    }
+
+    /// Set the color used when stroking.
+    pub fn stroke_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.stroke = color(r, g, b, 1.);
+    }
+
+    /// Stroke the current path with the current stroke style and line width.
+    pub fn stroke(&mut self) {
+        let line_width = (self.line_width * f64::from(self.height)).max(1.);
+        let mut edges = Vec::new();
+        for sub_path in &self.path {
+            for segment in sub_path.windows(2) {
+                stroke_segment(segment[0], segment[1], line_width, &mut edges);
+            }
+        }
+        self.fill_edges(&edges, self.stroke);
+    }
+
+    /// Set the line width, relative to the height of the drawing area.
+    pub fn line_width(&mut self, width: f64) {
+        self.line_width = width;
+    }
+
+    /// Add a straight line from the current point to the current sub-path.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        let point = self.pixel_point(x, y);
+        match self.path.last_mut() {
+            Some(sub_path) => sub_path.push(point),
+            None => self.path.push(vec![point]),
+        }
+    }
+
+    /// Start a new sub-path at the specified point.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        let point = self.pixel_point(x, y);
+        self.path.push(vec![point]);
+    }
+
+    /// Set the color used when filling.
+    pub fn fill_style_rgb(&mut self, r: i32, g: i32, b: i32) {
+        self.fill = color(r, g, b, 1.);
+    }
+
+    /// Set the color used when filling, with an alpha between 0 and 1.
+    pub fn fill_style_rgba(&mut self, r: i32, g: i32, b: i32, a: f64) {
+        self.fill = color(r, g, b, a);
+    }
+
+    /// Add a clockwise circular arc, with angles in radians, to the current path.
+    pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        use std::f64::consts::TAU;
+        let sweep = end_angle - start_angle;
+        let sweep = if sweep >= TAU {
+            TAU
+        } else {
+            sweep.rem_euclid(TAU)
+        };
+        let center = self.pixel_point(x, y);
+        let radius_x = radius * f64::from(self.width);
+        let radius_y = radius * f64::from(self.height);
+
+        // Flatten the arc into line segments of at most around two pixels.
+        let segment_count = (sweep * radius_x.max(radius_y) / 2.)
+            .ceil()
+            .clamp(4., 1024.) as u32;
+        if self.current_point().is_none() {
+            self.path.push(Vec::new());
+        }
+        if let Some(sub_path) = self.path.last_mut() {
+            for i in 0..=segment_count {
+                let angle = start_angle + sweep * f64::from(i) / f64::from(segment_count);
+                sub_path.push(Point {
+                    x: radius_x.mul_add(angle.cos(), center.x),
+                    y: radius_y.mul_add(angle.sin(), center.y),
+                });
+            }
+        }
+    }
+
+    /// Mark the end of a frame, rendering what has been drawn so far.
+    pub fn end_frame(&mut self) {
+        self.ended_frames += 1;
+        if (self.ended_frames - 1) % self.frame_stride != 0 {
+            if let Some(last) = self.frames.last_mut() {
+                last.delay_ms += DEFAULT_FRAME_DELAY_MS;
+            }
+            return;
+        }
+
+        if self.frames.len() == self.max_frames {
+            let frames = std::mem::take(&mut self.frames);
+            for (index, frame) in frames.into_iter().enumerate() {
+                if index % 2 == 0 {
+                    self.frames.push(frame);
+                } else if let Some(kept) = self.frames.last_mut() {
+                    kept.delay_ms += frame.delay_ms;
+                }
+            }
+            self.frame_stride *= 2;
+        }
+        self.frames.push(self.render());
+    }
+
+    /// Delay the last frame before showing the next one.
+    pub fn meta_delay(&mut self, delay_ms: u16) {
+        if let Some(last) = self.frames.last_mut() {
+            last.delay_ms += u32::from(delay_ms);
+        }
+    }
+
+    /// Switch the layer drawn on, where layer 0 is the bottom layer and 1 is an overlay.
+    pub fn meta_switch_layer(&mut self, to_layer: u16) {
+        self.current_layer = usize::from(to_layer).min(LAYER_COUNT - 1);
+    }
+
+    /// Set the ratio between the width and height of the drawing area.
+    pub fn set_aspect_ratio(&mut self, width: i32, height: i32) {
+        if width > 0 && height > 0 {
+            self.aspect_ratio = f64::from(width) / f64::from(height);
+            self.resize();
+        }
+    }
+
+    pub const fn aspect_ratio(&self) -> f64 {
+        self.aspect_ratio
+    }
+}
+
+#[cfg(test)]
+fn pixel(frame: &Frame, x: u32, y: u32) -> [u8; 4] {
+    let offset = ((y * frame.width + x) * 4) as usize;
+    [
+        frame.pixels[offset],
+        frame.pixels[offset + 1],
+        frame.pixels[offset + 2],
+        frame.pixels[offset + 3],
+    ]
+}
+
+#[test]
+fn fill_and_blend() {
+    let mut painter = RasterPainter::new(10);
+    painter.set_aspect_ratio(2, 1);
+    assert_eq!((painter.width(), painter.height()), (20, 10));
+
+    painter.fill_style_rgb(255, 0, 0);
+    painter.fill_rect(0.25, 0.5, 0.25, 0.5);
+    painter.end_frame();
+
+    painter.meta_switch_layer(1);
+    painter.fill_style_rgba(0, 0, 255, 0.5);
+    painter.begin_path();
+    painter.move_to(0., 0.);
+    painter.line_to(1., 0.);
+    painter.line_to(1., 1.);
+    painter.fill();
+    painter.end_frame();
+    painter.meta_delay(100);
+
+    painter.clear();
+    painter.end_frame();
+
+    let frames = painter.into_frames();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(pixel(&frames[0], 5, 5), [255, 0, 0, 255]);
+    assert_eq!(pixel(&frames[0], 4, 5), [0, 0, 0, 255]);
+    assert_eq!(pixel(&frames[0], 5, 4), [0, 0, 0, 255]);
+    assert_eq!(pixel(&frames[0], 9, 9), [255, 0, 0, 255]);
+    assert_eq!(pixel(&frames[0], 10, 9), [0, 0, 0, 255]);
+
+    // The half transparent blue triangle above the diagonal is blended over the black background:
+    assert_eq!(pixel(&frames[1], 9, 0), [0, 0, 128, 255]);
+    assert_eq!(pixel(&frames[1], 5, 5), [255, 0, 0, 255]);
+    assert_eq!(pixel(&frames[1], 0, 9), [0, 0, 0, 255]);
+    assert_eq!(frames[1].delay_ms, DEFAULT_FRAME_DELAY_MS + 100);
+
+    assert!(frames[2].pixels.chunks(4).all(|p| p == [0, 0, 0, 255]));
+}
+
+#[test]
+fn stroke_and_arc() {
+    let mut painter = RasterPainter::new(100);
+    painter.stroke_style_rgb(0, 255, 0);
+    painter.line_width(0.02);
+    painter.begin_path();
+    painter.move_to(0.1, 0.5);
+    painter.line_to(0.9, 0.5);
+    painter.stroke();
+
+    painter.fill_style_rgb(255, 255, 255);
+    painter.begin_path();
+    painter.arc(0.5, 0.2, 0.1, 0., std::f64::consts::TAU);
+    painter.fill();
+    painter.end_frame();
+
+    let frame = &painter.frames()[0];
+    assert_eq!(pixel(frame, 50, 50), [0, 255, 0, 255]);
+    assert_eq!(pixel(frame, 50, 48), [0, 0, 0, 255]);
+    assert_eq!(pixel(frame, 5, 50), [0, 0, 0, 255]);
+    assert_eq!(pixel(frame, 50, 20), [255, 255, 255, 255]);
+    assert_eq!(pixel(frame, 58, 20), [255, 255, 255, 255]);
+    assert_eq!(pixel(frame, 61, 20), [0, 0, 0, 255]);
+    assert_eq!(pixel(frame, 50, 31), [0, 0, 0, 255]);
+}
+
+#[test]
+fn max_width() {
+    let mut painter = RasterPainter::new(100).with_max_width(100);
+    assert_eq!((painter.width(), painter.height()), (100, 100));
+    painter.set_aspect_ratio(4, 1);
+    assert_eq!((painter.width(), painter.height()), (100, 25));
+    painter.set_aspect_ratio(1, 4);
+    assert_eq!((painter.width(), painter.height()), (25, 100));
+}
+
+#[test]
+fn max_frames() {
+    let mut painter = RasterPainter::new(1).with_max_frames(4);
+    for _ in 0..10 {
+        painter.end_frame();
+    }
+    let frames = painter.into_frames();
+    // Frames 0, 2, 4 and 6 are kept when the limit is first hit, and then
+    // frames 0, 4 and 8 when it is hit again.
+    assert_eq!(frames.len(), 3);
+    let total_delay: u32 = frames.iter().map(|frame| frame.delay_ms).sum();
+    assert_eq!(total_delay, 10 * DEFAULT_FRAME_DELAY_MS);
+}
@@ -0,0 +1,229 @@
+use std::io::Write;
+
+use crate::escape::escape_xml;
+
+/// How values are interpolated between the key times of an [SvgAnimate].
+pub enum SvgCalcMode {
+    /// Jump from one value to the next without interpolation.
+    Discrete,
+    /// Linear interpolation between values, the default.
+    Linear,
+    /// Interpolation producing an even pace of change across the animation.
+    Paced,
+}
+
+/// Timing shared by the SMIL animation elements, with times in milliseconds.
+#[derive(Default)]
+struct Timing {
+    begin: Option<f64>,
+    duration: Option<f64>,
+    repeat_indefinitely: bool,
+    freeze: bool,
+}
+
+impl Timing {
+    fn write<W: Write>(&self, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        if let Some(begin) = self.begin {
+            writer
+                .write_all(format!(" begin=\"{begin}ms\"").as_bytes())
+                .unwrap();
+        }
+        if let Some(duration) = self.duration {
+            writer
+                .write_all(format!(" dur=\"{duration}ms\"").as_bytes())
+                .unwrap();
+        }
+        if self.repeat_indefinitely {
+            writer.write_all(b" repeatCount=\"indefinite\"").unwrap();
+        }
+        if self.freeze {
+            writer.write_all(b" fill=\"freeze\"").unwrap();
+        }
+    }
+}
+
+/// The SMIL `<animate>` element, animating an attribute of its parent element over time.
+///
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animate
+pub struct SvgAnimate {
+    attribute_name: String,
+    values: Vec<String>,
+    calc_mode: Option<SvgCalcMode>,
+    timing: Timing,
+}
+
+impl SvgAnimate {
+    pub fn new(attribute_name: impl Into<String>) -> Self {
+        Self {
+            attribute_name: attribute_name.into(),
+            values: Vec::new(),
+            calc_mode: None,
+            timing: Timing::default(),
+        }
+    }
+
+    /// The values the attribute takes, evenly spaced over the duration of the animation.
+    pub fn values<S: ToString>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.values = values.into_iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    pub const fn calc_mode(mut self, calc_mode: SvgCalcMode) -> Self {
+        self.calc_mode = Some(calc_mode);
+        self
+    }
+
+    /// The time, in milliseconds after the document has loaded, at which the animation starts.
+    pub const fn begin(mut self, begin_ms: f64) -> Self {
+        self.timing.begin = Some(begin_ms);
+        self
+    }
+
+    pub const fn duration(mut self, duration_ms: f64) -> Self {
+        self.timing.duration = Some(duration_ms);
+        self
+    }
+
+    pub const fn repeat_indefinitely(mut self) -> Self {
+        self.timing.repeat_indefinitely = true;
+        self
+    }
+
+    /// Keep the last value once the animation has ended, instead of reverting to the original.
+    pub const fn freeze(mut self) -> Self {
+        self.timing.freeze = true;
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer
+            .write_all(
+                format!(
+                    "<animate attributeName=\"{}\" values=\"{}\"",
+                    escape_xml(&self.attribute_name),
+                    escape_xml(&self.values.join(";"))
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+        if let Some(calc_mode) = &self.calc_mode {
+            writer
+                .write_all(match calc_mode {
+                    SvgCalcMode::Discrete => b" calcMode=\"discrete\"",
+                    SvgCalcMode::Linear => b" calcMode=\"linear\"",
+                    SvgCalcMode::Paced => b" calcMode=\"paced\"",
+                })
+                .unwrap();
+        }
+        self.timing.write(writer);
+        writer.write_all(b"/>").unwrap();
+    }
+}
+
+/// The SMIL `<set>` element, setting an attribute of its parent element for a duration of time.
+///
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Element/set
+pub struct SvgSet {
+    attribute_name: String,
+    to: String,
+    timing: Timing,
+}
+
+impl SvgSet {
+    pub fn new(attribute_name: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            attribute_name: attribute_name.into(),
+            to: to.into(),
+            timing: Timing::default(),
+        }
+    }
+
+    /// The time, in milliseconds after the document has loaded, at which the value is set.
+    pub const fn begin(mut self, begin_ms: f64) -> Self {
+        self.timing.begin = Some(begin_ms);
+        self
+    }
+
+    pub const fn duration(mut self, duration_ms: f64) -> Self {
+        self.timing.duration = Some(duration_ms);
+        self
+    }
+
+    /// Keep the value once the duration has ended, instead of reverting to the original.
+    pub const fn freeze(mut self) -> Self {
+        self.timing.freeze = true;
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer
+            .write_all(
+                format!(
+                    "<set attributeName=\"{}\" to=\"{}\"",
+                    escape_xml(&self.attribute_name),
+                    escape_xml(&self.to)
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+        self.timing.write(writer);
+        writer.write_all(b"/>").unwrap();
+    }
+}
+
+/// An animation added as a child of an element using its `animate()` builder method.
+pub enum SvgAnimation {
+    Animate(SvgAnimate),
+    Set(SvgSet),
+}
+
+impl From<SvgAnimate> for SvgAnimation {
+    fn from(value: SvgAnimate) -> Self {
+        Self::Animate(value)
+    }
+}
+
+impl From<SvgSet> for SvgAnimation {
+    fn from(value: SvgSet) -> Self {
+        Self::Set(value)
+    }
+}
+
+impl SvgAnimation {
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) {
+        match self {
+            Self::Animate(animate) => animate.write(writer),
+            Self::Set(set) => set.write(writer),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    use crate::{SvgColor, SvgRect};
+
+    let mut buffer = Vec::new();
+    SvgRect::default()
+        .width(1)
+        .height(1)
+        .fill(SvgColor::Rgb(0, 0, 0))
+        .animate(
+            SvgAnimate::new("x")
+                .values([0, 5, 10])
+                .calc_mode(SvgCalcMode::Discrete)
+                .duration(300.)
+                .repeat_indefinitely(),
+        )
+        .animate(SvgSet::new("fill", "url(#a)").begin(1500.).freeze())
+        .write(None, &mut buffer);
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"rgb(0, 0, 0)\">\
+         <animate attributeName=\"x\" values=\"0;5;10\" calcMode=\"discrete\" dur=\"300ms\" repeatCount=\"indefinite\"/>\
+         <set attributeName=\"fill\" to=\"url(#a)\" begin=\"1500ms\" fill=\"freeze\"/></rect>\n"
+    );
+}
@@ -0,0 +1,70 @@
+use std::io::Write;
+
+use crate::{SvgElement, SvgId};
+
+/// A region outside of which nothing is drawn, to be defined with [crate::SvgImage::define]
+/// and used with the `clip_path()` builder method of elements.
+#[derive(Default)]
+pub struct SvgClipPath {
+    pub(crate) elements: Vec<SvgElement>,
+}
+
+impl From<SvgClipPath> for SvgElement {
+    fn from(value: SvgClipPath) -> Self {
+        Self::ClipPath(value)
+    }
+}
+
+impl SvgClipPath {
+    pub const fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn with_elements<E: Into<SvgElement>>(elements: Vec<E>) -> Self {
+        Self {
+            elements: elements.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn add<E: Into<SvgElement>>(&mut self, element: E) -> &mut Self {
+        self.elements.push(element.into());
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer.write_all(b"<clipPath").unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        writer.write_all(b">\n").unwrap();
+        for element in &self.elements {
+            element.write(None, writer);
+        }
+        writer.write_all(b"</clipPath>\n").unwrap();
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{SvgCircle, SvgGroup, SvgImage, SvgRect};
+
+    let mut image = SvgImage::new();
+    let clip_path_id = image.define(SvgClipPath::with_elements(vec![SvgCircle {
+        cx: 5.,
+        cy: 5.,
+        r: 5.,
+        fill: None,
+    }]));
+    image.add(
+        SvgGroup::with_elements(vec![SvgRect::default().width(10).height(10)])
+            .clip_path(clip_path_id),
+    );
+    let svg = image.to_svg_string();
+    assert!(svg.contains(
+        "<defs><clipPath id=\"i0\">\n<circle cx=\"5\" cy=\"5\" r=\"5\"/>\n</clipPath>\n</defs>"
+    ));
+    assert!(svg.contains("<g clip-path=\"url(#i0)\">\n<rect"));
+}
@@ -1,10 +1,12 @@
 use std::io::Write;
 
+use crate::SvgId;
+
 /// - Named colors — orange
 /// - Hex colors — #FF9E2C
 /// - RGB and RGBa colors — Rgb(255, 158, 44) and Rgba(255, 158, 44, .5)
 /// - HSL and HSLa colors — hsl(32, 100%, 59%) and hsla(32, 100%, 59%, .5)
-/// - References to SVG patterns: url(#pattern-id)
+/// - References to SVG patterns and gradients: url(#pattern-id)
 #[derive(Copy, Clone)]
 pub enum SvgColor {
     Rgb(u8, u8, u8),
@@ -13,8 +15,10 @@ pub enum SvgColor {
     RgbaPercentage(f64, f64, f64, f64),
     // https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/hsl
     Hsl(f64, usize, usize),
+    /// A reference to a paint server such as a [crate::SvgLinearGradient] defined with
+    /// [crate::SvgImage::define].
+    Reference(SvgId),
     // TODO: hue
-    // TODO: pattern reference
 }
 
 impl SvgColor {
@@ -26,6 +30,70 @@ impl SvgColor {
         self.write_internal("stroke", writer);
     }
 
+    pub(crate) fn write_stop_color(&self, writer: &mut dyn Write) {
+        self.write_internal("stop-color", writer);
+    }
+
+    /// Parse a color as written by this crate.
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid color: '{value}'");
+        let arguments = |prefix: &str| -> Option<Vec<&str>> {
+            value
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(')'))
+                .map(|arguments| {
+                    arguments
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|argument| !argument.is_empty())
+                        .collect()
+                })
+        };
+        if value.starts_with("url(") {
+            SvgId::parse_url(value).map(Self::Reference)
+        } else if let Some(arguments) = arguments("rgb(") {
+            match arguments[..] {
+                [r, g, b] if r.ends_with('%') => {
+                    let percentage = |component: &str| {
+                        component
+                            .strip_suffix('%')
+                            .and_then(|number| number.parse::<f64>().ok())
+                            .ok_or_else(invalid)
+                    };
+                    Ok(Self::RgbPercentage(
+                        percentage(r)?,
+                        percentage(g)?,
+                        percentage(b)?,
+                    ))
+                }
+                [r, g, b] => {
+                    let component =
+                        |component: &str| component.parse::<u8>().map_err(|_| invalid());
+                    Ok(Self::Rgb(component(r)?, component(g)?, component(b)?))
+                }
+                _ => Err(invalid()),
+            }
+        } else if let Some(arguments) = arguments("hsl(") {
+            match arguments[..] {
+                [hue, saturation, lightness] => {
+                    let percentage = |component: &str| {
+                        component
+                            .strip_suffix('%')
+                            .and_then(|number| number.parse::<usize>().ok())
+                            .ok_or_else(invalid)
+                    };
+                    Ok(Self::Hsl(
+                        hue.parse().map_err(|_| invalid())?,
+                        percentage(saturation)?,
+                        percentage(lightness)?,
+                    ))
+                }
+                _ => Err(invalid()),
+            }
+        } else {
+            Err(invalid())
+        }
+    }
+
     fn write_internal(&self, what: &str, writer: &mut dyn Write) {
         #![allow(clippy::panic)]
         #![allow(clippy::unwrap_used)]
@@ -47,6 +115,11 @@ impl SvgColor {
                     )
                     .unwrap();
             }
+            Self::Reference(id) => {
+                writer
+                    .write_all(format!(" {what}=\"url(#{id})\"").as_bytes())
+                    .unwrap();
+            }
             _ => {
                 panic!("Unhandled fill");
             }
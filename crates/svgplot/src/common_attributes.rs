@@ -13,6 +13,8 @@ pub struct CommonAttributes {
     pub(crate) fill: Option<SvgColor>,
     pub(crate) stroke_linecap: Option<SvgStrokeLinecap>,
     pub(crate) title: Option<String>,
+    pub(crate) clip_path: Option<SvgId>,
+    pub(crate) animations: Vec<SvgAnimation>,
 }
 
 impl CommonAttributes {
@@ -24,6 +26,8 @@ impl CommonAttributes {
             fill: None,
             stroke_linecap: None,
             title: None,
+            clip_path: None,
+            animations: Vec::new(),
         }
     }
     pub(crate) fn write<W: Write>(&self, writer: &mut W) {
@@ -42,6 +46,11 @@ impl CommonAttributes {
         if let Some(stroke_linecap) = &self.stroke_linecap {
             stroke_linecap.write(writer);
         }
+        if let Some(clip_path) = &self.clip_path {
+            writer
+                .write_all(format!(" clip-path=\"url(#{clip_path})\"").as_bytes())
+                .unwrap();
+        }
         if !self.classes.is_empty() {
             writer.write_all(b" class=\"").unwrap();
             for (idx, class) in self.classes.iter().enumerate() {
@@ -52,6 +61,39 @@ impl CommonAttributes {
             writer.write_all(b"\"").unwrap();
         }
     }
+
+    /// Whether there are child elements to write, so that an end tag is needed.
+    pub(crate) fn has_children(&self) -> bool {
+        self.title.is_some() || !self.animations.is_empty()
+    }
+
+    /// Write the child elements, which are the title and animations.
+    pub(crate) fn write_children<W: Write>(&self, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        if let Some(title) = &self.title {
+            writer
+                .write_all(format!("<title>{}</title>", escape_xml(title)).as_bytes())
+                .unwrap();
+        }
+        for animation in &self.animations {
+            animation.write(writer);
+        }
+    }
+
+    /// End the start tag of an element without other content, either by self-closing it
+    /// or by writing the child elements followed by an end tag.
+    pub(crate) fn write_end<W: Write>(&self, tag_name: &str, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        if self.has_children() {
+            writer.write_all(b">").unwrap();
+            self.write_children(writer);
+            writer
+                .write_all(format!("</{tag_name}>\n").as_bytes())
+                .unwrap();
+        } else {
+            writer.write_all(b"/>\n").unwrap();
+        }
+    }
 }
 
 macro_rules! implement_common_attributes {
@@ -83,9 +125,27 @@ macro_rules! implement_common_attributes {
                 self.common_attributes.title = Some(title);
                 self
             }
+            /// Clip the element to a [crate::SvgClipPath] defined with [crate::SvgImage::define].
+            pub const fn clip_path(mut self, clip_path_id: crate::SvgId) -> Self {
+                self.common_attributes.clip_path = Some(clip_path_id);
+                self
+            }
+            #[allow(dead_code)]
+            pub(crate) fn with_common_attributes(
+                mut self,
+                common_attributes: CommonAttributes,
+            ) -> Self {
+                self.common_attributes = common_attributes;
+                self
+            }
+            /// Add an animation of an attribute of the element.
+            pub fn animate<A: Into<crate::SvgAnimation>>(mut self, animation: A) -> Self {
+                self.common_attributes.animations.push(animation.into());
+                self
+            }
         }
     };
 }
 
-use crate::{SvgColor, SvgStrokeLinecap, SvgTransform};
+use crate::{SvgAnimation, SvgColor, SvgId, SvgStrokeLinecap, SvgTransform};
 pub(crate) use implement_common_attributes;
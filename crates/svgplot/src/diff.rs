@@ -0,0 +1,264 @@
+//! Structural comparison of SVG documents, for golden tests of rendered images.
+use std::fmt::{Display, Formatter};
+
+use crate::xml::{parse_xml, XmlElement};
+
+/// A difference found by [diff_svg].
+pub struct SvgDifference {
+    /// The location of the difference, such as `svg/g[1]/path[0]@d` for the `d`
+    /// attribute of a path which is the first child element of a group, which in
+    /// turn is the second child element of the document. Indices count all child
+    /// elements, whatever their names.
+    pub path: String,
+    pub description: String,
+}
+
+impl Display for SvgDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.description)
+    }
+}
+
+enum Token<'a> {
+    Number(f64),
+    Text(&'a str),
+}
+
+/// Split a value into numbers and the text between them, ignoring whitespace.
+fn tokenize(value: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let bytes = value.as_bytes();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_number = bytes[i].is_ascii_digit()
+            || (matches!(bytes[i], b'-' | b'+' | b'.')
+                && bytes.get(i + 1).map_or(false, u8::is_ascii_digit)
+                // A hyphen inside a name, such as in "x-1", is not a sign:
+                && !(bytes[i] == b'-' && i > 0 && bytes[i - 1].is_ascii_alphabetic()));
+        if !starts_number {
+            i += 1;
+            continue;
+        }
+
+        let mut end = i + 1;
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+            end += 1;
+        }
+        if end + 1 < bytes.len()
+            && matches!(bytes[end], b'e' | b'E')
+            && (bytes[end + 1].is_ascii_digit()
+                || (matches!(bytes[end + 1], b'-' | b'+')
+                    && bytes.get(end + 2).map_or(false, u8::is_ascii_digit)))
+        {
+            end += 2;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+        // Something like "1.2.3" is not a number, and is left as text:
+        if let Ok(number) = value[i..end].parse() {
+            let text = value[text_start..i].trim();
+            if !text.is_empty() {
+                tokens.push(Token::Text(text));
+            }
+            tokens.push(Token::Number(number));
+            text_start = end;
+        }
+        i = end;
+    }
+    let text = value[text_start..].trim();
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    tokens
+}
+
+/// Whether two values are equal, allowing numbers to be differently formatted and slightly
+/// different, such as `0.1` and `0.09999999999999998`.
+fn values_equal(expected: &str, actual: &str) -> bool {
+    if expected == actual {
+        return true;
+    }
+    let (expected, actual) = (tokenize(expected), tokenize(actual));
+    expected.len() == actual.len()
+        && expected.iter().zip(actual.iter()).all(|pair| match pair {
+            (Token::Number(a), Token::Number(b)) => {
+                (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.)
+            }
+            (Token::Text(a), Token::Text(b)) => a.split_whitespace().eq(b.split_whitespace()),
+            _ => false,
+        })
+}
+
+fn truncate(value: &str) -> String {
+    const MAX_LENGTH: usize = 60;
+    match value.char_indices().nth(MAX_LENGTH) {
+        Some((index, _)) => format!("'{}...'", &value[..index]),
+        None => format!("'{value}'"),
+    }
+}
+
+fn diff_elements(
+    path: &str,
+    expected: &XmlElement,
+    actual: &XmlElement,
+    differences: &mut Vec<SvgDifference>,
+) {
+    let mut report = |path: String, description: String| {
+        differences.push(SvgDifference { path, description });
+    };
+
+    for (name, expected_value) in &expected.attributes {
+        let attribute_path = format!("{path}@{name}");
+        match actual.attribute(name) {
+            None => report(attribute_path, "missing attribute".to_string()),
+            Some(actual_value) if !values_equal(expected_value, actual_value) => report(
+                attribute_path,
+                format!(
+                    "expected {}, found {}",
+                    truncate(expected_value),
+                    truncate(actual_value)
+                ),
+            ),
+            _ => {}
+        }
+    }
+    for (name, _) in &actual.attributes {
+        if expected.attribute(name).is_none() {
+            report(format!("{path}@{name}"), "unexpected attribute".to_string());
+        }
+    }
+
+    let (expected_text, actual_text) = (expected.text(), actual.text());
+    if !values_equal(&expected_text, &actual_text) {
+        report(
+            format!("{path}/text()"),
+            format!(
+                "expected {}, found {}",
+                truncate(expected_text.trim()),
+                truncate(actual_text.trim())
+            ),
+        );
+    }
+
+    let expected_children: Vec<_> = expected.child_elements().collect();
+    let actual_children: Vec<_> = actual.child_elements().collect();
+    for (index, (expected_child, actual_child)) in expected_children
+        .iter()
+        .zip(actual_children.iter())
+        .enumerate()
+    {
+        let child_path = format!("{path}/{}[{index}]", expected_child.name);
+        if expected_child.name == actual_child.name {
+            diff_elements(&child_path, expected_child, actual_child, differences);
+        } else {
+            differences.push(SvgDifference {
+                path: child_path,
+                description: format!(
+                    "expected <{}>, found <{}>",
+                    expected_child.name, actual_child.name
+                ),
+            });
+        }
+    }
+    let common_length = expected_children.len().min(actual_children.len());
+    for (index, child) in expected_children.iter().enumerate().skip(common_length) {
+        differences.push(SvgDifference {
+            path: format!("{path}/{}[{index}]", child.name),
+            description: "missing element".to_string(),
+        });
+    }
+    for (index, child) in actual_children.iter().enumerate().skip(common_length) {
+        differences.push(SvgDifference {
+            path: format!("{path}/{}[{index}]", child.name),
+            description: "unexpected element".to_string(),
+        });
+    }
+}
+
+/// Compare two SVG documents element by element and attribute by attribute, returning the
+/// differences found.
+///
+/// Attribute order, whitespace between elements and in values, and the formatting of numbers
+/// are not significant, and numbers are compared with a small relative tolerance.
+pub fn diff_svg(expected: &str, actual: &str) -> Result<Vec<SvgDifference>, String> {
+    let expected = parse_xml(expected).map_err(|error| format!("Invalid expected SVG: {error}"))?;
+    let actual = parse_xml(actual).map_err(|error| format!("Invalid actual SVG: {error}"))?;
+    let mut differences = Vec::new();
+    if expected.name == actual.name {
+        diff_elements(&expected.name, &expected, &actual, &mut differences);
+    } else {
+        differences.push(SvgDifference {
+            path: String::new(),
+            description: format!("expected <{}>, found <{}>", expected.name, actual.name),
+        });
+    }
+    Ok(differences)
+}
+
+/// Assert that an SVG document matches a golden one, as compared by [diff_svg], and that it is
+/// written back unchanged after being parsed, panicking with the differences otherwise.
+#[track_caller]
+pub fn assert_matches_golden(actual: &str, golden: &str) {
+    let differences = match diff_svg(golden, actual) {
+        Ok(differences) => differences.iter().map(ToString::to_string).collect(),
+        Err(error) => vec![error],
+    };
+    assert!(
+        differences.is_empty(),
+        "SVG differs from the golden one:\n{}",
+        differences.join("\n")
+    );
+    let written_back = crate::SvgImage::parse(actual).map(|image| image.to_svg_string());
+    assert_eq!(written_back.as_deref(), Ok(actual));
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let diff = |expected: &str, actual: &str| -> Vec<String> {
+        diff_svg(expected, actual)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    };
+
+    assert!(diff(
+        "<svg a=\"1\" b=\"x\"><path d=\"M 0.1 2L 3 4\"/>\n<text>A  B</text></svg>",
+        "<svg b=\"x\" a=\"1.0\">\n<path d=\"M 0.09999999999999998 2 L 3e0 4\"/><text>A B</text></svg>",
+    )
+    .is_empty());
+
+    assert_eq!(
+        diff(
+            "<svg a=\"1\" b=\"x\"><g><rect/><circle r=\"1\"/></g><style>a</style></svg>",
+            "<svg a=\"2\" c=\"y\"><g><circle r=\"1.5\"/></g><script/><path/></svg>",
+        ),
+        [
+            "svg@a: expected '1', found '2'",
+            "svg@b: missing attribute",
+            "svg@c: unexpected attribute",
+            "svg/g[0]/rect[0]: expected <rect>, found <circle>",
+            "svg/g[0]/circle[1]: missing element",
+            "svg/style[1]: expected <style>, found <script>",
+            "svg/path[2]: unexpected element",
+        ]
+    );
+
+    assert_eq!(
+        diff("<svg>1 2</svg>", "<svg>12</svg>"),
+        ["svg/text(): expected '1 2', found '12'"]
+    );
+    assert_eq!(diff("<svg/>", "<g/>"), [": expected <svg>, found <g>"]);
+    assert!(diff_svg("<svg>", "<svg/>").is_err());
+}
+
+#[test]
+fn test_assert_matches_golden() {
+    let svg = crate::SvgImage::new().view_box((0, 0, 1, 1)).to_svg_string();
+    assert_matches_golden(&svg, &svg.replace(" viewBox", "\n viewBox"));
+    let mismatch = std::panic::catch_unwind(|| assert_matches_golden(&svg, "<svg/>"));
+    assert!(mismatch.is_err());
+}
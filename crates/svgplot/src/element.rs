@@ -1,4 +1,7 @@
-use crate::{SvgCircle, SvgGroup, SvgId, SvgPath, SvgRect, SvgScript, SvgStyle, SvgUse};
+use crate::{
+    SvgCircle, SvgClipPath, SvgGroup, SvgId, SvgLine, SvgLinearGradient, SvgPath, SvgPolygon,
+    SvgPolyline, SvgRadialGradient, SvgRect, SvgScript, SvgStyle, SvgText, SvgUse,
+};
 use std::io::Write;
 
 pub enum SvgElement {
@@ -9,6 +12,13 @@ pub enum SvgElement {
     Path(SvgPath),
     Style(SvgStyle),
     Use(SvgUse),
+    Text(SvgText),
+    Line(SvgLine),
+    Polyline(SvgPolyline),
+    Polygon(SvgPolygon),
+    LinearGradient(SvgLinearGradient),
+    RadialGradient(SvgRadialGradient),
+    ClipPath(SvgClipPath),
 }
 
 impl SvgElement {
@@ -35,6 +45,27 @@ impl SvgElement {
             Self::Use(svg_use) => {
                 svg_use.write(id, writer);
             }
+            Self::Text(text) => {
+                text.write(id, writer);
+            }
+            Self::Line(line) => {
+                line.write(id, writer);
+            }
+            Self::Polyline(polyline) => {
+                polyline.write(id, writer);
+            }
+            Self::Polygon(polygon) => {
+                polygon.write(id, writer);
+            }
+            Self::LinearGradient(gradient) => {
+                gradient.write(id, writer);
+            }
+            Self::RadialGradient(gradient) => {
+                gradient.write(id, writer);
+            }
+            Self::ClipPath(clip_path) => {
+                clip_path.write(id, writer);
+            }
         }
     }
 }
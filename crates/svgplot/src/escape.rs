@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 pub fn escape_xml(input: &str) -> Cow<str> {
-    for (i, ch) in input.chars().enumerate() {
+    for (i, ch) in input.char_indices() {
         if xml_escape_char(ch).is_some() {
             let mut escaped_string = String::with_capacity(input.len());
             escaped_string.push_str(&input[..i]);
@@ -17,9 +17,41 @@ pub fn escape_xml(input: &str) -> Cow<str> {
     Cow::Borrowed(input)
 }
 
+/// Replace the predefined XML entities and character references with the characters they denote.
+pub fn unescape_xml(input: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(';')
+            .ok_or_else(|| format!("Unterminated entity in '{input}'"))?;
+        let entity = &rest[1..end];
+        let ch = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("Unknown entity '&{entity};'"))?,
+        };
+        result.push(ch);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
 const fn xml_escape_char(ch: char) -> Option<&'static str> {
     match ch {
-        '&' => Some("&amp"),
+        '&' => Some("&amp;"),
         '<' => Some("&lt;"),
         '>' => Some("&gt;"),
         '"' => Some("&quot;"),
@@ -42,3 +74,22 @@ fn escapes_text() {
     let escaped = escape_xml(input);
     assert_eq!(escaped, expected);
 }
+
+#[test]
+fn escapes_ampersand() {
+    assert_eq!(escape_xml("a & b"), "a &amp; b");
+}
+
+#[test]
+fn unescapes_text() {
+    #![allow(clippy::unwrap_used)]
+    assert_eq!(
+        unescape_xml("&lt;a&gt; &amp;&amp; &quot;b&quot; &#65;&#x42;").unwrap(),
+        "<a> && \"b\" AB"
+    );
+    assert_eq!(unescape_xml("plain").unwrap(), "plain");
+    assert!(unescape_xml("&unknown;").is_err());
+    assert!(unescape_xml("&amp").is_err());
+    let original = "This is a <script>alert('nasty' & \"more\");</script> string";
+    assert_eq!(unescape_xml(&escape_xml(original)).unwrap(), original);
+}
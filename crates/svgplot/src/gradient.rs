@@ -0,0 +1,196 @@
+use std::io::Write;
+
+use crate::{Coordinate, SvgColor, SvgElement, SvgId};
+
+/// A color at a position along a gradient.
+pub struct SvgGradientStop {
+    /// The position along the gradient, from 0 to 1.
+    pub offset: f64,
+    pub color: SvgColor,
+    pub opacity: Option<f64>,
+}
+
+impl SvgGradientStop {
+    pub const fn new(offset: f64, color: SvgColor) -> Self {
+        Self {
+            offset,
+            color,
+            opacity: None,
+        }
+    }
+
+    pub const fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer
+            .write_all(format!("<stop offset=\"{}\"", self.offset).as_bytes())
+            .unwrap();
+        self.color.write_stop_color(writer);
+        if let Some(opacity) = self.opacity {
+            writer
+                .write_all(format!(" stop-opacity=\"{opacity}\"").as_bytes())
+                .unwrap();
+        }
+        writer.write_all(b"/>").unwrap();
+    }
+}
+
+/// A gradient along a line, to be defined with [crate::SvgImage::define] and used
+/// with [SvgColor::Reference].
+///
+/// Coordinates are fractions of the bounding box of the element being painted.
+#[derive(Default)]
+pub struct SvgLinearGradient {
+    start: Option<(Coordinate, Coordinate)>,
+    end: Option<(Coordinate, Coordinate)>,
+    stops: Vec<SvgGradientStop>,
+}
+
+impl From<SvgLinearGradient> for SvgElement {
+    fn from(value: SvgLinearGradient) -> Self {
+        Self::LinearGradient(value)
+    }
+}
+
+impl SvgLinearGradient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Where the first stop is positioned, by default the left edge.
+    pub fn start<C: Into<Coordinate>>(mut self, x: C, y: C) -> Self {
+        self.start = Some((x.into(), y.into()));
+        self
+    }
+
+    /// Where the last stop is positioned, by default the right edge.
+    pub fn end<C: Into<Coordinate>>(mut self, x: C, y: C) -> Self {
+        self.end = Some((x.into(), y.into()));
+        self
+    }
+
+    pub fn stop(mut self, stop: SvgGradientStop) -> Self {
+        self.stops.push(stop);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer.write_all(b"<linearGradient").unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        if let Some((x1, y1)) = self.start {
+            writer
+                .write_all(format!(" x1=\"{x1}\" y1=\"{y1}\"").as_bytes())
+                .unwrap();
+        }
+        if let Some((x2, y2)) = self.end {
+            writer
+                .write_all(format!(" x2=\"{x2}\" y2=\"{y2}\"").as_bytes())
+                .unwrap();
+        }
+        writer.write_all(b">").unwrap();
+        for stop in &self.stops {
+            stop.write(writer);
+        }
+        writer.write_all(b"</linearGradient>\n").unwrap();
+    }
+}
+
+/// A gradient radiating from a center, to be defined with [crate::SvgImage::define] and
+/// used with [SvgColor::Reference].
+///
+/// Coordinates are fractions of the bounding box of the element being painted.
+#[derive(Default)]
+pub struct SvgRadialGradient {
+    center: Option<(Coordinate, Coordinate)>,
+    radius: Option<Coordinate>,
+    stops: Vec<SvgGradientStop>,
+}
+
+impl From<SvgRadialGradient> for SvgElement {
+    fn from(value: SvgRadialGradient) -> Self {
+        Self::RadialGradient(value)
+    }
+}
+
+impl SvgRadialGradient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The center of the gradient, by default the center of the bounding box.
+    pub fn center<C: Into<Coordinate>>(mut self, x: C, y: C) -> Self {
+        self.center = Some((x.into(), y.into()));
+        self
+    }
+
+    /// The radius at which the last stop is positioned, by default 0.5.
+    pub fn radius<C: Into<Coordinate>>(mut self, radius: C) -> Self {
+        self.radius = Some(radius.into());
+        self
+    }
+
+    pub fn stop(mut self, stop: SvgGradientStop) -> Self {
+        self.stops.push(stop);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer.write_all(b"<radialGradient").unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        if let Some((cx, cy)) = self.center {
+            writer
+                .write_all(format!(" cx=\"{cx}\" cy=\"{cy}\"").as_bytes())
+                .unwrap();
+        }
+        if let Some(r) = self.radius {
+            writer.write_all(format!(" r=\"{r}\"").as_bytes()).unwrap();
+        }
+        writer.write_all(b">").unwrap();
+        for stop in &self.stops {
+            stop.write(writer);
+        }
+        writer.write_all(b"</radialGradient>\n").unwrap();
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{SvgImage, SvgRect};
+
+    let mut image = SvgImage::new();
+    let linear_id = image.define(
+        SvgLinearGradient::new()
+            .end(0, 1)
+            .stop(SvgGradientStop::new(0., SvgColor::Rgb(255, 0, 0)))
+            .stop(SvgGradientStop::new(1., SvgColor::Rgb(0, 0, 255)).opacity(0.5)),
+    );
+    let radial_id = image.define(
+        SvgRadialGradient::new()
+            .center(0.5, 0.25)
+            .radius(1)
+            .stop(SvgGradientStop::new(0., SvgColor::Rgb(255, 255, 255))),
+    );
+    image.add(SvgRect::default().fill(SvgColor::Reference(linear_id)));
+    image.add(SvgRect::default().fill(SvgColor::Reference(radial_id)));
+
+    let svg = image.to_svg_string();
+    assert!(svg.contains(
+        "<defs><linearGradient id=\"i0\" x2=\"0\" y2=\"1\">\
+         <stop offset=\"0\" stop-color=\"rgb(255, 0, 0)\"/>\
+         <stop offset=\"1\" stop-color=\"rgb(0, 0, 255)\" stop-opacity=\"0.5\"/></linearGradient>\n\
+         <radialGradient id=\"i1\" cx=\"0.5\" cy=\"0.25\" r=\"1\">\
+         <stop offset=\"0\" stop-color=\"rgb(255, 255, 255)\"/></radialGradient>\n</defs>"
+    ));
+    assert!(svg.contains("fill=\"url(#i0)\""));
+    assert!(svg.contains("fill=\"url(#i1)\""));
+}
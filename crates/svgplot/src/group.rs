@@ -44,6 +44,7 @@ impl SvgGroup {
         }
         self.common_attributes.write(writer);
         writer.write_all(b">\n").unwrap();
+        self.common_attributes.write_children(writer);
         for element in &self.elements {
             element.write(None, writer);
         }
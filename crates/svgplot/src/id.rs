@@ -15,6 +15,26 @@ impl SvgId {
     }
 }
 
+impl SvgId {
+    /// Parse an id as written in an id attribute.
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        value
+            .strip_prefix('i')
+            .and_then(|number| number.parse().ok())
+            .map(|value| Self { value })
+            .ok_or_else(|| format!("Invalid id: '{value}'"))
+    }
+
+    /// Parse a reference to an id on the form `url(#i3)`.
+    pub(crate) fn parse_url(value: &str) -> Result<Self, String> {
+        value
+            .strip_prefix("url(#")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(|| format!("Invalid reference: '{value}'"))
+            .and_then(Self::parse)
+    }
+}
+
 impl Display for SvgId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "i{}", self.value)
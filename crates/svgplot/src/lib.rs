@@ -1,38 +1,56 @@
 use std::io::Write;
 
 use crate::escape::escape_xml;
+pub use animate::*;
 pub use circle::*;
+pub use clip_path::*;
 pub use color::*;
 use common_attributes::*;
+pub use diff::*;
 pub use element::*;
+pub use gradient::*;
 pub use group::*;
 pub use id::*;
+pub use line::*;
 pub use path::*;
+pub use polygon::*;
+pub use polyline::*;
 pub use rect::*;
 pub use script::*;
 pub use stroke::*;
 pub use style::*;
 pub use svg_use::*;
 pub use symbol::*;
+pub use text::*;
 pub use transform::*;
 pub use view_box::*;
 
+pub mod animate;
 pub mod circle;
+pub mod clip_path;
 pub mod color;
 pub mod common_attributes;
+pub mod diff;
 pub mod element;
 pub(crate) mod escape;
+pub mod gradient;
 pub mod group;
 pub mod id;
+pub mod line;
+pub mod parse;
 pub mod path;
+pub mod polygon;
+pub mod polyline;
 pub mod rect;
 pub mod script;
 pub mod stroke;
 pub mod style;
 pub mod svg_use;
 pub mod symbol;
+pub mod text;
 pub mod transform;
 pub mod view_box;
+mod xml;
 
 pub type Coordinate = f64;
 
@@ -128,12 +146,13 @@ impl SvgImage {
         for (name, value) in &self.data_attributes {
             buffer
                 .write_all(
-                    format!("data-{}=\"{}\"", escape_xml(name), escape_xml(value)).as_bytes(),
+                    format!(" data-{}=\"{}\"", escape_xml(name), escape_xml(value)).as_bytes(),
                 )
                 .unwrap();
         }
         self.common_attributes.write(&mut buffer);
         buffer.write_all(&[b'>', b'\n']).unwrap();
+        self.common_attributes.write_children(&mut buffer);
 
         let mut first = true;
         for (id, element) in &self.elements {
@@ -0,0 +1,87 @@
+use std::io::Write;
+
+use crate::common_attributes::{implement_common_attributes, CommonAttributes};
+use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
+
+/// A straight line between two points.
+#[derive(Default)]
+pub struct SvgLine {
+    pub x1: Coordinate,
+    pub y1: Coordinate,
+    pub x2: Coordinate,
+    pub y2: Coordinate,
+    pub stroke: Option<SvgColor>,
+    pub stroke_width: Option<f64>,
+    common_attributes: CommonAttributes,
+}
+
+implement_common_attributes!(SvgLine);
+
+impl From<SvgLine> for SvgElement {
+    fn from(value: SvgLine) -> Self {
+        Self::Line(value)
+    }
+}
+
+impl SvgLine {
+    pub fn new<C: Into<Coordinate>>(x1: C, y1: C, x2: C, y2: C) -> Self {
+        Self {
+            x1: x1.into(),
+            y1: y1.into(),
+            x2: x2.into(),
+            y2: y2.into(),
+            ..Default::default()
+        }
+    }
+
+    pub const fn stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    pub const fn stroke(mut self, color: SvgColor) -> Self {
+        self.stroke = Some(color);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer
+            .write_all(
+                format!(
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+                    self.x1, self.y1, self.x2, self.y2
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        if let Some(stroke) = &self.stroke {
+            stroke.write_stroke(writer);
+        }
+        if let Some(stroke_width) = &self.stroke_width {
+            writer
+                .write_all(format!(" stroke-width=\"{stroke_width}\"").as_bytes())
+                .unwrap();
+        }
+        self.common_attributes.write(writer);
+        self.common_attributes.write_end("line", writer);
+    }
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let mut buffer = Vec::new();
+    SvgLine::new(0, 1, 2, 3)
+        .stroke(SvgColor::Rgb(255, 0, 0))
+        .stroke_width(0.5)
+        .stroke_linecap(SvgStrokeLinecap::Round)
+        .write(None, &mut buffer);
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "<line x1=\"0\" y1=\"1\" x2=\"2\" y2=\"3\" stroke=\"rgb(255, 0, 0)\" stroke-width=\"0.5\" stroke-linecap=\"round\"/>\n"
+    );
+}
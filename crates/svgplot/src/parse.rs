@@ -0,0 +1,423 @@
+//! Parsing of SVG documents written by this crate back into an [SvgImage].
+use crate::common_attributes::CommonAttributes;
+use crate::xml::{parse_xml, XmlElement};
+use crate::{
+    Coordinate, OptionalSvgId, SvgAnimate, SvgAnimation, SvgCalcMode, SvgCircle, SvgClipPath,
+    SvgColor, SvgDominantBaseline, SvgElement, SvgGradientStop, SvgGroup, SvgId, SvgImage, SvgLine,
+    SvgLinearGradient, SvgPath, SvgPolygon, SvgPolyline, SvgRadialGradient, SvgRect, SvgScript,
+    SvgSet, SvgShape, SvgStrokeLinecap, SvgStyle, SvgText, SvgTextAnchor, SvgTransform, SvgUse,
+    ViewBox,
+};
+
+impl SvgImage {
+    /// Parse a SVG document as written by [SvgImage::to_svg_string].
+    ///
+    /// Only the elements and attributes which this crate writes are supported, and other
+    /// elements result in an error while other attributes are ignored.
+    pub fn parse(svg: &str) -> Result<Self, String> {
+        let root = parse_xml(svg)?;
+        if root.name != "svg" {
+            return Err(format!(
+                "Expected <svg> root element, found <{}>",
+                root.name
+            ));
+        }
+
+        let mut image = Self::new();
+        if let (Some(x), Some(y)) = (root.attribute("x"), root.attribute("y")) {
+            image.dimensions = Some((parse_number(x)?, parse_number(y)?));
+        }
+        if let Some(view_box) = root.attribute("viewBox") {
+            let numbers = view_box
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<Vec<_>, _>>()?;
+            match numbers[..] {
+                [min_x, min_y, width, height] => {
+                    image.view_box = Some(ViewBox::from((min_x, min_y, width, height)));
+                }
+                _ => return Err(format!("Invalid viewBox: '{view_box}'")),
+            }
+        }
+        for (name, value) in &root.attributes {
+            if let Some(name) = name.strip_prefix("data-") {
+                image
+                    .data_attributes
+                    .push((name.to_string(), value.to_string()));
+            }
+        }
+        image.common_attributes = parse_common_attributes(&root)?;
+
+        let mut max_id = None;
+        let mut add = |id: Option<SvgId>, element: SvgElement, is_definition: bool| {
+            image.elements.push((
+                match id {
+                    Some(id) if is_definition => OptionalSvgId::Def(id),
+                    Some(id) => OptionalSvgId::Some(id),
+                    None => OptionalSvgId::None,
+                },
+                element,
+            ));
+            if let Some(id) = id {
+                max_id = max_id.max(Some(id.value));
+            }
+        };
+        for child in root.child_elements() {
+            if child.name == "defs" {
+                for definition in child.child_elements() {
+                    let (id, element) = parse_element(definition)?;
+                    add(id, element, true);
+                }
+            } else if !is_common_child(child) {
+                let (id, element) = parse_element(child)?;
+                add(id, element, false);
+            }
+        }
+        image.id_sequence = max_id.map_or(0, |id| id + 1);
+        Ok(image)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number: '{value}'"))
+}
+
+fn parse_milliseconds(value: &str) -> Result<f64, String> {
+    value
+        .strip_suffix("ms")
+        .ok_or_else(|| format!("Expected milliseconds: '{value}'"))
+        .and_then(parse_number)
+}
+
+fn attribute<'a>(element: &'a XmlElement, name: &str) -> Result<&'a str, String> {
+    element
+        .attribute(name)
+        .ok_or_else(|| format!("Missing attribute '{name}' on <{}>", element.name))
+}
+
+fn optional<T>(
+    element: &XmlElement,
+    name: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    element.attribute(name).map(parse).transpose()
+}
+
+fn coordinate(element: &XmlElement, name: &str) -> Result<Coordinate, String> {
+    parse_number(attribute(element, name)?)
+}
+
+/// Whether an element is a child written from the common attributes of its parent.
+fn is_common_child(element: &XmlElement) -> bool {
+    matches!(element.name.as_str(), "title" | "animate" | "set")
+}
+
+fn parse_common_attributes(element: &XmlElement) -> Result<CommonAttributes, String> {
+    let mut common_attributes = CommonAttributes::new();
+    for (name, value) in &element.attributes {
+        match name.as_str() {
+            "style" => match SvgTransform::parse_style(value) {
+                Some(transform) => common_attributes.transform = Some(transform?),
+                None => common_attributes.style = Some(value.clone()),
+            },
+            "class" => {
+                common_attributes.classes = value.split_whitespace().map(String::from).collect();
+            }
+            "fill" => common_attributes.fill = Some(SvgColor::parse(value)?),
+            "stroke-linecap" => {
+                common_attributes.stroke_linecap = Some(SvgStrokeLinecap::parse(value)?);
+            }
+            "clip-path" => common_attributes.clip_path = Some(SvgId::parse_url(value)?),
+            _ => {}
+        }
+    }
+    for child in element.child_elements() {
+        match child.name.as_str() {
+            "title" => common_attributes.title = Some(child.text()),
+            "animate" | "set" => common_attributes.animations.push(parse_animation(child)?),
+            _ => {}
+        }
+    }
+    Ok(common_attributes)
+}
+
+fn parse_animation(element: &XmlElement) -> Result<SvgAnimation, String> {
+    let attribute_name = attribute(element, "attributeName")?;
+    let begin = optional(element, "begin", parse_milliseconds)?;
+    let duration = optional(element, "dur", parse_milliseconds)?;
+    let freeze = element.attribute("fill") == Some("freeze");
+
+    if element.name == "set" {
+        let mut set = SvgSet::new(attribute_name, attribute(element, "to")?);
+        if let Some(begin) = begin {
+            set = set.begin(begin);
+        }
+        if let Some(duration) = duration {
+            set = set.duration(duration);
+        }
+        if freeze {
+            set = set.freeze();
+        }
+        return Ok(set.into());
+    }
+
+    let mut animate =
+        SvgAnimate::new(attribute_name).values(attribute(element, "values")?.split(';'));
+    if let Some(calc_mode) = element.attribute("calcMode") {
+        animate = animate.calc_mode(match calc_mode {
+            "discrete" => SvgCalcMode::Discrete,
+            "linear" => SvgCalcMode::Linear,
+            "paced" => SvgCalcMode::Paced,
+            _ => return Err(format!("Invalid calcMode: '{calc_mode}'")),
+        });
+    }
+    if let Some(begin) = begin {
+        animate = animate.begin(begin);
+    }
+    if let Some(duration) = duration {
+        animate = animate.duration(duration);
+    }
+    if element.attribute("repeatCount") == Some("indefinite") {
+        animate = animate.repeat_indefinitely();
+    }
+    if freeze {
+        animate = animate.freeze();
+    }
+    Ok(animate.into())
+}
+
+fn parse_points(value: &str) -> Result<Vec<(Coordinate, Coordinate)>, String> {
+    value
+        .split_whitespace()
+        .map(|point| match point.split_once(',') {
+            Some((x, y)) => Ok((parse_number(x)?, parse_number(y)?)),
+            None => Err(format!("Invalid point: '{point}'")),
+        })
+        .collect()
+}
+
+fn parse_stops(element: &XmlElement) -> Result<Vec<SvgGradientStop>, String> {
+    element
+        .child_elements()
+        .map(|stop| {
+            let mut gradient_stop = SvgGradientStop::new(
+                coordinate(stop, "offset")?,
+                SvgColor::parse(attribute(stop, "stop-color")?)?,
+            );
+            if let Some(opacity) = optional(stop, "stop-opacity", parse_number)? {
+                gradient_stop = gradient_stop.opacity(opacity);
+            }
+            Ok(gradient_stop)
+        })
+        .collect()
+}
+
+fn parse_children(element: &XmlElement) -> Result<Vec<SvgElement>, String> {
+    element
+        .child_elements()
+        .filter(|child| !is_common_child(child))
+        .map(|child| parse_element(child).map(|(_, element)| element))
+        .collect()
+}
+
+fn parse_element(element: &XmlElement) -> Result<(Option<SvgId>, SvgElement), String> {
+    let id = optional(element, "id", SvgId::parse)?;
+    let stroke = optional(element, "stroke", SvgColor::parse)?;
+    let stroke_width = optional(element, "stroke-width", parse_number)?;
+    let common_attributes = parse_common_attributes(element)?;
+
+    let parsed: SvgElement = match element.name.as_str() {
+        "rect" => SvgRect::default()
+            .x(coordinate(element, "x")?)
+            .y(coordinate(element, "y")?)
+            .width(coordinate(element, "width")?)
+            .height(coordinate(element, "height")?)
+            .with_common_attributes(common_attributes)
+            .into(),
+        "circle" => SvgCircle {
+            cx: coordinate(element, "cx")?,
+            cy: coordinate(element, "cy")?,
+            r: coordinate(element, "r")?,
+            fill: common_attributes.fill,
+        }
+        .into(),
+        "script" => SvgScript::new(element.text()).into(),
+        "style" => SvgStyle::new(element.text()).into(),
+        "g" => SvgGroup::with_elements(parse_children(element)?)
+            .with_common_attributes(common_attributes)
+            .into(),
+        "clipPath" => SvgClipPath::with_elements(parse_children(element)?).into(),
+        "path" => SvgPath {
+            shape: SvgShape::parse(attribute(element, "d")?)?,
+            stroke,
+            stroke_width,
+            common_attributes,
+        }
+        .into(),
+        "use" => {
+            let href = attribute(element, "href")?;
+            let referenced_id = href
+                .strip_prefix('#')
+                .ok_or_else(|| format!("Invalid href: '{href}'"))
+                .and_then(SvgId::parse)?;
+            let mut svg_use = SvgUse::new(referenced_id).with_common_attributes(common_attributes);
+            if let Some(x) = optional(element, "x", parse_number)? {
+                svg_use = svg_use.x(x);
+            }
+            if let Some(y) = optional(element, "y", parse_number)? {
+                svg_use = svg_use.y(y);
+            }
+            svg_use.into()
+        }
+        "text" => {
+            let mut text = SvgText::new(element.text());
+            text.x = coordinate(element, "x")?;
+            text.y = coordinate(element, "y")?;
+            text.font_size = optional(element, "font-size", parse_number)?;
+            text.font_family = element.attribute("font-family").map(String::from);
+            text.text_anchor = optional(element, "text-anchor", SvgTextAnchor::parse)?;
+            text.dominant_baseline =
+                optional(element, "dominant-baseline", SvgDominantBaseline::parse)?;
+            text.with_common_attributes(common_attributes).into()
+        }
+        "line" => {
+            let mut line = SvgLine::new(
+                coordinate(element, "x1")?,
+                coordinate(element, "y1")?,
+                coordinate(element, "x2")?,
+                coordinate(element, "y2")?,
+            );
+            line.stroke = stroke;
+            line.stroke_width = stroke_width;
+            line.with_common_attributes(common_attributes).into()
+        }
+        "polyline" => {
+            let mut polyline =
+                SvgPolyline::with_points(parse_points(attribute(element, "points")?)?);
+            polyline.stroke = stroke;
+            polyline.stroke_width = stroke_width;
+            polyline.with_common_attributes(common_attributes).into()
+        }
+        "polygon" => {
+            let mut polygon = SvgPolygon::with_points(parse_points(attribute(element, "points")?)?);
+            polygon.stroke = stroke;
+            polygon.stroke_width = stroke_width;
+            polygon.with_common_attributes(common_attributes).into()
+        }
+        "linearGradient" => {
+            let mut gradient = SvgLinearGradient::new();
+            if let (Some(x1), Some(y1)) = (element.attribute("x1"), element.attribute("y1")) {
+                gradient = gradient.start(parse_number::<Coordinate>(x1)?, parse_number(y1)?);
+            }
+            if let (Some(x2), Some(y2)) = (element.attribute("x2"), element.attribute("y2")) {
+                gradient = gradient.end(parse_number::<Coordinate>(x2)?, parse_number(y2)?);
+            }
+            parse_stops(element)?
+                .into_iter()
+                .fold(gradient, SvgLinearGradient::stop)
+                .into()
+        }
+        "radialGradient" => {
+            let mut gradient = SvgRadialGradient::new();
+            if let (Some(cx), Some(cy)) = (element.attribute("cx"), element.attribute("cy")) {
+                gradient = gradient.center(parse_number::<Coordinate>(cx)?, parse_number(cy)?);
+            }
+            if let Some(r) = optional(element, "r", parse_number::<Coordinate>)? {
+                gradient = gradient.radius(r);
+            }
+            parse_stops(element)?
+                .into_iter()
+                .fold(gradient, SvgRadialGradient::stop)
+                .into()
+        }
+        name => return Err(format!("Unsupported element: <{name}>")),
+    };
+    Ok((id, parsed))
+}
+
+#[test]
+fn round_trip() {
+    #![allow(clippy::unwrap_used)]
+    let mut image = SvgImage::new()
+        .dimensions(200, 100)
+        .view_box((-1, -1, 12, 7))
+        .data_attribute("steps".to_string(), "3".to_string())
+        .style("background: black");
+    let gradient_id = image.define(
+        SvgLinearGradient::new()
+            .end(0, 1)
+            .stop(SvgGradientStop::new(0., SvgColor::Rgb(255, 0, 0)))
+            .stop(SvgGradientStop::new(1., SvgColor::Hsl(120., 50, 50)).opacity(0.5)),
+    );
+    let clip_id = image.define(SvgClipPath::with_elements(vec![SvgRect::default()
+        .width(10)
+        .height(5)]));
+    let circle_id = image.define(SvgCircle {
+        cx: 0.5,
+        cy: 0.5,
+        r: 0.25,
+        fill: Some(SvgColor::RgbPercentage(100., 50., 0.)),
+    });
+    image.add(
+        SvgGroup::with_elements(vec![SvgUse::new(circle_id).x(1.).y(2.)])
+            .class("a")
+            .class("b")
+            .clip_path(clip_id)
+            .transform(SvgTransform::Matrix {
+                a: 1.,
+                b: 0.,
+                c: 0.,
+                d: -1.,
+                dx: 0.5,
+                dy: 1.,
+            }),
+    );
+    image.add(
+        SvgRect::default()
+            .width(1)
+            .height(1)
+            .fill(SvgColor::Reference(gradient_id))
+            .title("A \"title\" & more".to_string())
+            .animate(
+                SvgAnimate::new("x")
+                    .values([0, 5])
+                    .calc_mode(SvgCalcMode::Discrete)
+                    .duration(300.)
+                    .repeat_indefinitely(),
+            )
+            .animate(SvgSet::new("y", "2").begin(100.).freeze()),
+    );
+    image.add_with_id(
+        SvgPath::default()
+            .shape(SvgShape::at(0, 0).line_to_relative(1, 1).close())
+            .stroke(SvgColor::Rgb(1, 2, 3))
+            .stroke_width(0.2)
+            .stroke_linecap(SvgStrokeLinecap::Round),
+    );
+    image.add(
+        SvgText::new("x < y")
+            .x(1)
+            .y(2)
+            .font_size(0.5)
+            .text_anchor(SvgTextAnchor::End),
+    );
+    image.add(SvgLine::new(0, 0, 1, 1).stroke(SvgColor::Rgb(0, 0, 0)));
+    image.add(SvgPolyline::new().point(0, 0).point(1, 2));
+    image.add(SvgPolygon::new().point(0, 0).point(1, 2).point(2, 0));
+    image.add(SvgStyle::new(".a { fill: red; }".to_string()));
+    image.add(SvgScript::new("const a = 1 < 2 && true;".to_string()));
+
+    let svg = image.to_svg_string();
+    let parsed = SvgImage::parse(&svg).unwrap();
+    assert_eq!(parsed.to_svg_string(), svg);
+    assert_eq!(parsed.id_sequence, image.id_sequence);
+
+    assert!(SvgImage::parse("<g/>").is_err());
+    assert!(SvgImage::parse("<svg><unknown/></svg>").is_err());
+    assert!(
+        SvgImage::parse("<svg><rect x=\"a\" y=\"0\" width=\"1\" height=\"1\"/></svg>").is_err()
+    );
+}
@@ -1,7 +1,6 @@
 use std::io::Write;
 
 use crate::common_attributes::{implement_common_attributes, CommonAttributes};
-use crate::escape::escape_xml;
 use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
 
 #[derive(Default)]
@@ -14,6 +13,7 @@ pub struct SvgPath {
 
 implement_common_attributes!(SvgPath);
 
+#[derive(Clone)]
 enum SvgPathElement {
     LineAbsolute((Coordinate, Coordinate)),
     LineRelative((Coordinate, Coordinate)),
@@ -79,17 +79,11 @@ impl SvgPath {
         writer.write_all(b" d=\"").unwrap();
         self.shape.write(writer);
         writer.write_all(b"\"").unwrap();
-        if let Some(title) = &self.common_attributes.title {
-            writer
-                .write_all(format!("><title>{}</title></path>", escape_xml(title)).as_bytes())
-                .unwrap();
-        } else {
-            writer.write_all(b"/>\n").unwrap();
-        }
+        self.common_attributes.write_end("path", writer);
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct SvgShape {
     elements: Vec<SvgPathElement>,
 }
@@ -173,6 +167,45 @@ impl SvgShape {
         self
     }
 
+    /// Parse path data as written by [Self::data_string].
+    ///
+    /// Only the commands used by this crate, `M`, `m`, `L`, `l`, `a` and `Z`, are supported.
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut elements = Vec::new();
+        let mut rest = data.trim_start();
+        while let Some(command) = rest.chars().next() {
+            rest = rest[command.len_utf8()..].trim_start();
+            let argument_count = match command {
+                'M' | 'm' | 'L' | 'l' => 2,
+                'a' => 7,
+                'Z' | 'z' => 0,
+                _ => return Err(format!("Unsupported path command '{command}' in '{data}'")),
+            };
+            let mut arguments = [0.; 7];
+            for argument in arguments.iter_mut().take(argument_count) {
+                let length = rest
+                    .find(|c: char| {
+                        c.is_ascii_alphabetic() && c != 'e' || c.is_whitespace() || c == ','
+                    })
+                    .unwrap_or(rest.len());
+                *argument = rest[..length]
+                    .parse()
+                    .map_err(|_| format!("Invalid number in path data '{data}'"))?;
+                rest = rest[length..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            }
+            let [a, b, c, d, e, f, g] = arguments;
+            elements.push(match command {
+                'M' => SvgPathElement::MoveAbsolute((a, b)),
+                'm' => SvgPathElement::MoveRelative((a, b)),
+                'L' => SvgPathElement::LineAbsolute((a, b)),
+                'l' => SvgPathElement::LineRelative((a, b)),
+                'a' => SvgPathElement::ArcRelative((a, b, c, d, e, f, g)),
+                _ => SvgPathElement::Close,
+            });
+        }
+        Ok(Self { elements })
+    }
+
     pub fn data_string(&self) -> String {
         #![allow(clippy::unwrap_used)]
         let mut buffer = Vec::new();
@@ -211,3 +244,17 @@ impl SvgShape {
         }
     }
 }
+
+#[test]
+fn parse_shape() {
+    #![allow(clippy::unwrap_used)]
+    let data = "M 0 0.5L -1.25 2e-3l 1 0a 0.4 0.4 0 1 0 -0.8 0Zm 1 1Z";
+    let shape = SvgShape::parse(data).unwrap();
+    assert_eq!(
+        shape.data_string(),
+        "M 0 0.5L -1.25 0.002l 1 0a 0.4 0.4 0 1 0 -0.8 0Zm 1 1Z"
+    );
+    assert!(SvgShape::parse("").unwrap().is_empty());
+    assert!(SvgShape::parse("M 0").is_err());
+    assert!(SvgShape::parse("Q 0 0 1 1").is_err());
+}
@@ -0,0 +1,76 @@
+use std::io::Write;
+
+use crate::common_attributes::{implement_common_attributes, CommonAttributes};
+use crate::polyline::write_points_and_stroke;
+use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
+
+/// A closed shape of straight lines through a list of points.
+#[derive(Default)]
+pub struct SvgPolygon {
+    pub points: Vec<(Coordinate, Coordinate)>,
+    pub stroke: Option<SvgColor>,
+    pub stroke_width: Option<f64>,
+    common_attributes: CommonAttributes,
+}
+
+implement_common_attributes!(SvgPolygon);
+
+impl From<SvgPolygon> for SvgElement {
+    fn from(value: SvgPolygon) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+impl SvgPolygon {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_points(points: Vec<(Coordinate, Coordinate)>) -> Self {
+        Self {
+            points,
+            ..Default::default()
+        }
+    }
+
+    pub fn point<C: Into<Coordinate>>(mut self, x: C, y: C) -> Self {
+        self.points.push((x.into(), y.into()));
+        self
+    }
+
+    pub const fn stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    pub const fn stroke(mut self, color: SvgColor) -> Self {
+        self.stroke = Some(color);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer.write_all(b"<polygon").unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        write_points_and_stroke(&self.points, self.stroke, self.stroke_width, writer);
+        self.common_attributes.write(writer);
+        self.common_attributes.write_end("polygon", writer);
+    }
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let mut buffer = Vec::new();
+    SvgPolygon::with_points(vec![(0., 0.), (1., 0.), (0.5, 1.)])
+        .fill(SvgColor::Rgb(0, 255, 0))
+        .title("A <triangle>".to_string())
+        .write(Some(SvgId { value: 3 }), &mut buffer);
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "<polygon id=\"i3\" points=\"0,0 1,0 0.5,1\" fill=\"rgb(0, 255, 0)\">\
+         <title>A &lt;triangle&gt;</title></polygon>\n"
+    );
+}
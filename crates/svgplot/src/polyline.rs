@@ -0,0 +1,105 @@
+use std::io::Write;
+
+use crate::common_attributes::{implement_common_attributes, CommonAttributes};
+use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
+
+/// Connected straight lines through a list of points.
+///
+/// Note that a polyline is filled black unless a fill is specified.
+#[derive(Default)]
+pub struct SvgPolyline {
+    pub points: Vec<(Coordinate, Coordinate)>,
+    pub stroke: Option<SvgColor>,
+    pub stroke_width: Option<f64>,
+    common_attributes: CommonAttributes,
+}
+
+implement_common_attributes!(SvgPolyline);
+
+impl From<SvgPolyline> for SvgElement {
+    fn from(value: SvgPolyline) -> Self {
+        Self::Polyline(value)
+    }
+}
+
+impl SvgPolyline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_points(points: Vec<(Coordinate, Coordinate)>) -> Self {
+        Self {
+            points,
+            ..Default::default()
+        }
+    }
+
+    pub fn point<C: Into<Coordinate>>(mut self, x: C, y: C) -> Self {
+        self.points.push((x.into(), y.into()));
+        self
+    }
+
+    pub const fn stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    pub const fn stroke(mut self, color: SvgColor) -> Self {
+        self.stroke = Some(color);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer.write_all(b"<polyline").unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        write_points_and_stroke(&self.points, self.stroke, self.stroke_width, writer);
+        self.common_attributes.write(writer);
+        self.common_attributes.write_end("polyline", writer);
+    }
+}
+
+/// Write the attributes shared by [SvgPolyline] and [crate::SvgPolygon].
+pub(crate) fn write_points_and_stroke<W: Write>(
+    points: &[(Coordinate, Coordinate)],
+    stroke: Option<SvgColor>,
+    stroke_width: Option<f64>,
+    writer: &mut W,
+) {
+    #![allow(clippy::unwrap_used)]
+    writer.write_all(b" points=\"").unwrap();
+    for (idx, (x, y)) in points.iter().enumerate() {
+        writer
+            .write_all(format!("{}{x},{y}", if idx == 0 { "" } else { " " }).as_bytes())
+            .unwrap();
+    }
+    writer.write_all(b"\"").unwrap();
+    if let Some(stroke) = stroke {
+        stroke.write_stroke(writer);
+    }
+    if let Some(stroke_width) = stroke_width {
+        writer
+            .write_all(format!(" stroke-width=\"{stroke_width}\"").as_bytes())
+            .unwrap();
+    }
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let mut buffer = Vec::new();
+    SvgPolyline::new()
+        .point(0, 0)
+        .point(1.5, 2.)
+        .point(3, 0)
+        .stroke(SvgColor::Rgb(0, 0, 255))
+        .stroke_width(0.1)
+        .style("fill: none")
+        .write(None, &mut buffer);
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "<polyline points=\"0,0 1.5,2 3,0\" stroke=\"rgb(0, 0, 255)\" stroke-width=\"0.1\" style=\"fill: none\"/>\n"
+    );
+}
@@ -1,5 +1,4 @@
 use crate::common_attributes::{implement_common_attributes, CommonAttributes};
-use crate::escape::escape_xml;
 use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
 use std::io::Write;
 
@@ -55,12 +54,6 @@ impl SvgRect {
             id.write(buffer);
         }
         self.common_attributes.write(buffer);
-        if let Some(title) = &self.common_attributes.title {
-            buffer
-                .write_all(format!("><title>{}</title></rect>", escape_xml(title)).as_bytes())
-                .unwrap();
-        } else {
-            buffer.write_all(b"/>\n").unwrap();
-        }
+        self.common_attributes.write_end("rect", buffer);
     }
 }
@@ -19,4 +19,13 @@ impl SvgStrokeLinecap {
             .unwrap();
         writer.write_all(b"\"").unwrap();
     }
+
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "butt" => Ok(Self::Butt),
+            "round" => Ok(Self::Round),
+            "square" => Ok(Self::Square),
+            _ => Err(format!("Invalid stroke-linecap: '{value}'")),
+        }
+    }
 }
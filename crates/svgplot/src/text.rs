@@ -0,0 +1,162 @@
+use std::io::Write;
+
+use crate::common_attributes::{implement_common_attributes, CommonAttributes};
+use crate::escape::escape_xml;
+use crate::{Coordinate, SvgColor, SvgElement, SvgId, SvgStrokeLinecap, SvgTransform};
+
+/// How text is aligned horizontally relative to its position.
+pub enum SvgTextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// How text is aligned vertically relative to its position.
+pub enum SvgDominantBaseline {
+    /// Position the alphabetic baseline, the default.
+    Auto,
+    /// Position the middle of the text.
+    Middle,
+    /// Position the top of the text.
+    Hanging,
+}
+
+impl SvgTextAnchor {
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "start" => Ok(Self::Start),
+            "middle" => Ok(Self::Middle),
+            "end" => Ok(Self::End),
+            _ => Err(format!("Invalid text-anchor: '{value}'")),
+        }
+    }
+}
+
+impl SvgDominantBaseline {
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "auto" => Ok(Self::Auto),
+            "middle" => Ok(Self::Middle),
+            "hanging" => Ok(Self::Hanging),
+            _ => Err(format!("Invalid dominant-baseline: '{value}'")),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SvgText {
+    pub x: Coordinate,
+    pub y: Coordinate,
+    pub text: String,
+    pub font_size: Option<Coordinate>,
+    pub font_family: Option<String>,
+    pub text_anchor: Option<SvgTextAnchor>,
+    pub dominant_baseline: Option<SvgDominantBaseline>,
+    common_attributes: CommonAttributes,
+}
+
+implement_common_attributes!(SvgText);
+
+impl From<SvgText> for SvgElement {
+    fn from(value: SvgText) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl SvgText {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+    pub fn x<C: Into<Coordinate>>(mut self, x: C) -> Self {
+        self.x = x.into();
+        self
+    }
+    pub fn y<C: Into<Coordinate>>(mut self, y: C) -> Self {
+        self.y = y.into();
+        self
+    }
+    pub fn font_size<C: Into<Coordinate>>(mut self, font_size: C) -> Self {
+        self.font_size = Some(font_size.into());
+        self
+    }
+    pub fn font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+    pub const fn text_anchor(mut self, text_anchor: SvgTextAnchor) -> Self {
+        self.text_anchor = Some(text_anchor);
+        self
+    }
+    pub const fn dominant_baseline(mut self, dominant_baseline: SvgDominantBaseline) -> Self {
+        self.dominant_baseline = Some(dominant_baseline);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, id: Option<SvgId>, writer: &mut W) {
+        #![allow(clippy::unwrap_used)]
+        writer
+            .write_all(format!("<text x=\"{}\" y=\"{}\"", self.x, self.y).as_bytes())
+            .unwrap();
+        if let Some(id) = id {
+            id.write(writer);
+        }
+        if let Some(font_size) = self.font_size {
+            writer
+                .write_all(format!(" font-size=\"{font_size}\"").as_bytes())
+                .unwrap();
+        }
+        if let Some(font_family) = &self.font_family {
+            writer
+                .write_all(format!(" font-family=\"{}\"", escape_xml(font_family)).as_bytes())
+                .unwrap();
+        }
+        if let Some(text_anchor) = &self.text_anchor {
+            writer
+                .write_all(match text_anchor {
+                    SvgTextAnchor::Start => b" text-anchor=\"start\"" as &[u8],
+                    SvgTextAnchor::Middle => b" text-anchor=\"middle\"",
+                    SvgTextAnchor::End => b" text-anchor=\"end\"",
+                })
+                .unwrap();
+        }
+        if let Some(dominant_baseline) = &self.dominant_baseline {
+            writer
+                .write_all(match dominant_baseline {
+                    SvgDominantBaseline::Auto => b" dominant-baseline=\"auto\"" as &[u8],
+                    SvgDominantBaseline::Middle => b" dominant-baseline=\"middle\"",
+                    SvgDominantBaseline::Hanging => b" dominant-baseline=\"hanging\"",
+                })
+                .unwrap();
+        }
+        self.common_attributes.write(writer);
+        writer.write_all(b">").unwrap();
+        self.common_attributes.write_children(writer);
+        writer
+            .write_all(format!("{}</text>\n", escape_xml(&self.text)).as_bytes())
+            .unwrap();
+    }
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let mut buffer = Vec::new();
+    SvgText::new("Tom & <Jerry>")
+        .x(1)
+        .y(2.5)
+        .font_size(3)
+        .font_family("monospace")
+        .text_anchor(SvgTextAnchor::Middle)
+        .dominant_baseline(SvgDominantBaseline::Hanging)
+        .fill(SvgColor::Rgb(255, 255, 255))
+        .write(None, &mut buffer);
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "<text x=\"1\" y=\"2.5\" font-size=\"3\" font-family=\"monospace\" \
+         text-anchor=\"middle\" dominant-baseline=\"hanging\" fill=\"rgb(255, 255, 255)\">\
+         Tom &amp; &lt;Jerry&gt;</text>\n"
+    );
+}
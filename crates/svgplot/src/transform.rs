@@ -17,6 +17,26 @@ pub enum SvgTransform {
 }
 
 impl SvgTransform {
+    /// Parse a transform from the value of the style attribute written by [Self::write],
+    /// returning `None` for other styles.
+    pub(crate) fn parse_style(style: &str) -> Option<Result<Self, String>> {
+        let transform = style.strip_prefix("transform:")?;
+        let invalid = || format!("Invalid transform: '{transform}'");
+        let (function, arguments) = transform
+            .strip_suffix(')')
+            .and_then(|transform| transform.split_once('('))?;
+        let arguments = arguments
+            .split(',')
+            .map(|argument| argument.trim_end_matches("px").parse::<Coordinate>())
+            .collect::<Result<Vec<_>, _>>();
+        Some(match (function, arguments.as_deref()) {
+            ("translate", Ok(&[x, y])) => Ok(Self::Translate(x, y)),
+            ("scale", Ok(&[x, y])) => Ok(Self::Scale(x, y)),
+            ("matrix", Ok(&[a, b, c, d, dx, dy])) => Ok(Self::Matrix { a, b, c, d, dx, dy }),
+            _ => Err(invalid()),
+        })
+    }
+
     pub(crate) fn write<W: Write>(&self, writer: &mut W) {
         #![allow(clippy::unwrap_used)]
         // TODO: Co exist with styling
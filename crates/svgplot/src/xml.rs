@@ -0,0 +1,212 @@
+//! A minimal XML reader, sufficient for documents written by this crate.
+use crate::escape::unescape_xml;
+
+pub enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+pub struct XmlElement {
+    pub name: String,
+    /// The attributes in document order. Names may repeat, as the `style` attribute
+    /// does when both a style and a transform is specified.
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_name, _)| attribute_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn child_elements(&self) -> impl Iterator<Item = &Self> {
+        self.children.iter().filter_map(|child| match child {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// The concatenated text content of the direct children of this element.
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                XmlNode::Text(text) => Some(text.as_str()),
+                XmlNode::Element(_) => None,
+            })
+            .collect()
+    }
+}
+
+struct Reader<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{message} at byte offset {}", self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn consume(&mut self, expected: &str) -> bool {
+        if self.rest().starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skip past the next occurrence of `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("Missing '{end}'"))),
+        }
+    }
+
+    /// Skip any XML declaration, processing instructions, comments and doctype.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.consume("<?") {
+                self.skip_past("?>")?;
+            } else if self.consume("<!--") {
+                self.skip_past("-->")?;
+            } else if self.consume("<!DOCTYPE") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("Expected a name"));
+        }
+        self.position += length;
+        Ok(rest[..length].to_string())
+    }
+
+    fn element(&mut self) -> Result<XmlElement, String> {
+        if !self.consume("<") {
+            return Err(self.error("Expected '<'"));
+        }
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.consume("/>") {
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children: Vec::new(),
+                });
+            } else if self.consume(">") {
+                break;
+            }
+            let attribute_name = self.name()?;
+            self.skip_whitespace();
+            if !self.consume("=") {
+                return Err(self.error("Expected '='"));
+            }
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("Expected a quoted attribute value")),
+            };
+            self.position += 1;
+            let length = self
+                .rest()
+                .find(quote)
+                .ok_or_else(|| self.error("Unterminated attribute value"))?;
+            let value = unescape_xml(&self.rest()[..length])?;
+            self.position += length + 1;
+            attributes.push((attribute_name, value));
+        }
+
+        let mut children = Vec::new();
+        loop {
+            if self.consume("</") {
+                let end_name = self.name()?;
+                if end_name != name {
+                    return Err(self.error(&format!("Expected </{name}>, found </{end_name}>")));
+                }
+                self.skip_whitespace();
+                if !self.consume(">") {
+                    return Err(self.error("Expected '>'"));
+                }
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children,
+                });
+            } else if self.consume("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with('<') {
+                children.push(XmlNode::Element(self.element()?));
+            } else if self.rest().is_empty() {
+                return Err(self.error(&format!("Unterminated <{name}>")));
+            } else {
+                let length = self.rest().find('<').unwrap_or_else(|| self.rest().len());
+                children.push(XmlNode::Text(unescape_xml(&self.rest()[..length])?));
+                self.position += length;
+            }
+        }
+    }
+}
+
+/// Parse a XML document, returning its root element.
+pub fn parse_xml(input: &str) -> Result<XmlElement, String> {
+    let mut reader = Reader { input, position: 0 };
+    reader.skip_misc()?;
+    let root = reader.element()?;
+    reader.skip_misc()?;
+    if !reader.rest().is_empty() {
+        return Err(reader.error("Unexpected content after the root element"));
+    }
+    Ok(root)
+}
+
+#[test]
+fn test() {
+    #![allow(clippy::unwrap_used)]
+    let root = parse_xml(
+        "<?xml version=\"1.0\"?>\n<svg a=\"1\" b='&lt;2&gt;'>\n<!-- comment --><g><rect/>Tom &amp; Jerry</g></svg>\n",
+    )
+    .unwrap();
+    assert_eq!(root.name, "svg");
+    assert_eq!(root.attribute("a"), Some("1"));
+    assert_eq!(root.attribute("b"), Some("<2>"));
+    assert_eq!(root.attribute("c"), None);
+    let children: Vec<_> = root.child_elements().collect();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].name, "g");
+    assert_eq!(children[0].child_elements().next().unwrap().name, "rect");
+    assert_eq!(children[0].text(), "Tom & Jerry");
+
+    assert!(parse_xml("<svg>").is_err());
+    assert!(parse_xml("<svg></g>").is_err());
+    assert!(parse_xml("<svg a=1/>").is_err());
+    assert!(parse_xml("<svg/><svg/>").is_err());
+}
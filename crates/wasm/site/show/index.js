@@ -27,6 +27,8 @@ visualizerWorker.onmessage = (message) => {
   if ("errorMessage" in message.data) {
     console.error("Error from worker", message.data.errorMessage);
     spinner.innerHTML = `<h1>Error: ${message.data.errorMessage}</h1>`;
+  } else if ("progressFrame" in message.data) {
+    spinner.innerHTML = `<h1>Computing frame ${message.data.progressFrame}...</h1>`;
   } else if (message.data.done) {
     console.log(
       "SVG size: " +
@@ -228,6 +230,10 @@ progress.addEventListener("input", () => {
   svg.style.setProperty("--step", progress.value);
   if (window.onNewStep) {
     window.onNewStep(parseInt(progress.value));
+  } else if (svg.querySelector("animate, set")) {
+    // Step through SMIL animations by seeking in their paused timeline:
+    svg.pauseAnimations();
+    svg.setCurrentTime((parseInt(progress.value) * state.stepDuration) / 1000);
   }
 });
 
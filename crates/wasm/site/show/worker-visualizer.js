@@ -1,11 +1,18 @@
-import init, { solve } from "./generated/advent_of_code_wasm.js";
+import init, { solveDetailed } from "./generated/advent_of_code_wasm.js";
 
 self.onmessage = async (message) => {
   try {
     const { year, day, part, input } = message.data;
     await self.wasmReadyPromise;
-    const answer = solve(year, day, part, input);
-    self.postMessage({ done: true, answer });
+    const { svg } = solveDetailed(
+      Number(year),
+      Number(day),
+      Number(part),
+      input,
+      ({ frame }) => self.postMessage({ progressFrame: frame }),
+      false
+    );
+    self.postMessage({ done: true, answer: svg });
   } catch (e) {
     console.log(e);
     self.postMessage({ errorMessage: e.message });
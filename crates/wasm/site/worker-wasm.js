@@ -1,4 +1,4 @@
-import init, { solve } from "./generated/advent_of_code_wasm.js";
+import init, { solveDetailed } from "./generated/advent_of_code_wasm.js";
 
 class WasmNotWorkingError extends Error {
   constructor(message) {
@@ -16,8 +16,12 @@ async function solveWasm(year, day, part, input) {
 
   const startTime = performance.now();
   try {
-    const answer = solve(year, day, part, input);
-    const executionTime = performance.now() - startTime;
+    const { answer, elapsedMs: executionTime } = solveDetailed(
+      Number(year),
+      Number(day),
+      Number(part),
+      input
+    );
     console.log(
       `Wasm ${year}-${day}-${part} solution in: ${executionTime.toFixed(2)} ms`
     );
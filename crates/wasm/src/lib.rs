@@ -2,9 +2,49 @@
 extern crate js_sys;
 extern crate wasm_bindgen;
 
-use advent_of_code::solve_raw;
+use advent_of_code::{solve_raw, supported_puzzles};
+use js_sys::{Array, Date, Object, Reflect};
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_TYPES: &'static str = r#"
+/** The result of solving a problem, as returned from solveDetailed(). */
+export interface Solution {
+  /** The computed answer as text. */
+  answer: string;
+  /** The rendered SVG if built with visualization, otherwise null. */
+  svg: string | null;
+  /** The time it took to solve the problem in milliseconds. */
+  elapsedMs: number;
+}
+
+/** A supported problem, as returned from listPuzzles(). */
+export interface Puzzle {
+  year: number;
+  day: number;
+}
+
+/** Progress of a solve, as passed to the progress callback of solveDetailed(). */
+export interface Progress {
+  /** The number of the frame, starting at 1. */
+  frame: number;
+  /** The frame rendered as an SVG document, unless only progress was requested. */
+  svg?: string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Solution")]
+    pub type Solution;
+
+    #[wasm_bindgen(typescript_type = "Puzzle[]")]
+    pub type PuzzleArray;
+
+    #[wasm_bindgen(typescript_type = "(progress: Progress) => void")]
+    pub type ProgressCallback;
+}
 
 fn as_string(value: &JsValue) -> String {
     value.as_string().unwrap_or_else(|| {
@@ -14,6 +54,24 @@ fn as_string(value: &JsValue) -> String {
     })
 }
 
+fn to_js_error(message: &str) -> JsValue {
+    JsValue::from(js_sys::Error::new(message))
+}
+
+fn new_object(properties: &[(&str, JsValue)]) -> Object {
+    let object = Object::new();
+    for (key, value) in properties {
+        // Setting a property on a plain object can not fail.
+        let _ = Reflect::set(&object, &JsValue::from_str(key), value);
+    }
+    object
+}
+
+fn install_panic_hook() {
+    #[cfg(feature = "console-panic-hook")]
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+}
+
 #[wasm_bindgen]
 pub fn solve(
     year: &JsValue,
@@ -21,11 +79,97 @@ pub fn solve(
     part: &JsValue,
     input: &str,
 ) -> Result<String, JsValue> {
-    #[cfg(feature = "console-panic-hook")]
-    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+    install_panic_hook();
 
     let year = as_string(year);
     let day = as_string(day);
     let part = as_string(part);
-    solve_raw(&year, &day, &part, input).map_err(|error| JsValue::from(js_sys::Error::new(&error)))
+    solve_raw(&year, &day, &part, input).map_err(|error| to_js_error(&error))
+}
+
+/// Solves the specified problem, returning the answer together with the rendered SVG
+/// (if built with the `visualization` feature) and the time it took to solve.
+///
+/// The optional `progress` callback is called with each frame of the visualization
+/// as it is rendered, which allows a page to show a long solve advancing if solving
+/// in a web worker. It is only called when built with the `visualization` feature.
+/// If `frame_svgs` is false the frames are only counted and not rendered.
+#[wasm_bindgen(js_name = solveDetailed)]
+pub fn solve_detailed(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    progress: Option<ProgressCallback>,
+    frame_svgs: Option<bool>,
+) -> Result<Solution, JsValue> {
+    install_panic_hook();
+
+    let start_time = Date::now();
+
+    #[cfg(feature = "visualization")]
+    let (answer, svg) = {
+        use advent_of_code::FrameListener;
+
+        let progress = progress.map(JsCast::unchecked_into::<js_sys::Function>);
+        let frame_count = std::cell::Cell::new(0);
+        let report_frame = |svg: Option<String>| {
+            frame_count.set(frame_count.get() + 1);
+            if let Some(progress) = &progress {
+                let mut properties = vec![("frame", JsValue::from(frame_count.get()))];
+                if let Some(svg) = svg {
+                    properties.push(("svg", JsValue::from(svg)));
+                }
+                // An exception thrown by the callback should not abort the solve.
+                let _ = progress.call1(&JsValue::NULL, &new_object(&properties));
+            }
+            std::ops::ControlFlow::Continue(())
+        };
+        let svg_listener = |svg: String| report_frame(Some(svg));
+        let progress_listener = || report_frame(None);
+        let listener = match (&progress, frame_svgs) {
+            (None, _) => None,
+            (Some(_), Some(false)) => Some(FrameListener::Progress(&progress_listener)),
+            (Some(_), _) => Some(FrameListener::Svg(&svg_listener)),
+        };
+        let solution = advent_of_code::solve_visualized(year, day, part, input, listener)
+            .map_err(|error| to_js_error(&error))?;
+        (solution.answer, JsValue::from(solution.svg))
+    };
+
+    #[cfg(not(feature = "visualization"))]
+    let (answer, svg) = {
+        drop((progress, frame_svgs));
+        let answer =
+            advent_of_code::solve(year, day, part, input).map_err(|error| to_js_error(&error))?;
+        (answer, JsValue::NULL)
+    };
+
+    let elapsed_ms = Date::now() - start_time;
+    Ok(new_object(&[
+        ("answer", JsValue::from(answer)),
+        ("svg", svg),
+        ("elapsedMs", JsValue::from(elapsed_ms)),
+    ])
+    .unchecked_into())
+}
+
+/// Returns the supported problems as `{year, day}` objects, ordered by year and day.
+#[wasm_bindgen(js_name = listPuzzles)]
+pub fn list_puzzles() -> PuzzleArray {
+    supported_puzzles()
+        .map(|(year, day)| {
+            JsValue::from(new_object(&[
+                ("year", JsValue::from(year)),
+                ("day", JsValue::from(day)),
+            ]))
+        })
+        .collect::<Array>()
+        .unchecked_into()
+}
+
+/// Returns whether the module was built with the `visualization` feature.
+#[wasm_bindgen(js_name = hasVisualization)]
+pub fn has_visualization() -> bool {
+    cfg!(feature = "visualization")
 }
@@ -4,3 +4,17 @@ const solve = require("advent-of-code-wasm").solve;
 assert.equal(solve(2019, 1, 1, "14"), "2");
 assert.equal(solve("2019", "1", "1", "14"), "2");
 assert.equal(solve(2019, 3, 2, "R8,U5,L5,D3\nU7,R6,D4,L4"), "30");
+
+const { solveDetailed, listPuzzles, hasVisualization } = require("advent-of-code-wasm");
+
+const solution = solveDetailed(2019, 1, 1, "14");
+assert.equal(solution.answer, "2");
+assert.equal(solution.svg, null);
+assert.ok(solution.elapsedMs >= 0);
+assert.throws(() => solveDetailed(2019, 1, 1, "hello"), /Invalid digit/);
+
+const puzzles = listPuzzles();
+assert.deepEqual(puzzles[0], { year: 2015, day: 1 });
+assert.ok(puzzles.some(({ year, day }) => year === 2022 && day === 25));
+
+assert.equal(hasVisualization(), false);
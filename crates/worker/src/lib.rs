@@ -1,5 +1,4 @@
 #![allow(clippy::future_not_send)]
-use advent_of_code::solve_raw;
 use worker::*;
 
 mod utils;
@@ -9,49 +8,36 @@ fn log_request(req: &Request) {
         "{} - [{}], located at: {:?}, within: {}",
         Date::now().to_string(),
         req.path(),
-        req.cf().coordinates().unwrap_or_default(),
-        req.cf().region().unwrap_or_else(|| "unknown region".into())
+        req.cf().and_then(Cf::coordinates).unwrap_or_default(),
+        req.cf()
+            .and_then(Cf::region)
+            .unwrap_or_else(|| "unknown region".into())
     );
 }
 
 #[event(fetch)]
-#[allow(clippy::unwrap_used)]
-pub async fn main(req: Request, env: Env, _ctx: worker::Context) -> Result<Response> {
+pub async fn main(mut req: Request, _env: Env, _ctx: worker::Context) -> Result<Response> {
     log_request(&req);
 
     // Optionally, get more helpful error messages written to the console in the case of a panic.
     utils::set_panic_hook();
 
-    // Optionally, use the Router to handle matching endpoints, use ":name" placeholders, or "*name"
-    // catch-alls to match on specific patterns. Alternatively, use `Router::with_data(D)` to
-    // provide arbitrary data that will be accessible in each route via the `ctx.data()` method.
-    let router = Router::new();
-
-    // Add as many routes as your Worker needs! Each route will get a `Request` for handling HTTP
-    // functionality and a `RouteContext` which you can use to  and get route parameters and
-    // Environment bindings like KV Stores, Durable Objects, Secrets, and Variables.
-    router
-        .get("/", |_, _| Response::ok("Hello from Workers!"))
-        .post_async("/solve/:year/:day/:part", |mut req, ctx| async move {
-            let year = ctx.param("year").unwrap();
-            let day = ctx.param("day").unwrap();
-            let part = ctx.param("part").unwrap();
-            let input = req.text().await?;
-
-            let response = match solve_raw(year, day, part, &input) {
-                Ok(answer) => Response::ok(answer),
-                Err(message) => Response::error(message, 400),
-            }
-            .unwrap();
-
-            let mut headers = Headers::new();
-            headers.append("Access-Control-Allow-Origin", "*").unwrap();
-            Ok(response.with_headers(headers))
-        })
-        .get("/worker-version", |_, _ctx| {
-            let version = env!("CARGO_PKG_VERSION");
-            Response::ok(version)
-        })
-        .run(req, env)
-        .await
+    let body = req.text().await?;
+    let path = req.path();
+    let method = req.method();
+
+    // Routing is shared with other servers to keep behaviour and error formats identical.
+    let response = advent_of_code_api::handle(&advent_of_code_api::Request {
+        method: advent_of_code_api::Method::parse(method.as_ref()),
+        path: &path,
+        body: &body,
+    });
+
+    let headers = Headers::new();
+    for (name, value) in response.headers() {
+        headers.set(name, value)?;
+    }
+    Ok(Response::ok(response.body)?
+        .with_status(response.status)
+        .with_headers(headers))
 }